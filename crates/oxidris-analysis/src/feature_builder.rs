@@ -21,14 +21,27 @@
 //!   - Ignores safe values (below P75), only penalizes approaching danger zones
 //!   - Reflects situations where the metric is only concerning at extreme values
 //!
-//! - **Reward**: Smooth positive signals (e.g., `i_well_reward`)
+//! - **Reward**: Smooth positive signals (e.g., `i_well_reward`, `eroded_piece_cells_raw_reward`)
 //!   - Encourages beneficial board configurations
 //!   - Uses custom transformation (e.g., triangular peak for optimal I-well depth)
+//!     or P01-P99 normalization for sparse positive measurements
 //!
 //! - **Bonus**: Discrete strong rewards (e.g., `line_clear_bonus`)
 //!   - Applied to per-placement actions (not cumulative board state)
 //!   - Uses discrete mapping with emphasis on high-value actions (4-line tetrises)
 //!
+//! - **KM**: Survival-based signals mapped through KM median survival (e.g., `num_holes_table_km`)
+//!   - `_table_km`: Lookup table per feature value ([`KmTransform::Table`])
+//!   - `_pwl_km`: Monotone piecewise-linear curve ([`KmTransform::PiecewiseLinear`])
+//!   - `_spline_km`: Smoothing spline ([`KmTransform::Spline`])
+//!   - `_survival_km` / `_rmst_km`: Lookup table of survival probability / restricted mean
+//!     survival time at a horizon, usable under heavy censoring
+//!     ([`KmTransform::SurvivalProbability`], [`KmTransform::RestrictedMean`])
+//!
+//! - **Interaction**: Combinations of two features (e.g., `max_height_x_num_holes_raw`)
+//!   - Built from the penalty/risk or KM features above (`_raw` / `_table_km` suffix, etc.)
+//!   - Lets a linear evaluator learn that one feature matters more when another is bad
+//!
 //! # Design Decisions
 //!
 //! ## Percentile-Based Normalization
@@ -50,6 +63,11 @@
 //! - Linear transformation treats `holes: 0→1` the same as `holes: 10→11`
 //! - This limitation affects all survival features (holes, height, etc.)
 //!
+//! KM features avoid this with non-linear transforms fitted to survival data. Table
+//! transforms follow noisy estimates for rare values; piecewise-linear and spline
+//! transforms trade some detail for smoothness. All of them saturate beyond the observed
+//! range.
+//!
 //! ### Feature Redundancy
 //!
 //! The feature set has two types of redundancy:
@@ -96,8 +114,13 @@
 //! let survival_stats = SurvivalStatsMap::collect_all_by_feature_value(&sessions, &sources);
 //!
 //! // 4. Build normalization parameters
-//! let norm_params =
-//!     BoardFeatureNormalizationParamCollection::from_stats(&sources, &raw_stats, &survival_stats);
+//! let horizon = 250; // e.g., half of the session turn limit
+//! let norm_params = BoardFeatureNormalizationParamCollection::from_stats(
+//!     &sources,
+//!     &raw_stats,
+//!     &survival_stats,
+//!     horizon,
+//! );
 //!
 //! // 5. Construct features with runtime normalization
 //! let builder = FeatureBuilder::new(norm_params);
@@ -107,39 +130,115 @@
 use oxidris_evaluator::board_feature::{
     BoardFeatureSource, BoxedBoardFeature, FeatureSignal,
     source::{
-        CenterColumnMaxHeight, ColumnTransitions, EdgeIWellDepth, MaxHeight, NumClearedLines,
-        NumHoles, RowTransitions, SumOfHoleDepth, SumOfWellDepth, SurfaceBumpiness,
-        SurfaceRoughness, TotalHeight,
+        CellsAboveHoles, CenterColumnMaxHeight, CheckerboardParityImbalance, ColumnParityImbalance,
+        ColumnTransitions, EdgeIWellDepth, ErodedPieceCells, HeldIPiece, IDependencies,
+        LandingHeight, MaxHeight, NumClearedLines, NumHoles, OpenWellCleanRows, OpenWellColumn,
+        OpenWellDepth, QueueFitCount, ReachableOverhangs, RowTransitions, RowsWithHoles,
+        SumOfHoleDepth, SumOfWellDepth, SurfaceBumpiness, SurfaceRoughness, TSlotCount, TSlotDepth,
+        TetrisReady, TotalHeight, UnfittablePieceKinds,
     },
     transform::{
-        IWellReward, LineClearBonus, RawTransform, RawTransformParam, TableTransform,
-        TableTransformParam,
+        IWellReward, InteractionFeature, InteractionOperator, KnotParamError, LineClearBonus,
+        PiecewiseLinearParam, PiecewiseLinearTransform, RawTransform, RawTransformParam,
+        SplineTransform, SplineTransformParam, SurvivalMetric, TableTransform, TableTransformParam,
     },
 };
+use oxidris_stats::smoothing::{SmoothingSpline, isotonic_regression};
 
 use crate::normalization::{
     BoardFeatureNormalizationParam, BoardFeatureNormalizationParamCollection,
 };
 
+/// Roughness penalty of KM spline features, relative to the cube of the mean knot spacing
+///
+/// Observation weights are normalized to a mean of 1.0, so a value around 1.0 smooths
+/// over a few neighboring feature values.
+const SPLINE_SMOOTHING: f64 = 1.0;
+
+/// Transform used to map feature values to KM survival-based features
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, derive_more::FromStr)]
+#[from_str(rename_all = "kebab-case")]
+pub enum KmTransform {
+    /// Lookup table of KM median survival per value ([`TableTransform`])
+    #[default]
+    Table,
+    /// Monotone piecewise-linear curve through the table at P05/P25/P50/P75/P95
+    /// ([`PiecewiseLinearTransform`])
+    PiecewiseLinear,
+    /// Smoothing spline fitted to KM medians ([`SplineTransform`])
+    Spline,
+    /// Lookup table of KM survival probability at the horizon per value ([`TableTransform`])
+    ///
+    /// Unlike the median, defined even for values whose curves never drop below 0.5.
+    SurvivalProbability,
+    /// Lookup table of KM restricted mean survival time per value ([`TableTransform`])
+    ///
+    /// Unlike the median, defined even for values whose curves never drop below 0.5.
+    RestrictedMean,
+}
+
+impl KmTransform {
+    /// Suffix of feature IDs built with this transform (e.g., `table_km`)
+    #[must_use]
+    pub fn id_suffix(self) -> &'static str {
+        match self {
+            Self::Table => "table_km",
+            Self::PiecewiseLinear => "pwl_km",
+            Self::Spline => "spline_km",
+            Self::SurvivalProbability => "survival_km",
+            Self::RestrictedMean => "rmst_km",
+        }
+    }
+
+    /// Suffix of feature names built with this transform (e.g., `Table KM`)
+    #[must_use]
+    pub fn name_suffix(self) -> &'static str {
+        match self {
+            Self::Table => "Table KM",
+            Self::PiecewiseLinear => "Piecewise-Linear KM",
+            Self::Spline => "Spline KM",
+            Self::SurvivalProbability => "Survival KM",
+            Self::RestrictedMean => "RMST KM",
+        }
+    }
+}
+
 #[derive(Debug, derive_more::Display, derive_more::Error)]
 pub enum BuildFeatureError {
     #[display("Missing normalization parameters for feature source '{source_id}'")]
     MissingNormalizationParam { source_id: String },
+    #[display("Invalid transform fitted for feature source '{source_id}': {source}")]
+    InvalidTransformParam {
+        source_id: String,
+        source: KnotParamError,
+    },
+}
+
+impl BuildFeatureError {
+    fn invalid_transform_param<S>(source: &S, error: KnotParamError) -> Self
+    where
+        S: BoardFeatureSource + ?Sized,
+    {
+        Self::InvalidTransformParam {
+            source_id: source.id().to_owned(),
+            source: error,
+        }
+    }
 }
 
 #[derive(Debug)]
 struct FeatureVecBuilder<'a> {
     raw: bool,
-    table_km: bool,
+    km: Option<KmTransform>,
     builder: &'a FeatureBuilder,
     features: Vec<BoxedBoardFeature>,
 }
 
 impl<'a> FeatureVecBuilder<'a> {
-    fn new(builder: &'a FeatureBuilder, raw: bool, table_km: bool) -> Self {
+    fn new(builder: &'a FeatureBuilder, raw: bool, km: Option<KmTransform>) -> Self {
         Self {
             raw,
-            table_km,
+            km,
             builder,
             features: vec![],
         }
@@ -166,12 +265,23 @@ impl<'a> FeatureVecBuilder<'a> {
         Ok(())
     }
 
-    fn add_table_km<S>(&mut self, source: &S) -> Result<(), BuildFeatureError>
+    fn add_raw_reward<S>(&mut self, source: &S) -> Result<(), BuildFeatureError>
+    where
+        S: BoardFeatureSource,
+    {
+        if self.raw {
+            self.features
+                .push(self.builder.build_raw_reward_for(source)?);
+        }
+        Ok(())
+    }
+
+    fn add_km<S>(&mut self, source: &S) -> Result<(), BuildFeatureError>
     where
         S: BoardFeatureSource,
     {
-        if self.table_km {
-            self.features.push(self.builder.build_table_km_for(source)?);
+        if let Some(km) = self.km {
+            self.features.push(self.builder.build_km_for(source, km)?);
         }
         Ok(())
     }
@@ -181,19 +291,54 @@ impl<'a> FeatureVecBuilder<'a> {
 #[derive(Debug)]
 pub struct FeatureBuilder {
     params: BoardFeatureNormalizationParamCollection,
+    queue_features: bool,
 }
 
 impl FeatureBuilder {
+    /// Creates a builder without queue-aware features
+    ///
+    /// Use [`with_queue_features()`](Self::with_queue_features) to enable them when the
+    /// sessions record piece queues.
     #[must_use]
     pub fn new(params: BoardFeatureNormalizationParamCollection) -> Self {
-        Self { params }
+        Self {
+            params,
+            queue_features: false,
+        }
+    }
+
+    /// Sets whether to build features that need the piece queue after the turn
+    /// (`held_i_piece`, `queue_fit_count`)
+    ///
+    /// Boards recorded without queues see no held piece and no upcoming pieces, so these
+    /// features are constant there and only add noise to training.
+    #[must_use]
+    pub fn with_queue_features(mut self, queue_features: bool) -> Self {
+        self.queue_features = queue_features;
+        self
     }
 
     pub fn build_km_features(&self) -> Result<Vec<BoxedBoardFeature>, BuildFeatureError> {
+        self.build_km_features_with(KmTransform::Table)
+    }
+
+    /// Build KM-based board features using the given KM transform
+    ///
+    /// Same as [`build_km_features()`](Self::build_km_features), but survival and score
+    /// features use `km` instead of table transforms.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if normalization parameters are missing for any feature
+    pub fn build_km_features_with(
+        &self,
+        km: KmTransform,
+    ) -> Result<Vec<BoxedBoardFeature>, BuildFeatureError> {
         let mut features = vec![];
-        features.extend_from_slice(&self.build_survival_features(false, true)?);
+        features.extend_from_slice(&self.build_survival_features(false, Some(km))?);
         features.extend_from_slice(&self.build_structure_raw_features()?);
-        features.extend_from_slice(&self.build_score_features());
+        features.extend_from_slice(&self.build_score_features(false, Some(km))?);
+        features.extend_from_slice(&self.build_interaction_features(false, Some(km))?);
         Ok(features)
     }
 
@@ -211,9 +356,10 @@ impl FeatureBuilder {
     /// Returns error if normalization parameters are missing for any feature
     pub fn build_raw_features(&self) -> Result<Vec<BoxedBoardFeature>, BuildFeatureError> {
         let mut features = vec![];
-        features.extend_from_slice(&self.build_survival_features(true, false)?);
+        features.extend_from_slice(&self.build_survival_features(true, None)?);
         features.extend_from_slice(&self.build_structure_raw_features()?);
-        features.extend_from_slice(&self.build_score_features());
+        features.extend_from_slice(&self.build_score_features(true, None)?);
+        features.extend_from_slice(&self.build_interaction_features(true, None)?);
         Ok(features)
     }
 
@@ -232,47 +378,63 @@ impl FeatureBuilder {
     /// Returns error if normalization parameters are missing for any feature
     pub fn build_all_features(&self) -> Result<Vec<BoxedBoardFeature>, BuildFeatureError> {
         let mut features = vec![];
-        features.extend_from_slice(&self.build_survival_features(true, true)?);
+        let km = Some(KmTransform::Table);
+        features.extend_from_slice(&self.build_survival_features(true, km)?);
         features.extend_from_slice(&self.build_structure_raw_features()?);
-        features.extend_from_slice(&self.build_score_features());
+        features.extend_from_slice(&self.build_score_features(true, km)?);
+        features.extend_from_slice(&self.build_interaction_features(true, km)?);
         Ok(features)
     }
 
     /// Build survival features with optional raw and table transforms
     ///
     /// Constructs survival-critical features:
-    /// - Holes (`num_holes`, `sum_of_hole_depth`)
+    /// - Holes (`num_holes`, `sum_of_hole_depth`, `rows_with_holes`, `cells_above_holes`)
     /// - Height (`max_height`, `center_column_max_height`, `total_height`)
+    /// - Placement (`landing_height`)
+    /// - Surface fit (`unfittable_piece_kinds`)
     ///
     /// Features from the same source are placed consecutively.
     ///
     /// # Arguments
     ///
     /// * `raw` - Include raw-transform features (penalty/risk)
-    /// * `table_km` - Include table-transform features (KM-based)
+    /// * `km` - Include KM-based features with the given transform
     fn build_survival_features(
         &self,
         raw: bool,
-        table_km: bool,
+        km: Option<KmTransform>,
     ) -> Result<Vec<BoxedBoardFeature>, BuildFeatureError> {
-        let mut builder = FeatureVecBuilder::new(self, raw, table_km);
+        let mut builder = FeatureVecBuilder::new(self, raw, km);
 
         builder.add_raw_penalty(&NumHoles)?;
-        builder.add_table_km(&NumHoles)?;
+        builder.add_km(&NumHoles)?;
 
         builder.add_raw_penalty(&SumOfHoleDepth)?;
-        builder.add_table_km(&SumOfHoleDepth)?;
+        builder.add_km(&SumOfHoleDepth)?;
+
+        builder.add_raw_penalty(&RowsWithHoles)?;
+        builder.add_km(&RowsWithHoles)?;
+
+        builder.add_raw_penalty(&CellsAboveHoles)?;
+        builder.add_km(&CellsAboveHoles)?;
 
         builder.add_raw_penalty(&MaxHeight)?;
         builder.add_raw_risk(&MaxHeight)?;
-        builder.add_table_km(&MaxHeight)?;
+        builder.add_km(&MaxHeight)?;
 
         builder.add_raw_penalty(&CenterColumnMaxHeight)?;
         builder.add_raw_risk(&CenterColumnMaxHeight)?;
-        builder.add_table_km(&CenterColumnMaxHeight)?;
+        builder.add_km(&CenterColumnMaxHeight)?;
 
         builder.add_raw_penalty(&TotalHeight)?;
-        builder.add_table_km(&TotalHeight)?;
+        builder.add_km(&TotalHeight)?;
+
+        builder.add_raw_penalty(&LandingHeight)?;
+        builder.add_km(&LandingHeight)?;
+
+        builder.add_raw_penalty(&UnfittablePieceKinds)?;
+        builder.add_km(&UnfittablePieceKinds)?;
 
         Ok(builder.features)
     }
@@ -283,6 +445,9 @@ impl FeatureBuilder {
     /// - Surface metrics (bumpiness, roughness)
     /// - Transition metrics (row, column)
     /// - Well depth
+    /// - Reachable overhangs
+    /// - Parity (checkerboard, column)
+    /// - I-dependencies
     ///
     /// # Returns
     ///
@@ -295,6 +460,10 @@ impl FeatureBuilder {
             self.build_raw_penalty_for(&ColumnTransitions)?,
             self.build_raw_penalty_for(&SumOfWellDepth)?,
             self.build_raw_risk_for(&SumOfWellDepth)?,
+            self.build_raw_penalty_for(&ReachableOverhangs)?,
+            self.build_raw_penalty_for(&CheckerboardParityImbalance)?,
+            self.build_raw_penalty_for(&ColumnParityImbalance)?,
+            self.build_raw_penalty_for(&IDependencies)?,
         ])
     }
 
@@ -302,13 +471,103 @@ impl FeatureBuilder {
     ///
     /// Constructs features that directly relate to game score:
     /// - Line clear bonus (discrete rewards)
-    /// - I-piece well reward (strategic bonus)
+    /// - I-piece well rewards for edge wells and the deepest open well (strategic bonus)
+    /// - Open well location (table transform only, since it is categorical)
+    /// - Eroded piece cells, T-slots, open well cleanliness and tetris readiness
+    ///   (with optional raw and table transforms)
+    /// - Held I piece and queue fit count, which need the piece queue after the turn
+    ///   (with optional raw and table transforms, only if queue features are enabled)
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// Vector of score features (no normalization needed)
-    fn build_score_features(&self) -> Vec<BoxedBoardFeature> {
-        vec![self.build_line_clear_bonus(), self.build_i_well_reward()]
+    /// * `raw` - Include raw-transform features (reward)
+    /// * `km` - Include KM-based features with the given transform
+    fn build_score_features(
+        &self,
+        raw: bool,
+        km: Option<KmTransform>,
+    ) -> Result<Vec<BoxedBoardFeature>, BuildFeatureError> {
+        let mut builder = FeatureVecBuilder::new(self, raw, km);
+        builder.features.push(self.build_line_clear_bonus());
+        builder.features.push(self.build_i_well_reward());
+        builder.features.push(self.build_open_well_reward());
+
+        builder.add_km(&OpenWellColumn)?;
+
+        builder.add_raw_reward(&OpenWellCleanRows)?;
+        builder.add_km(&OpenWellCleanRows)?;
+
+        builder.add_raw_reward(&TetrisReady)?;
+        builder.add_km(&TetrisReady)?;
+
+        builder.add_raw_reward(&ErodedPieceCells)?;
+        builder.add_km(&ErodedPieceCells)?;
+
+        builder.add_raw_reward(&TSlotCount)?;
+        builder.add_km(&TSlotCount)?;
+
+        builder.add_raw_reward(&TSlotDepth)?;
+        builder.add_km(&TSlotDepth)?;
+
+        if self.queue_features {
+            builder.add_raw_reward(&HeldIPiece)?;
+            builder.add_km(&HeldIPiece)?;
+
+            builder.add_raw_reward(&QueueFitCount)?;
+            builder.add_km(&QueueFitCount)?;
+        }
+
+        Ok(builder.features)
+    }
+
+    /// Build interaction features with optional raw and table transforms
+    ///
+    /// Constructs pairwise interactions between survival features:
+    /// - Height × holes (product): holes are more dangerous on a high stack
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - Include interactions of raw-transform features (`_raw` suffix)
+    /// * `km` - Include interactions of KM-based features (e.g., `_table_km` suffix)
+    fn build_interaction_features(
+        &self,
+        raw: bool,
+        km: Option<KmTransform>,
+    ) -> Result<Vec<BoxedBoardFeature>, BuildFeatureError> {
+        let mut features = vec![];
+        if raw {
+            features.push(Self::build_interaction(
+                "max_height_x_num_holes_raw".into(),
+                "Max Height × Number of Holes (Raw)".into(),
+                InteractionOperator::Product,
+                self.build_raw_risk_for(&MaxHeight)?,
+                self.build_raw_penalty_for(&NumHoles)?,
+            ));
+        }
+        if let Some(km) = km {
+            features.push(Self::build_interaction(
+                format!("max_height_x_num_holes_{}", km.id_suffix()),
+                format!("Max Height × Number of Holes ({})", km.name_suffix()),
+                InteractionOperator::Product,
+                self.build_km_for(&MaxHeight, km)?,
+                self.build_km_for(&NumHoles, km)?,
+            ));
+        }
+        Ok(features)
+    }
+
+    /// Build an interaction feature combining two features.
+    ///
+    /// See [`InteractionFeature`] for how the operands are combined.
+    #[must_use]
+    pub fn build_interaction(
+        id: String,
+        name: String,
+        operator: InteractionOperator,
+        left: BoxedBoardFeature,
+        right: BoxedBoardFeature,
+    ) -> BoxedBoardFeature {
+        Box::new(InteractionFeature::new(id, name, operator, left, right))
     }
 
     /// Build a linear penalty feature with P05-P95 normalization range.
@@ -376,11 +635,62 @@ impl FeatureBuilder {
         )))
     }
 
+    /// Build a linear reward feature with P01-P99 normalization range.
+    ///
+    /// Linear rewards provide positive signals for beneficial measurements that are
+    /// zero for most placements (e.g., eroded piece cells), so the wider P01-P99 range
+    /// is used to keep the non-zero tail inside the normalization range.
+    ///
+    /// # Normalization strategy
+    ///
+    /// - **Range**: P01-P99
+    /// - **Signal**: Positive (higher raw values are better)
+    /// - **Clipping**: Values outside [P01, P99] are clipped
+    /// - **Mapping**: Linear interpolation from [P01, P99] → [0.0, 1.0]
+    fn build_raw_reward_for<S>(&self, source: &S) -> Result<BoxedBoardFeature, BuildFeatureError>
+    where
+        S: BoardFeatureSource,
+    {
+        let norm_param = self.get_param(source)?;
+        let param = RawTransformParam::new(
+            FeatureSignal::Positive,
+            norm_param.value_percentiles.p01,
+            norm_param.value_percentiles.p99,
+        );
+        Ok(Box::new(RawTransform::new(
+            format!("{}_raw_reward", source.id()),
+            format!("{} (Raw Reward)", source.name()),
+            source.clone_boxed(),
+            param,
+        )))
+    }
+
+    /// Build a KM survival feature for a given source using the given transform
+    fn build_km_for<S>(
+        &self,
+        source: &S,
+        km: KmTransform,
+    ) -> Result<BoxedBoardFeature, BuildFeatureError>
+    where
+        S: BoardFeatureSource,
+    {
+        match km {
+            KmTransform::Table => self.build_table_km_for(source),
+            KmTransform::PiecewiseLinear => self.build_piecewise_linear_km_for(source),
+            KmTransform::Spline => self.build_spline_km_for(source),
+            KmTransform::SurvivalProbability | KmTransform::RestrictedMean => {
+                self.build_survival_table_km_for(source, km)
+            }
+        }
+    }
+
     /// Build a table-based KM survival feature for a given source
     ///
     /// Creates a feature that transforms raw values through a lookup table
     /// of Kaplan-Meier median survival times. Each raw feature value maps
-    /// to its corresponding median survival time.
+    /// to its corresponding median survival time. Fully censored features have no median,
+    /// so they use restricted mean survival times with the
+    /// [`SurvivalMetric::RestrictedMeanSurvival`] metric instead.
     ///
     /// # Type Parameters
     ///
@@ -410,12 +720,16 @@ impl FeatureBuilder {
         S: BoardFeatureSource,
     {
         let norm_param = self.get_param(source)?;
+        let survival_table = &norm_param.survival_table;
+        let (table, metric) = survival_table.median_or_restricted_mean();
+        let (normalize_min, normalize_max) = table_range(table);
         let param = TableTransformParam::new(
-            norm_param.survival_table.feature_min_value,
-            norm_param.survival_table.normalize_min,
-            norm_param.survival_table.normalize_max,
-            norm_param.survival_table.median_survival_turns.clone(),
-        );
+            survival_table.feature_min_value,
+            normalize_min,
+            normalize_max,
+            table.to_vec(),
+        )
+        .with_metric(metric);
         Ok(Box::new(TableTransform::new(
             format!("{}_table_km", source.id()),
             format!("{} (Table KM)", source.name()),
@@ -424,6 +738,214 @@ impl FeatureBuilder {
         )))
     }
 
+    /// Build a table-based KM feature from survival probabilities or restricted mean times
+    ///
+    /// Same as [`build_table_km_for()`](Self::build_table_km_for), but each raw feature
+    /// value maps to the KM survival probability at the horizon
+    /// ([`KmTransform::SurvivalProbability`]) or the KM restricted mean survival time up to
+    /// the horizon ([`KmTransform::RestrictedMean`]). Both are defined for heavily censored
+    /// values, for which the median is not.
+    ///
+    /// # Feature ID Format
+    ///
+    /// `{source_id}_survival_km` / `{source_id}_rmst_km` (e.g., "`num_holes_rmst_km`")
+    fn build_survival_table_km_for<S>(
+        &self,
+        source: &S,
+        km: KmTransform,
+    ) -> Result<BoxedBoardFeature, BuildFeatureError>
+    where
+        S: BoardFeatureSource,
+    {
+        let norm_param = self.get_param(source)?;
+        let survival_table = &norm_param.survival_table;
+        let horizon = survival_table.horizon;
+        let (table, metric) = match km {
+            KmTransform::SurvivalProbability => (
+                &survival_table.survival_probabilities,
+                SurvivalMetric::SurvivalProbability { horizon },
+            ),
+            KmTransform::RestrictedMean => (
+                &survival_table.restricted_mean_turns,
+                SurvivalMetric::RestrictedMeanSurvival { horizon },
+            ),
+            KmTransform::Table | KmTransform::PiecewiseLinear | KmTransform::Spline => {
+                unreachable!("not a survival table transform: {km:?}")
+            }
+        };
+        let (normalize_min, normalize_max) = table_range(table);
+        let param = TableTransformParam::new(
+            survival_table.feature_min_value,
+            normalize_min,
+            normalize_max,
+            table.clone(),
+        )
+        .with_metric(metric);
+        Ok(Box::new(TableTransform::new(
+            format!("{}_{}", source.id(), km.id_suffix()),
+            format!("{} ({})", source.name(), km.name_suffix()),
+            source.clone_boxed(),
+            param,
+        )))
+    }
+
+    /// Build a monotone piecewise-linear KM survival feature for a given source
+    ///
+    /// Knots are placed at the P05, P25, P50, P75 and P95 feature values, with outputs
+    /// taken from the KM survival table and scaled to \[0.0, 1.0\]. The direction
+    /// (better with higher or lower values) is decided by comparing both ends, and
+    /// outputs are made monotone with isotonic regression.
+    ///
+    /// # Feature ID Format
+    ///
+    /// `{source_id}_pwl_km` (e.g., "`num_holes_pwl_km`")
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn build_piecewise_linear_km_for<S>(
+        &self,
+        source: &S,
+    ) -> Result<BoxedBoardFeature, BuildFeatureError>
+    where
+        S: BoardFeatureSource,
+    {
+        let norm_param = self.get_param(source)?;
+        let feature_min_value = norm_param.survival_table.feature_min_value;
+        let (table, _) = norm_param.survival_table.median_or_restricted_mean();
+        let percentiles = &norm_param.value_percentiles;
+
+        let table_max = feature_min_value + (table.len() - 1) as u32;
+        let mut knots = [
+            percentiles.p05,
+            percentiles.p25,
+            percentiles.p50,
+            percentiles.p75,
+            percentiles.p95,
+        ]
+        .map(|p| (p.round().max(0.0) as u32).clamp(feature_min_value, table_max))
+        .to_vec();
+        knots.dedup();
+
+        let (normalize_min, normalize_max) = table_range(table);
+        let range = normalize_max - normalize_min;
+        let mut outputs = knots
+            .iter()
+            .map(|&k| {
+                let survival = table[(k - feature_min_value) as usize];
+                if range > 0.0 {
+                    f64::from((survival - normalize_min) / range)
+                } else {
+                    0.5
+                }
+            })
+            .collect::<Vec<_>>();
+        let decreasing = outputs[outputs.len() - 1] < outputs[0];
+        let signal = if decreasing {
+            for y in &mut outputs {
+                *y = 1.0 - *y;
+            }
+            FeatureSignal::Negative
+        } else {
+            FeatureSignal::Positive
+        };
+        let outputs = isotonic_regression(&outputs, &vec![1.0; outputs.len()]);
+
+        #[expect(clippy::cast_precision_loss)]
+        let param = PiecewiseLinearParam::new(
+            signal,
+            knots.iter().map(|&k| k as f32).collect(),
+            outputs
+                .iter()
+                .map(|&y| (y as f32).clamp(0.0, 1.0))
+                .collect(),
+        )
+        .map_err(|e| BuildFeatureError::invalid_transform_param(source, e))?;
+        Ok(Box::new(PiecewiseLinearTransform::new(
+            format!("{}_pwl_km", source.id()),
+            format!("{} (Piecewise-Linear KM)", source.name()),
+            source.clone_boxed(),
+            param,
+        )))
+    }
+
+    /// Build a smoothing-spline KM survival feature for a given source
+    ///
+    /// Fits a cubic smoothing spline to the KM median survival of each feature value in
+    /// the P05-P95 range, weighted by the number of boards with that value. Noisy medians
+    /// of rare values are smoothed out. Values beyond the range saturate at the extremes of
+    /// the fitted curve.
+    ///
+    /// # Feature ID Format
+    ///
+    /// `{source_id}_spline_km` (e.g., "`num_holes_spline_km`")
+    #[expect(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    fn build_spline_km_for<S>(&self, source: &S) -> Result<BoxedBoardFeature, BuildFeatureError>
+    where
+        S: BoardFeatureSource,
+    {
+        let norm_param = self.get_param(source)?;
+        let table = &norm_param.survival_table;
+
+        let (x, y, mut weights): (Vec<f64>, Vec<f64>, Vec<f64>) = if table.km_medians.is_empty() {
+            // No direct KM estimate in range: smooth the interpolated table instead
+            (0..)
+                .zip(table.median_or_restricted_mean().0)
+                .map(|(i, &median)| {
+                    (
+                        f64::from(table.feature_min_value + i),
+                        f64::from(median),
+                        1.0,
+                    )
+                })
+                .collect()
+        } else {
+            table
+                .km_medians
+                .iter()
+                .map(|p| {
+                    (
+                        f64::from(p.value),
+                        f64::from(p.median_survival_turns),
+                        p.boards_count as f64,
+                    )
+                })
+                .collect()
+        };
+        let mean_weight = weights.iter().sum::<f64>() / weights.len() as f64;
+        for w in &mut weights {
+            *w /= mean_weight;
+        }
+        let smoothing = if x.len() > 1 {
+            let mean_spacing = (x[x.len() - 1] - x[0]) / (x.len() - 1) as f64;
+            SPLINE_SMOOTHING * mean_spacing.powi(3)
+        } else {
+            0.0
+        };
+        let spline = SmoothingSpline::fit(&x, &y, &weights, smoothing)
+            .expect("feature values are strictly increasing");
+
+        let table_max = table.feature_min_value + table.restricted_mean_turns.len() as u32;
+        let fitted = (table.feature_min_value..table_max)
+            .map(|v| spline.evaluate(f64::from(v)))
+            .collect::<Vec<_>>();
+        let normalize_min = fitted.iter().copied().fold(f64::INFINITY, f64::min);
+        let normalize_max = fitted.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        let to_f32 = |v: &[f64]| v.iter().map(|&x| x as f32).collect();
+        let param = SplineTransformParam::new(
+            to_f32(&spline.knots),
+            to_f32(&spline.values),
+            to_f32(&spline.second_derivatives),
+            normalize_min as f32,
+            normalize_max as f32,
+        )
+        .map_err(|e| BuildFeatureError::invalid_transform_param(source, e))?;
+        Ok(Box::new(SplineTransform::new(
+            format!("{}_spline_km", source.id()),
+            format!("{} (Spline KM)", source.name()),
+            source.clone_boxed(),
+            param,
+        )))
+    }
+
     /// Build discrete bonus for line clears with emphasis on 4-line tetrises.
     ///
     /// Rewards efficient line clearing with strong preference for 4-line clears (tetrises).
@@ -464,6 +986,24 @@ impl FeatureBuilder {
         ))
     }
 
+    /// Build triangular reward for the deepest well in any column.
+    ///
+    /// Same transform as [`build_i_well_reward`](Self::build_i_well_reward), applied to
+    /// [`OpenWellDepth`] so that center and off-center wells are also rewarded.
+    ///
+    /// # Feature ID
+    ///
+    /// `open_well_reward`
+    #[expect(clippy::unused_self)]
+    fn build_open_well_reward(&self) -> BoxedBoardFeature {
+        let source = OpenWellDepth;
+        Box::new(IWellReward::new(
+            "open_well_reward".into(),
+            "Open Well Reward".into(),
+            source,
+        ))
+    }
+
     /// Get normalization parameters for a specific feature source
     fn get_param<S>(&self, source: &S) -> Result<&BoardFeatureNormalizationParam, BuildFeatureError>
     where
@@ -476,3 +1016,10 @@ impl FeatureBuilder {
             })
     }
 }
+
+/// Minimum and maximum of a survival table (for normalization)
+fn table_range(table: &[f32]) -> (f32, f32) {
+    let min = table.iter().copied().min_by(f32::total_cmp).unwrap();
+    let max = table.iter().copied().max_by(f32::total_cmp).unwrap();
+    (min, max)
+}
@@ -34,6 +34,8 @@
 //! 1. **Load Session Data** ([`session::SessionCollection`]): Load gameplay sessions
 //! 2. **Group by Feature** ([`survival::SurvivalStatsMap`]): Collect survival times per feature value
 //! 3. **Kaplan-Meier Analysis**: Compute unbiased survival curves from censored data
+//! 4. **Survival Labels** ([`survival_regression::SurvivalRegressionData`]): Impute per-board
+//!    survival labels for fitting regression models (e.g., gradient-boosted trees)
 //!
 //! # Primary Use Cases
 //!
@@ -73,8 +75,13 @@
 //! let survival_stats = SurvivalStatsMap::collect_all_by_feature_value(&sessions, &sources);
 //!
 //! // 5. Generate normalization parameters
-//! let norm_params =
-//!     BoardFeatureNormalizationParamCollection::from_stats(&sources, &raw_stats, &survival_stats);
+//! let horizon = 250; // e.g., half of the session turn limit
+//! let norm_params = BoardFeatureNormalizationParamCollection::from_stats(
+//!     &sources,
+//!     &raw_stats,
+//!     &survival_stats,
+//!     horizon,
+//! );
 //!
 //! // 6. Build features with runtime parameters
 //! let builder = FeatureBuilder::new(norm_params);
@@ -124,10 +131,7 @@
 //!
 //! ```no_run
 //! use oxidris_analysis::{session::SessionData, survival::SurvivalStatsMap};
-//! use oxidris_evaluator::{
-//!     board_feature::{BoardFeatureSource, source::NumHoles},
-//!     placement_analysis::PlacementAnalysis,
-//! };
+//! use oxidris_evaluator::board_feature::{BoardFeatureSource, source::NumHoles};
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //!
 //! // Load sessions (in practice, load from file)
@@ -136,7 +140,7 @@
 //!
 //! // Group survival times by feature value
 //! let stats = SurvivalStatsMap::collect_by_group(&sessions, |_session, board| {
-//!     let analysis = PlacementAnalysis::from_board(&board.before_placement, board.placement);
+//!     let analysis = board.placement_analysis();
 //!     source.extract_raw(&analysis)
 //! });
 //!
@@ -160,3 +164,4 @@ pub mod sample;
 pub mod session;
 pub mod statistics;
 pub mod survival;
+pub mod survival_regression;
@@ -30,8 +30,13 @@
 //! let survival_stats = SurvivalStatsMap::collect_all_by_feature_value(&sessions, &sources);
 //!
 //! // Build normalization parameters from statistics
-//! let norm_params =
-//!     BoardFeatureNormalizationParamCollection::from_stats(&sources, &raw_stats, &survival_stats);
+//! let horizon = 250; // e.g., half of the session turn limit
+//! let norm_params = BoardFeatureNormalizationParamCollection::from_stats(
+//!     &sources,
+//!     &raw_stats,
+//!     &survival_stats,
+//!     horizon,
+//! );
 //!
 //! // Access percentiles for a specific source
 //! if let Some(param) = norm_params.get(&feature) {
@@ -47,7 +52,9 @@ use std::{
     iter,
 };
 
-use oxidris_evaluator::board_feature::{BoardFeatureSource, BoxedBoardFeatureSource};
+use oxidris_evaluator::board_feature::{
+    BoardFeatureSource, BoxedBoardFeatureSource, transform::SurvivalMetric,
+};
 
 use crate::{statistics::RawFeatureStatistics, survival::SurvivalStatsMap};
 
@@ -96,13 +103,24 @@ pub struct ValuePercentiles {
 
 /// Survival table for table-based feature transformations
 ///
-/// Contains a lookup table mapping feature values to their Kaplan-Meier
-/// median survival times, along with normalization parameters.
+/// Contains lookup tables mapping feature values to summaries of their
+/// Kaplan-Meier survival curves, along with normalization parameters:
+///
+/// - `median_survival_turns`: KM median survival time
+/// - `survival_probabilities`: KM survival probability at `horizon`
+/// - `restricted_mean_turns`: KM restricted mean survival time up to `horizon`
+///
+/// The median is undefined for values whose curve never drops below 0.5 (heavy
+/// censoring), and such values are filled by interpolation. If no value has a median
+/// (the feature is fully censored), the median table is empty and median-based
+/// transforms use the restricted mean table instead
+/// (see [`median_or_restricted_mean()`](Self::median_or_restricted_mean)). The other two
+/// tables are defined for every observed value.
 ///
 /// # Table Coverage
 ///
-/// The table covers feature values from `feature_min_value` to
-/// `feature_min_value + median_survival_turns.len() - 1` (inclusive).
+/// The tables cover feature values from `feature_min_value` to
+/// `feature_min_value + restricted_mean_turns.len() - 1` (inclusive).
 ///
 /// Values are typically derived from P05-P95 percentiles of the feature
 /// distribution to focus on the statistically significant range.
@@ -111,11 +129,29 @@ pub struct SurvivalTable {
     /// Minimum feature value covered by the table
     pub feature_min_value: u32,
     /// KM median survival time for each feature value
+    ///
+    /// Empty if no feature value has a KM median (the feature is fully censored).
     pub median_survival_turns: Vec<f32>,
-    /// Minimum survival time (for normalization)
-    pub normalize_min: f32,
-    /// Maximum survival time (for normalization)
-    pub normalize_max: f32,
+    /// Feature values in the table range that have a KM median survival time
+    /// (without interpolation), for fitting smooth curves
+    pub km_medians: Vec<KmMedianPoint>,
+    /// Time horizon of `survival_probabilities` and `restricted_mean_turns`
+    pub horizon: usize,
+    /// KM survival probability at `horizon` for each feature value
+    pub survival_probabilities: Vec<f32>,
+    /// KM restricted mean survival time up to `horizon` for each feature value
+    pub restricted_mean_turns: Vec<f32>,
+}
+
+/// KM median survival time observed for a single feature value
+#[derive(Debug, Clone, Copy)]
+pub struct KmMedianPoint {
+    /// Feature value
+    pub value: u32,
+    /// KM median survival time of boards with this value
+    pub median_survival_turns: f32,
+    /// Number of boards with this value
+    pub boards_count: usize,
 }
 
 impl BoardFeatureNormalizationParamCollection {
@@ -126,6 +162,7 @@ impl BoardFeatureNormalizationParamCollection {
     /// * `sources` - Feature sources (determines which parameters to compute)
     /// * `raw_stats` - Pre-computed raw feature statistics (one per source)
     /// * `survival_stats` - Pre-computed survival statistics (one per source)
+    /// * `horizon` - Time horizon for survival probability and restricted mean tables
     ///
     /// # Returns
     ///
@@ -135,11 +172,13 @@ impl BoardFeatureNormalizationParamCollection {
         sources: &[BoxedBoardFeatureSource],
         raw_stats: &[RawFeatureStatistics],
         survival_stats: &[SurvivalStatsMap<u32>],
+        horizon: usize,
     ) -> Self {
         let feature_params = iter::zip(sources, iter::zip(raw_stats, survival_stats))
             .map(|(source, (raw_stats, survival_stats))| {
                 let source_id = source.id();
-                let param = BoardFeatureNormalizationParam::from_stats(raw_stats, survival_stats);
+                let param =
+                    BoardFeatureNormalizationParam::from_stats(raw_stats, survival_stats, horizon);
                 (source_id.to_string(), param)
             })
             .collect();
@@ -170,6 +209,7 @@ impl BoardFeatureNormalizationParam {
     ///
     /// * `raw_stats` - Raw feature statistics (for percentiles)
     /// * `survival_stats` - Survival statistics grouped by feature value
+    /// * `horizon` - Time horizon for survival probability and restricted mean tables
     ///
     /// # Returns
     ///
@@ -178,10 +218,11 @@ impl BoardFeatureNormalizationParam {
     pub fn from_stats(
         raw_stats: &RawFeatureStatistics,
         survival_stats: &SurvivalStatsMap<u32>,
+        horizon: usize,
     ) -> Self {
         Self {
             value_percentiles: ValuePercentiles::from_raw_stats(raw_stats),
-            survival_table: SurvivalTable::from_survival_stats(survival_stats),
+            survival_table: SurvivalTable::from_survival_stats(survival_stats, horizon),
         }
     }
 }
@@ -208,79 +249,175 @@ impl ValuePercentiles {
 impl SurvivalTable {
     /// Create a survival table from survival statistics
     ///
-    /// Builds a lookup table mapping feature values to their Kaplan-Meier
-    /// median survival times. The table covers the P05-P95 range of feature
-    /// values, with linear interpolation for values without direct KM estimates.
+    /// Builds lookup tables mapping feature values to their Kaplan-Meier
+    /// median survival times, survival probabilities at `horizon` and restricted
+    /// mean survival times up to `horizon`. The tables cover the P05-P95 range of
+    /// feature values, with linear interpolation for values without direct KM estimates.
     ///
     /// # Arguments
     ///
     /// * `survival_stats` - Map of feature values to their survival statistics
+    /// * `horizon` - Time horizon for survival probability and restricted mean tables
     ///
     /// # Returns
     ///
-    /// A survival table covering P05-P95 range with KM median survival times
+    /// A survival table covering P05-P95 range with KM survival summaries
     ///
     /// # Algorithm
     ///
     /// 1. Find P05 and P95 feature values to define table range
     /// 2. For each value in [P05, P95]:
-    ///    - Use KM median if available
-    ///    - Otherwise, linearly interpolate between nearest values with KM medians
-    /// 3. Compute `normalize_min/max` from table values
+    ///    - Use KM estimate if available
+    ///    - Otherwise, linearly interpolate between nearest values with KM estimates
+    ///      (or use the nearest one when the value lies outside them)
+    /// 3. If no value has a KM median, leave the median table empty
     ///
     /// # Panics
     ///
     /// Panics if `survival_stats` is empty or lacks sufficient data for percentile calculation
-    #[expect(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    #[expect(clippy::cast_possible_truncation)]
     #[must_use]
-    pub fn from_survival_stats(survival_stats: &SurvivalStatsMap<u32>) -> Self {
+    pub fn from_survival_stats(survival_stats: &SurvivalStatsMap<u32>, horizon: usize) -> Self {
         let percentiles = survival_stats.filter_by_percentiles(&[0.05, 0.95]);
         let p05_value = **percentiles.first_key_value().unwrap().0;
         let p95_value = **percentiles.last_key_value().unwrap().0;
 
         let mut median_km_map = BTreeMap::new();
+        let mut survival_map = BTreeMap::new();
+        let mut rmst_map = BTreeMap::new();
+        let mut km_medians = vec![];
         for (key, stats) in &survival_stats.map {
+            survival_map.insert(*key, stats.km_curve.survival_at(horizon) as f32);
+            rmst_map.insert(
+                *key,
+                stats.km_curve.restricted_mean_survival(horizon) as f32,
+            );
             if let Some(median_km) = stats.median_km {
                 median_km_map.insert(*key, median_km as f32);
+                if (p05_value..=p95_value).contains(key) {
+                    km_medians.push(KmMedianPoint {
+                        value: *key,
+                        median_survival_turns: median_km as f32,
+                        boards_count: stats.boards_count,
+                    });
+                }
             }
         }
+        let survival_probabilities = interpolate_table(&survival_map, p05_value, p95_value);
+        let restricted_mean_turns = interpolate_table(&rmst_map, p05_value, p95_value);
+        // Fully censored feature: every median is undefined
+        let median_survival_turns = if median_km_map.is_empty() {
+            vec![]
+        } else {
+            interpolate_table(&median_km_map, p05_value, p95_value)
+        };
+
+        Self {
+            feature_min_value: p05_value,
+            median_survival_turns,
+            km_medians,
+            horizon,
+            survival_probabilities,
+            restricted_mean_turns,
+        }
+    }
 
-        let median_survival_turns = (p05_value..=p95_value)
-            .map(|value| {
-                if let Some(median_km) = median_km_map.get(&value) {
-                    *median_km
-                } else {
-                    // linear interpolation
-                    // find nearest lower and upper keys with median_km
-                    let (lower_key, lower_value) =
-                        median_km_map.range(..=value).next_back().unwrap();
-                    let (upper_key, upper_value) = median_km_map
-                        .range(value..)
-                        .next()
-                        .map(|(k, v)| (*k, *v))
-                        .unwrap();
+    /// Table used by median-based transforms, with the metric it holds
+    ///
+    /// This is the KM median table, or the restricted mean table
+    /// ([`SurvivalMetric::RestrictedMeanSurvival`]) if the feature is fully censored.
+    #[must_use]
+    pub fn median_or_restricted_mean(&self) -> (&[f32], SurvivalMetric) {
+        if self.median_survival_turns.is_empty() {
+            (
+                &self.restricted_mean_turns,
+                SurvivalMetric::RestrictedMeanSurvival {
+                    horizon: self.horizon,
+                },
+            )
+        } else {
+            (&self.median_survival_turns, SurvivalMetric::MedianSurvival)
+        }
+    }
+}
+
+/// Build a table for `min_value..=max_value` from sparse per-value estimates
+///
+/// Values without an estimate are linearly interpolated between the nearest lower and
+/// upper values with estimates, falling back to the nearest one if the value is outside
+/// them.
+///
+/// # Panics
+///
+/// Panics if `estimates` is empty.
+#[expect(clippy::cast_precision_loss)]
+fn interpolate_table(estimates: &BTreeMap<u32, f32>, min_value: u32, max_value: u32) -> Vec<f32> {
+    (min_value..=max_value)
+        .map(|value| {
+            if let Some(estimate) = estimates.get(&value) {
+                return *estimate;
+            }
+            let lower = estimates.range(..=value).next_back();
+            let upper = estimates.range(value..).next();
+            match (lower, upper) {
+                (Some((lower_key, lower_value)), Some((upper_key, upper_value))) => {
                     let ratio = (value - lower_key) as f32 / (upper_key - lower_key) as f32;
                     lower_value + ratio * (upper_value - lower_value)
                 }
-            })
-            .collect::<Vec<_>>();
+                (Some((_, nearest_value)), None) | (None, Some((_, nearest_value))) => {
+                    *nearest_value
+                }
+                (None, None) => panic!("no estimates to interpolate"),
+            }
+        })
+        .collect()
+}
 
-        let normalize_min = median_survival_turns
-            .iter()
-            .copied()
-            .min_by(f32::total_cmp)
-            .unwrap();
-        let normalize_max = median_survival_turns
-            .iter()
-            .copied()
-            .max_by(f32::total_cmp)
-            .unwrap();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::survival::SurvivalStats;
 
-        Self {
-            feature_min_value: p05_value,
-            median_survival_turns,
-            normalize_min,
-            normalize_max,
-        }
+    #[test]
+    fn test_survival_table_with_heavy_censoring() {
+        // value 0: all censored at 100 turns (KM median undefined)
+        // value 1: all die at 10 turns
+        let map = BTreeMap::from([
+            (0, SurvivalStats::from_data(&[(100, true); 10])),
+            (1, SurvivalStats::from_data(&[(10, false); 10])),
+        ]);
+        let table = SurvivalTable::from_survival_stats(&SurvivalStatsMap { map }, 50);
+
+        assert_eq!(table.feature_min_value, 0);
+        // the median of value 0 falls back to the nearest value with a median
+        assert_eq!(table.median_survival_turns, vec![10.0, 10.0]);
+        assert_eq!(table.survival_probabilities, vec![1.0, 0.0]);
+        assert_eq!(table.restricted_mean_turns, vec![50.0, 10.0]);
+    }
+
+    #[test]
+    fn test_survival_table_fully_censored() {
+        // value 0: all censored at 100 turns
+        // value 1: 4 die at 10 turns, 6 censored at 100 turns (survival stays at 0.6)
+        let mut data = vec![(10, false); 4];
+        data.extend([(100, true); 6]);
+        let map = BTreeMap::from([
+            (0, SurvivalStats::from_data(&[(100, true); 10])),
+            (1, SurvivalStats::from_data(&data)),
+        ]);
+        let table = SurvivalTable::from_survival_stats(&SurvivalStatsMap { map }, 50);
+
+        assert!(table.km_medians.is_empty());
+        assert!(table.median_survival_turns.is_empty());
+        // RMST at 50 turns of value 1: 10 × 1.0 + 40 × 0.6
+        assert_eq!(table.restricted_mean_turns, vec![50.0, 34.0]);
+        // median-based transforms fall back to the restricted mean table
+        assert_eq!(
+            table.median_or_restricted_mean(),
+            (
+                &[50.0, 34.0][..],
+                SurvivalMetric::RestrictedMeanSurvival { horizon: 50 }
+            )
+        );
     }
 }
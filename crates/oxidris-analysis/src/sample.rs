@@ -91,9 +91,8 @@
 //! }
 //! ```
 
-use oxidris_evaluator::{
-    board_feature::{BoardFeatureValue, BoxedBoardFeature, BoxedBoardFeatureSource},
-    placement_analysis::PlacementAnalysis,
+use oxidris_evaluator::board_feature::{
+    BoardFeatureValue, BoxedBoardFeature, BoxedBoardFeatureSource,
 };
 
 use crate::session::{BoardAndPlacement, SessionData};
@@ -117,7 +116,7 @@ impl RawBoardSample {
     /// This is used for computing statistics.
     #[must_use]
     pub fn from_board(sources: &[BoxedBoardFeatureSource], board: &BoardAndPlacement) -> Self {
-        let analysis = PlacementAnalysis::from_board(&board.before_placement, board.placement);
+        let analysis = board.placement_analysis();
         let raw_values = sources
             .iter()
             .map(|source| source.extract_raw(&analysis))
@@ -208,7 +207,7 @@ impl BoardSample {
     /// ```
     #[must_use]
     pub fn from_board(features: &[BoxedBoardFeature], board: &BoardAndPlacement) -> Self {
-        let analysis = PlacementAnalysis::from_board(&board.before_placement, board.placement);
+        let analysis = board.placement_analysis();
         let feature_vector = features
             .iter()
             .map(|feature| feature.compute_feature_value(&analysis))
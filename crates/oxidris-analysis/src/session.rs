@@ -24,7 +24,8 @@
 //!     └─ boards: Vec<BoardAndPlacement>
 //!         ├─ turn number
 //!         ├─ board state (before placement)
-//!         └─ piece placed
+//!         ├─ piece placed
+//!         └─ piece queue after the turn (optional)
 //! ```
 //!
 //! # Right-Censored Data
@@ -109,6 +110,7 @@
 //! ```
 
 use oxidris_engine::{BitBoard, Piece};
+use oxidris_evaluator::placement_analysis::{PieceQueue, PlacementAnalysis};
 use serde::{Deserialize, Serialize};
 
 /// Collection of game sessions with board states captured during training data generation.
@@ -141,6 +143,14 @@ pub struct SessionData {
     pub boards: Vec<BoardAndPlacement>,
 }
 
+impl SessionData {
+    /// Returns `true` if any captured board records the piece queue after its turn.
+    #[must_use]
+    pub fn has_queue(&self) -> bool {
+        self.boards.iter().any(|board| board.queue.is_some())
+    }
+}
+
 /// A captured board state with the piece that was placed on it.
 ///
 /// Represents a single training data point, containing the board state before
@@ -153,4 +163,34 @@ pub struct BoardAndPlacement {
     pub before_placement: BitBoard,
     /// Piece that was placed on this board
     pub placement: Piece,
+    /// Held piece and upcoming pieces after the turn
+    ///
+    /// Missing in data generated before queues were recorded; queue-aware features then
+    /// see no held piece and no upcoming pieces.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queue: Option<PieceQueue>,
+}
+
+impl BoardAndPlacement {
+    /// Captures the board before a placement together with the placement and piece queue
+    /// of its analysis.
+    #[must_use]
+    pub fn new(turn: usize, before_placement: BitBoard, analysis: &PlacementAnalysis) -> Self {
+        Self {
+            turn,
+            before_placement,
+            placement: *analysis.placement(),
+            queue: analysis.queue().cloned(),
+        }
+    }
+
+    /// Analyzes the recorded placement, including the piece queue if recorded.
+    #[must_use]
+    pub fn placement_analysis(&self) -> PlacementAnalysis {
+        let analysis = PlacementAnalysis::from_board(&self.before_placement, self.placement);
+        match &self.queue {
+            Some(queue) => analysis.with_queue(queue.clone()),
+            None => analysis,
+        }
+    }
 }
@@ -65,17 +65,14 @@
 //!
 //! ```no_run
 //! use oxidris_analysis::{session::SessionData, survival::SurvivalStatsMap};
-//! use oxidris_evaluator::{
-//!     board_feature::{BoardFeatureSource, source::NumHoles},
-//!     placement_analysis::PlacementAnalysis,
-//! };
+//! use oxidris_evaluator::board_feature::{BoardFeatureSource, source::NumHoles};
 //!
 //! let sessions: Vec<SessionData> = vec![]; // Load from file
 //! let source = NumHoles;
 //!
 //! // Group survival times by number of holes
 //! let stats_map = SurvivalStatsMap::collect_by_group(&sessions, |_session, board| {
-//!     let analysis = PlacementAnalysis::from_board(&board.before_placement, board.placement);
+//!     let analysis = board.placement_analysis();
 //!     source.extract_raw(&analysis)
 //! });
 //!
@@ -91,14 +88,11 @@
 //!
 //! ```no_run
 //! use oxidris_analysis::{session::SessionData, survival::SurvivalStatsMap};
-//! # use oxidris_evaluator::{
-//! #     board_feature::{source::NumHoles, BoardFeatureSource},
-//! #     placement_analysis::PlacementAnalysis,
-//! # };
+//! # use oxidris_evaluator::board_feature::{BoardFeatureSource, source::NumHoles};
 //! # let sessions: Vec<SessionData> = vec![];
 //! # let source = NumHoles;
 //! # let stats_map = SurvivalStatsMap::collect_by_group(&sessions, |_session, board| {
-//! #     let analysis = PlacementAnalysis::from_board(&board.before_placement, board.placement);
+//! #     let analysis = board.placement_analysis();
 //! #     source.extract_raw(&analysis)
 //! # });
 //!
@@ -122,7 +116,7 @@
 
 use std::collections::BTreeMap;
 
-use oxidris_evaluator::{board_feature::BoardFeatureSource, placement_analysis::PlacementAnalysis};
+use oxidris_evaluator::board_feature::BoardFeatureSource;
 use oxidris_stats::survival::KaplanMeierCurve;
 
 use crate::session::{BoardAndPlacement, SessionData};
@@ -212,17 +206,14 @@ impl<K> SurvivalStatsMap<K> {
     ///
     /// ```no_run
     /// use oxidris_analysis::{session::SessionData, survival::SurvivalStatsMap};
-    /// use oxidris_evaluator::{
-    ///     board_feature::{BoardFeatureSource, source::NumHoles},
-    ///     placement_analysis::PlacementAnalysis,
-    /// };
+    /// use oxidris_evaluator::board_feature::{BoardFeatureSource, source::NumHoles};
     ///
     /// let sessions: Vec<SessionData> = vec![];
     /// let source = NumHoles;
     ///
     /// // Group by number of holes
     /// let stats = SurvivalStatsMap::collect_by_group(&sessions, |_session, board| {
-    ///     let analysis = PlacementAnalysis::from_board(&board.before_placement, board.placement);
+    ///     let analysis = board.placement_analysis();
     ///     source.extract_raw(&analysis)
     /// });
     ///
@@ -341,10 +332,7 @@ impl<K> SurvivalStatsMap<K> {
     ///
     /// ```no_run
     /// use oxidris_analysis::{session::SessionData, survival::SurvivalStatsMap};
-    /// use oxidris_evaluator::{
-    ///     board_feature::{BoardFeatureSource, source::NumHoles},
-    ///     placement_analysis::PlacementAnalysis,
-    /// };
+    /// use oxidris_evaluator::board_feature::{BoardFeatureSource, source::NumHoles};
     ///
     /// let sessions: Vec<SessionData> = vec![];
     /// let source = NumHoles;
@@ -353,7 +341,7 @@ impl<K> SurvivalStatsMap<K> {
     /// let stats = SurvivalStatsMap::collect_with_adaptive_binning(
     ///     &sessions,
     ///     |_session, board| {
-    ///         let analysis = PlacementAnalysis::from_board(&board.before_placement, board.placement);
+    ///         let analysis = board.placement_analysis();
     ///         source.extract_raw(&analysis)
     ///     },
     ///     0.03, // 3% per bin
@@ -426,8 +414,7 @@ impl SurvivalStatsMap<u32> {
         Self::collect_with_adaptive_binning(
             sessions,
             |_, board| {
-                let analysis =
-                    PlacementAnalysis::from_board(&board.before_placement, board.placement);
+                let analysis = board.placement_analysis();
                 feature.extract_raw(&analysis)
             },
             target_sample_percentage,
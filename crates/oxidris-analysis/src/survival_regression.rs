@@ -0,0 +1,136 @@
+//! Per-board survival labels for regression models
+//!
+//! This module turns recorded sessions into `(feature vector, label)` pairs, where the
+//! label is the number of turns the board is expected to survive. Regression models
+//! (e.g., gradient-boosted trees in `oxidris-training`) can then be fitted to predict
+//! survival directly from board features.
+//!
+//! # Labels and Censoring
+//!
+//! Each board's survival time is the number of remaining turns until its session ended.
+//! Sessions that reached the turn limit are right-censored: the board survived *at least*
+//! that long. Using the censored time as-is would underestimate survival, so labels are
+//! imputed from the Kaplan-Meier curve of all boards, restricted to `horizon` turns:
+//!
+//! - **Event** (game over after `t` turns): `min(t, horizon)`
+//! - **Censored** at `t < horizon`: `t + E[min(T, horizon) - t | T > t]`, i.e.,
+//!   `t + (RMST(horizon) - RMST(t)) / S(t)`
+//! - **Censored** at `t >= horizon`: `horizon`
+//!
+//! where `S` is the KM survival function and `RMST` the restricted mean survival time.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use oxidris_analysis::{session::SessionCollection, survival_regression::SurvivalRegressionData};
+//! use oxidris_evaluator::board_feature::BoxedBoardFeature;
+//!
+//! let collection: SessionCollection = todo!();
+//! let features: Vec<BoxedBoardFeature> = todo!();
+//!
+//! let data =
+//!     SurvivalRegressionData::from_sessions(&features, &collection.sessions, collection.max_turns);
+//! println!("{} samples", data.labels.len());
+//! ```
+
+use oxidris_evaluator::board_feature::BoxedBoardFeature;
+use oxidris_stats::survival::KaplanMeierCurve;
+
+use crate::session::SessionData;
+
+/// Feature vectors and KM-imputed survival labels for all boards in a set of sessions
+#[derive(Debug, Clone)]
+pub struct SurvivalRegressionData {
+    /// Normalized feature values for each board (one entry per feature)
+    pub inputs: Vec<Vec<f32>>,
+    /// Expected survival turns for each board (capped at the horizon)
+    pub labels: Vec<f32>,
+}
+
+impl SurvivalRegressionData {
+    /// Collect feature vectors and survival labels from session data
+    ///
+    /// # Arguments
+    ///
+    /// * `features` - Features whose normalized values form the input vectors
+    /// * `sessions` - Session data containing board states
+    /// * `horizon` - Maximum survival time considered (typically the session turn limit)
+    #[must_use]
+    pub fn from_sessions(
+        features: &[BoxedBoardFeature],
+        sessions: &[SessionData],
+        horizon: usize,
+    ) -> Self {
+        let observations = sessions
+            .iter()
+            .flat_map(|session| {
+                session
+                    .boards
+                    .iter()
+                    .map(|board| (session.survived_turns - board.turn, !session.is_game_over))
+            })
+            .collect::<Vec<_>>();
+        let curve = KaplanMeierCurve::from_data(observations.clone());
+
+        let inputs = sessions
+            .iter()
+            .flat_map(|session| &session.boards)
+            .map(|board| {
+                let analysis = board.placement_analysis();
+                features
+                    .iter()
+                    .map(|f| f.compute_feature_value(&analysis).normalized)
+                    .collect()
+            })
+            .collect();
+        let labels = observations
+            .iter()
+            .map(|&(time, is_censored)| survival_label(&curve, time, is_censored, horizon))
+            .collect();
+
+        Self { inputs, labels }
+    }
+}
+
+/// Compute the KM-imputed survival label of a single observation
+///
+/// See the [module documentation](self) for the formula.
+#[expect(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+#[must_use]
+pub fn survival_label(
+    curve: &KaplanMeierCurve,
+    time: usize,
+    is_censored: bool,
+    horizon: usize,
+) -> f32 {
+    if !is_censored || time >= horizon {
+        return time.min(horizon) as f32;
+    }
+    let survival = curve.survival_at(time);
+    if survival <= 0.0 {
+        return time as f32;
+    }
+    let remaining = curve.restricted_mean_survival(horizon) - curve.restricted_mean_survival(time);
+    (time as f64 + remaining / survival) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_survival_label() {
+        // S(t) = 1.0 for t < 10, 0.5 for 10 <= t < 20, 0.0 afterwards
+        let curve = KaplanMeierCurve::from_data(vec![(10, false), (20, false), (5, true)]);
+        assert!((curve.survival_at(10) - 0.5).abs() < 1e-9);
+
+        // events are capped at the horizon
+        assert!((survival_label(&curve, 15, false, 30) - 15.0).abs() < 1e-6);
+        assert!((survival_label(&curve, 40, false, 30) - 30.0).abs() < 1e-6);
+        // censored at 5: 5 + (RMST(30) - RMST(5)) / S(5)
+        //   RMST(30) = 10 × 1.0 + 10 × 0.5 = 15, RMST(5) = 5, S(5) = 1.0
+        assert!((survival_label(&curve, 5, true, 30) - 15.0).abs() < 1e-5);
+        // censored beyond the horizon
+        assert!((survival_label(&curve, 35, true, 30) - 30.0).abs() < 1e-6);
+    }
+}
@@ -79,10 +79,12 @@ pub fn run(arg: &AnalyzeBoardFeaturesArg) -> anyhow::Result<()> {
     let AnalyzeBoardFeaturesArg { boards_file } = arg;
 
     eprintln!("Loading boards from {}...", boards_file.display());
-    let sessions = util::read_boards_file(boards_file)?.sessions;
+    let collection = util::read_boards_file(boards_file)?;
+    let sessions = collection.sessions;
     eprintln!("Loaded {} sessions", sessions.len());
 
-    let features = util::build_feature_from_session(FeatureSet::All, &sessions)?;
+    let features =
+        util::build_feature_from_session(FeatureSet::All, &sessions, collection.max_turns)?;
 
     eprintln!("Computing features for all boards...");
     let board_samples = BoardSample::from_sessions(&features, &sessions);
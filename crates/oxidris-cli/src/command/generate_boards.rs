@@ -3,8 +3,9 @@ use std::{collections::HashMap, fmt, path::PathBuf};
 use oxidris_analysis::session::{BoardAndPlacement, SessionCollection, SessionData};
 use oxidris_engine::{GameField, GameStats};
 use oxidris_evaluator::{
-    placement_analysis::PlacementAnalysis, placement_evaluator::PlacementEvaluator,
-    turn_evaluator::TurnEvaluator,
+    placement_analysis::PlacementAnalysis,
+    placement_evaluator::PlacementEvaluator,
+    turn_evaluator::{TurnEvaluator, TurnSelection},
 };
 use rand::Rng;
 
@@ -18,11 +19,27 @@ pub(crate) struct GenerateBoardsArg {
     /// Number of boards to generate
     #[arg(long, default_value_t = 100000)]
     num_boards: usize,
+    /// Softmax temperature for stochastic turn selection (greedy if omitted)
+    #[arg(long, conflicts_with = "epsilon")]
+    temperature: Option<f32>,
+    /// Probability of choosing a uniformly random turn (greedy if omitted)
+    #[arg(long)]
+    epsilon: Option<f32>,
     /// Output file path
     #[arg(long)]
     output: Option<PathBuf>,
 }
 
+impl GenerateBoardsArg {
+    fn turn_selection(&self) -> TurnSelection {
+        match (self.temperature, self.epsilon) {
+            (Some(temperature), _) => TurnSelection::Softmax { temperature },
+            (None, Some(epsilon)) => TurnSelection::EpsilonGreedy { epsilon },
+            (None, None) => TurnSelection::Greedy,
+        }
+    }
+}
+
 type BoxedPlacementEvaluator = Box<dyn PlacementEvaluator>;
 #[derive(Debug, Clone)]
 struct PlacementEvaluatorFactory {
@@ -176,7 +193,13 @@ fn select_weighted_evaluator<R: Rng>(
 }
 
 pub(crate) fn run(arg: &GenerateBoardsArg) -> anyhow::Result<()> {
-    let GenerateBoardsArg { num_boards, output } = arg;
+    let GenerateBoardsArg {
+        num_boards,
+        temperature: _,
+        epsilon: _,
+        output,
+    } = arg;
+    let turn_selection = arg.turn_selection();
     let placement_evaluators: &[PlacementEvaluatorFactory] = &[
         PlacementEvaluatorFactory::new("random", RandomPlacementEvaluator::boxed, 1),
         PlacementEvaluatorFactory::new("height_only", HeightOnlyEvaluator::boxed, 1),
@@ -211,13 +234,11 @@ pub(crate) fn run(arg: &GenerateBoardsArg) -> anyhow::Result<()> {
             boards: vec![],
         };
         let mut capture_interval = CAPTURE_INTERVAL;
-        while let Some((turn_plan, analysis)) = turn_evaluator.select_best_turn(&field, true) {
+        while let Some((turn_plan, analysis)) =
+            turn_evaluator.select_turn(&field, true, turn_selection, &mut rng)
+        {
             let turn = stats.turn();
-            let capture_board = BoardAndPlacement {
-                turn,
-                before_placement: field.board().clone(),
-                placement: turn_plan.placement(),
-            };
+            let capture_board = BoardAndPlacement::new(turn, field.board().clone(), &analysis);
             let (_cleared_lines, result) = turn_plan.apply(&analysis, &mut field, &mut stats);
             if result.is_err() {
                 session_data.is_game_over = true;
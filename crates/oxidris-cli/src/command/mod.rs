@@ -2,7 +2,7 @@ use clap::{Parser, Subcommand};
 
 use self::{
     analyze_board_features::AnalyzeBoardFeaturesArg, analyze_censoring::AnalyzeCensoringArg,
-    generate_boards::GenerateBoardsArg, train_ai::TrainAiArg,
+    generate_boards::GenerateBoardsArg, train_ai::TrainAiArg, train_tree::TrainTreeArg,
 };
 use crate::command::{
     play::{AutoPlayArg, ManualPlayArg},
@@ -15,6 +15,7 @@ mod generate_boards;
 mod play;
 mod replay;
 mod train_ai;
+mod train_tree;
 
 #[derive(Debug, Clone, Parser)]
 #[command(author, version, about, long_about = None)]
@@ -33,6 +34,8 @@ enum Mode {
     Replay(#[clap(flatten)] ReplayArg),
     /// Train AI using genetic algorithm
     TrainAi(#[clap(flatten)] TrainAiArg),
+    /// Train a gradient-boosted tree AI on survival labels
+    TrainTree(#[clap(flatten)] TrainTreeArg),
     /// Generate boards for training data
     GenerateBoards(#[clap(flatten)] GenerateBoardsArg),
     /// Analyze board features with TUI
@@ -51,6 +54,7 @@ pub fn run() -> anyhow::Result<()> {
         Mode::AutoPlay(arg) => play::run_auto(&arg)?,
         Mode::Replay(arg) => replay::run(&arg)?,
         Mode::TrainAi(arg) => train_ai::run(&arg)?,
+        Mode::TrainTree(arg) => train_tree::run(&arg)?,
         Mode::GenerateBoards(arg) => generate_boards::run(&arg)?,
         Mode::AnalyzeBoardFeatures(arg) => analyze_board_features::run(&arg)?,
         Mode::AnalyzeCensoring(arg) => analyze_censoring::run(&arg)?,
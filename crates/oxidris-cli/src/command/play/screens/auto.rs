@@ -8,7 +8,6 @@ use crossterm::event::{Event, KeyCode, KeyEvent};
 use oxidris_engine::{GameSession, SessionState};
 use oxidris_evaluator::{
     placement_analysis::PlacementAnalysis,
-    placement_evaluator::FeatureBasedPlacementEvaluator,
     turn_evaluator::{TurnEvaluator, TurnPlan},
 };
 use ratatui::{
@@ -334,9 +333,8 @@ struct AutoPlay {
 
 impl AutoPlay {
     fn new(session: RecordingSession, model: &AiModel) -> anyhow::Result<Self> {
-        let (features, weights) = model.to_feature_weights()?;
-        let placement_evaluator = FeatureBasedPlacementEvaluator::new(features, weights);
-        let turn_evaluator = TurnEvaluator::new(Box::new(placement_evaluator));
+        let turn_evaluator =
+            TurnEvaluator::new(model.to_placement_evaluator()?).with_parallel(true);
         Ok(Self {
             session,
             turn_evaluator,
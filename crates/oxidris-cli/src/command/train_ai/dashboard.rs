@@ -0,0 +1,678 @@
+//! Live training dashboard of `train-ai --tui`.
+//!
+//! Training runs in a background thread and sends its progress to the dashboard as
+//! [`TrainingEvent`]s. Closing the dashboard drops the receiver, which makes the training
+//! thread stop after the current generation and save its results.
+
+use std::{
+    sync::mpsc::{self, TryRecvError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crossterm::event::{Event, KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect, Spacing},
+    style::{Color, Modifier, Style},
+    symbols::{Marker, merge::MergeStrategy},
+    text::{Line, Span},
+    widgets::{
+        Axis, Block, Chart, Dataset, GraphType, List, ListItem, ListState, Paragraph,
+        StatefulWidget, Widget,
+    },
+};
+use ratatui_runtime::{RenderMode, Runtime, Screen, ScreenStack, ScreenTransition};
+
+use super::{RunOutputs, TrainingJob};
+use crate::{
+    DEFAULT_FRAME_RATE,
+    schema::training_log::{GenerationLog, IndividualLog, StatsLog},
+    view::widgets::{KeyBinding, KeyBindingDisplay},
+};
+
+const TICK_RATE: f64 = 10.0;
+
+/// Progress of the training thread.
+#[derive(Debug)]
+pub(super) enum TrainingEvent {
+    Started(TrainingOverview),
+    Generation(Box<GenerationLog>),
+    /// Training finished; the error message if it failed
+    Finished(Result<(), String>),
+}
+
+#[derive(Debug)]
+pub(super) struct TrainingOverview {
+    pub(super) title: String,
+    /// Names of the weights of an individual
+    pub(super) weight_names: Vec<String>,
+    pub(super) budget: TrainingBudget,
+}
+
+/// Amount of work of a training run, used to estimate the remaining time.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum TrainingBudget {
+    /// Generations `first..total`
+    Generations { first: usize, total: usize },
+    /// Number of individuals to evaluate
+    Evaluations(usize),
+}
+
+/// Runs `job` in a background thread while showing the dashboard.
+pub(super) fn run(job: TrainingJob, mut outputs: RunOutputs) -> anyhow::Result<()> {
+    let (tx, rx) = mpsc::channel();
+    outputs.log.connect_dashboard(tx.clone());
+    let training = thread::spawn(move || training_thread(job, outputs, &tx));
+
+    let mut app = ScreenStack::new(Box::new(TrainingDashboardScreen::new(rx)));
+    Runtime::new().run(&mut app)?;
+    drop(app);
+
+    if !training.is_finished() {
+        eprintln!("Stopping training after the current generation and saving the results...");
+    }
+    training.join().unwrap()
+}
+
+fn training_thread(
+    job: TrainingJob,
+    outputs: RunOutputs,
+    tx: &mpsc::Sender<TrainingEvent>,
+) -> anyhow::Result<()> {
+    let result = job.run(outputs);
+    let finished = result.as_ref().map_err(|e| format!("{e:#}")).copied();
+    // The dashboard may already be closed
+    let _ = tx.send(TrainingEvent::Finished(finished));
+    result
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Action {
+    SelectPrev,
+    SelectNext,
+    NextObjective,
+    Quit,
+}
+
+impl Action {
+    fn from_key_event(event: &KeyEvent) -> Option<Self> {
+        match event.code {
+            KeyCode::Up => Some(Self::SelectPrev),
+            KeyCode::Down => Some(Self::SelectNext),
+            KeyCode::Char('o') => Some(Self::NextObjective),
+            KeyCode::Char('q') | KeyCode::Esc => Some(Self::Quit),
+            _ => None,
+        }
+    }
+
+    fn bindings(multi_objective: bool, running: bool) -> Vec<KeyBinding<'static>> {
+        let mut bindings: Vec<KeyBinding<'static>> = vec![(&["↑", "↓"], "Select Weight")];
+        if multi_objective {
+            bindings.push((&["o"], "Next Objective"));
+        }
+        bindings.push((
+            &["q", "Esc"],
+            if running {
+                "Quit (stop training)"
+            } else {
+                "Quit"
+            },
+        ));
+        bindings
+    }
+}
+
+#[derive(Debug)]
+enum TrainingState {
+    Running,
+    Completed,
+    Failed(String),
+}
+
+#[derive(Debug)]
+struct TrainingDashboardScreen {
+    rx: mpsc::Receiver<TrainingEvent>,
+    overview: Option<TrainingOverview>,
+    generations: Vec<GenerationLog>,
+    state: TrainingState,
+    started_at: Option<Instant>,
+    finished_at: Option<Instant>,
+    selected_objective: usize,
+    selected_weight: usize,
+}
+
+impl TrainingDashboardScreen {
+    fn new(rx: mpsc::Receiver<TrainingEvent>) -> Self {
+        Self {
+            rx,
+            overview: None,
+            generations: vec![],
+            state: TrainingState::Running,
+            started_at: None,
+            finished_at: None,
+            selected_objective: 0,
+            selected_weight: 0,
+        }
+    }
+
+    fn is_running(&self) -> bool {
+        matches!(self.state, TrainingState::Running)
+    }
+
+    fn apply_event(&mut self, event: TrainingEvent) {
+        match event {
+            TrainingEvent::Started(overview) => {
+                self.overview = Some(overview);
+                self.started_at = Some(Instant::now());
+            }
+            TrainingEvent::Generation(log) => self.generations.push(*log),
+            TrainingEvent::Finished(result) => {
+                self.state = match result {
+                    Ok(()) => TrainingState::Completed,
+                    Err(message) => TrainingState::Failed(message),
+                };
+                self.finished_at = Some(Instant::now());
+            }
+        }
+    }
+
+    /// Objectives shown in the chart: `fitness`, or the objectives of multi-objective training.
+    fn objectives(&self) -> Vec<&str> {
+        match self.generations.first() {
+            Some(log) if log.fitness_stats.is_none() => log
+                .individuals
+                .first()
+                .map(|ind| ind.objectives.keys().map(String::as_str).collect())
+                .unwrap_or_default(),
+            _ => vec!["fitness"],
+        }
+    }
+
+    fn objective(&self) -> &str {
+        self.objectives()
+            .get(self.selected_objective)
+            .copied()
+            .unwrap_or("fitness")
+    }
+
+    /// Returns the best individual of all generations and its generation.
+    fn best_individual(&self, objective: &str) -> Option<(usize, &IndividualLog)> {
+        self.generations
+            .iter()
+            .flat_map(|log| log.individuals.iter().map(|ind| (log.generation, ind)))
+            .filter(|(_, ind)| objective_value(ind, objective).is_some())
+            .max_by(|(_, a), (_, b)| {
+                let a = objective_value(a, objective).unwrap();
+                let b = objective_value(b, objective).unwrap();
+                a.total_cmp(&b)
+            })
+    }
+
+    fn elapsed(&self) -> Duration {
+        let Some(started_at) = self.started_at else {
+            return Duration::ZERO;
+        };
+        self.finished_at
+            .unwrap_or_else(Instant::now)
+            .duration_since(started_at)
+    }
+
+    /// Returns the amount of work done and the total amount of work of this run.
+    fn progress(&self) -> Option<(usize, usize)> {
+        let overview = self.overview.as_ref()?;
+        let last = self.generations.last()?;
+        let progress = match overview.budget {
+            TrainingBudget::Generations { first, total } => {
+                (last.generation + 1 - first, total - first)
+            }
+            TrainingBudget::Evaluations(total) => (
+                self.generations
+                    .iter()
+                    .map(|log| log.individuals.len())
+                    .sum(),
+                total,
+            ),
+        };
+        Some(progress)
+    }
+
+    /// Estimates the remaining time from the average time per unit of work so far.
+    #[expect(clippy::cast_precision_loss)]
+    fn eta(&self) -> Option<Duration> {
+        let last = self.generations.last()?;
+        let (done, total) = self.progress()?;
+        if !self.is_running() || done == 0 {
+            return None;
+        }
+        let remaining = total.saturating_sub(done);
+        Some(Duration::from_secs_f64(
+            last.elapsed_secs * remaining as f64 / done as f64,
+        ))
+    }
+}
+
+impl Screen for TrainingDashboardScreen {
+    fn on_active(&mut self, runtime: &mut Runtime) {
+        runtime.set_render_mode(RenderMode::throttled_from_rate(DEFAULT_FRAME_RATE));
+        runtime.set_tick_rate(self.is_running().then_some(TICK_RATE));
+    }
+
+    fn on_inactive(&mut self, _runtime: &mut Runtime) {}
+
+    fn on_close(&mut self, _runtime: &mut Runtime) {}
+
+    fn handle_event(&mut self, _runtime: &mut Runtime, event: &Event) -> ScreenTransition {
+        if let Some(event) = event.as_key_event()
+            && let Some(action) = Action::from_key_event(&event)
+        {
+            let num_weights = self
+                .overview
+                .as_ref()
+                .map_or(0, |overview| overview.weight_names.len());
+            match action {
+                Action::SelectPrev => {
+                    if num_weights > 0 {
+                        self.selected_weight = self
+                            .selected_weight
+                            .checked_sub(1)
+                            .unwrap_or(num_weights - 1);
+                    }
+                }
+                Action::SelectNext => {
+                    if num_weights > 0 {
+                        self.selected_weight = (self.selected_weight + 1) % num_weights;
+                    }
+                }
+                Action::NextObjective => {
+                    self.selected_objective =
+                        (self.selected_objective + 1) % self.objectives().len().max(1);
+                }
+                Action::Quit => return ScreenTransition::Pop,
+            }
+        }
+        ScreenTransition::Stay
+    }
+
+    fn update(&mut self, runtime: &mut Runtime) {
+        loop {
+            match self.rx.try_recv() {
+                Ok(event) => self.apply_event(event),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    if self.is_running() {
+                        self.apply_event(TrainingEvent::Finished(Err(
+                            "training thread stopped unexpectedly".to_owned(),
+                        )));
+                    }
+                    break;
+                }
+            }
+        }
+        runtime.set_tick_rate(self.is_running().then_some(TICK_RATE));
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let objective = self.objective();
+        let best = self.best_individual(objective);
+        let weight_names = self
+            .overview
+            .as_ref()
+            .map_or(&[][..], |overview| &overview.weight_names);
+
+        let [main_area, help_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(frame.area());
+        let [top_area, weights_area] =
+            Layout::vertical([Constraint::Percentage(55), Constraint::Fill(1)])
+                .spacing(Spacing::Overlap(1))
+                .areas(main_area);
+        let [left_area, chart_area] =
+            Layout::horizontal([Constraint::Length(44), Constraint::Fill(1)])
+                .spacing(Spacing::Overlap(1))
+                .areas(top_area);
+        let [status_area, best_area] =
+            Layout::vertical([Constraint::Length(10), Constraint::Fill(1)])
+                .spacing(Spacing::Overlap(1))
+                .areas(left_area);
+
+        frame.render_widget(TrainingStatus { screen: self }, status_area);
+        frame.render_widget(BestIndividual { best, weight_names }, best_area);
+        frame.render_widget(
+            ObjectiveChart {
+                objective,
+                generations: &self.generations,
+            },
+            chart_area,
+        );
+        frame.render_widget(
+            WeightDistributions {
+                weight_names,
+                stats: self
+                    .generations
+                    .last()
+                    .map_or(&[][..], |log| &log.weight_stats),
+                best: best.map(|(_, ind)| &ind.weights[..]),
+                selected_weight: self.selected_weight,
+            },
+            weights_area,
+        );
+
+        let bindings = Action::bindings(self.objectives().len() > 1, self.is_running());
+        frame.render_widget(KeyBindingDisplay::new(&bindings), help_area);
+    }
+}
+
+/// Returns the value of `objective` (`fitness` for single-objective training) of `ind`.
+fn objective_value(ind: &IndividualLog, objective: &str) -> Option<f32> {
+    ind.fitness
+        .or_else(|| ind.objectives.get(objective).copied())
+}
+
+/// Returns the minimum, mean and maximum of `objective` in a generation.
+#[expect(clippy::cast_precision_loss)]
+fn objective_stats(log: &GenerationLog, objective: &str) -> Option<[f64; 3]> {
+    let values = log
+        .individuals
+        .iter()
+        .filter_map(|ind| objective_value(ind, objective))
+        .map(f64::from)
+        .collect::<Vec<_>>();
+    if values.is_empty() {
+        return None;
+    }
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    Some([min, mean, max])
+}
+
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+fn bounds_labels(bounds: [f64; 2], precision: usize) -> [String; 3] {
+    [
+        format!("{:.precision$}", bounds[0]),
+        format!("{:.precision$}", f64::midpoint(bounds[0], bounds[1])),
+        format!("{:.precision$}", bounds[1]),
+    ]
+}
+
+struct TrainingStatus<'a> {
+    screen: &'a TrainingDashboardScreen,
+}
+
+impl Widget for TrainingStatus<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let screen = self.screen;
+        let title = screen
+            .overview
+            .as_ref()
+            .map_or("Training", |overview| overview.title.as_str());
+        let block = Block::bordered()
+            .merge_borders(MergeStrategy::Exact)
+            .title(title);
+
+        let state = match &screen.state {
+            TrainingState::Running => Span::styled("Running", Style::default().fg(Color::Yellow)),
+            TrainingState::Completed => {
+                Span::styled("Completed", Style::default().fg(Color::Green))
+            }
+            TrainingState::Failed(_) => Span::styled("Failed", Style::default().fg(Color::Red)),
+        };
+        let last = screen.generations.last();
+        let progress = match (screen.progress(), screen.overview.as_ref()) {
+            (Some((done, total)), Some(overview)) => match overview.budget {
+                TrainingBudget::Generations { .. } => format!("{done}/{total} generations"),
+                TrainingBudget::Evaluations(_) => format!("{done}/{total} evaluations"),
+            },
+            _ => "-".to_owned(),
+        };
+        let mut text = vec![
+            Line::from(vec![Span::raw("  State:      "), state]),
+            Line::raw(format!(
+                "  Generation: {}",
+                last.map_or("-".to_owned(), |log| format!("#{}", log.generation))
+            )),
+            Line::raw(format!("  Progress:   {progress}")),
+            Line::raw(format!(
+                "  Phase:      {}",
+                last.and_then(|log| log.phase)
+                    .map_or("-".to_owned(), |phase| format!("{phase:?}"))
+            )),
+            Line::raw(format!(
+                "  Evaluation: {}",
+                last.map_or("-".to_owned(), |log| format!("{:.2}s", log.evaluation_secs))
+            )),
+            Line::raw(format!(
+                "  Elapsed:    {}",
+                format_duration(screen.elapsed())
+            )),
+            Line::raw(format!(
+                "  ETA:        {}",
+                screen.eta().map_or("-".to_owned(), format_duration)
+            )),
+        ];
+        if let TrainingState::Failed(message) = &screen.state {
+            text.push(Line::styled(
+                format!("  {message}"),
+                Style::default().fg(Color::Red),
+            ));
+        }
+
+        Paragraph::new(text).block(block).render(area, buf);
+    }
+}
+
+struct BestIndividual<'a> {
+    best: Option<(usize, &'a IndividualLog)>,
+    weight_names: &'a [String],
+}
+
+impl Widget for BestIndividual<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let block = Block::bordered()
+            .merge_borders(MergeStrategy::Exact)
+            .title("Best Individual");
+        let Some((generation, best)) = self.best else {
+            Paragraph::new("  Waiting for the first generation...")
+                .block(block)
+                .render(area, buf);
+            return;
+        };
+
+        let mut text = vec![Line::raw(format!("  Generation: #{generation}"))];
+        if let Some(fitness) = best.fitness {
+            text.push(Line::raw(format!("  Fitness:    {fitness:.3}")));
+        }
+        for (name, value) in &best.objectives {
+            text.push(Line::raw(format!(
+                "  {:<11} {value:.3}",
+                format!("{name}:")
+            )));
+        }
+        text.push(Line::raw("  Top weights:"));
+        let mut weights = self
+            .weight_names
+            .iter()
+            .zip(&best.weights)
+            .collect::<Vec<_>>();
+        weights.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        for (name, weight) in weights {
+            text.push(Line::raw(format!("    {weight:6.3} {name}")));
+        }
+
+        Paragraph::new(text).block(block).render(area, buf);
+    }
+}
+
+struct ObjectiveChart<'a> {
+    objective: &'a str,
+    generations: &'a [GenerationLog],
+}
+
+impl Widget for ObjectiveChart<'_> {
+    #[expect(clippy::cast_precision_loss)]
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let series = self
+            .generations
+            .iter()
+            .filter_map(|log| {
+                objective_stats(log, self.objective).map(|stats| (log.generation as f64, stats))
+            })
+            .collect::<Vec<_>>();
+        let [min_data, mean_data, max_data] =
+            [0, 1, 2].map(|i| series.iter().map(|(x, s)| (*x, s[i])).collect::<Vec<_>>());
+
+        let x_min = series.first().map_or(0.0, |(x, _)| *x);
+        let x_max = series.last().map_or(1.0, |(x, _)| *x).max(x_min + 1.0);
+        let y_min = series
+            .iter()
+            .map(|(_, s)| s[0])
+            .fold(f64::INFINITY, f64::min);
+        let y_max = series
+            .iter()
+            .map(|(_, s)| s[2])
+            .fold(f64::NEG_INFINITY, f64::max);
+        let (y_min, y_max) = if series.is_empty() {
+            (0.0, 1.0)
+        } else if (y_max - y_min).abs() < f64::EPSILON {
+            (y_min - 0.5, y_max + 0.5)
+        } else {
+            (y_min, y_max)
+        };
+
+        let datasets = [
+            ("max", Color::Green, &max_data),
+            ("mean", Color::Yellow, &mean_data),
+            ("min", Color::Red, &min_data),
+        ]
+        .into_iter()
+        .map(|(name, color, data)| {
+            Dataset::default()
+                .name(name)
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(color))
+                .data(data)
+        })
+        .collect::<Vec<_>>();
+        let x_axis = Axis::default()
+            .title("Generation")
+            .bounds([x_min, x_max])
+            .labels(bounds_labels([x_min, x_max], 0));
+        let y_axis = Axis::default()
+            .title(self.objective)
+            .bounds([y_min, y_max])
+            .labels(bounds_labels([y_min, y_max], 2));
+        let chart = Chart::new(datasets)
+            .block(
+                Block::bordered()
+                    .merge_borders(MergeStrategy::Exact)
+                    .title(format!("{} (min / mean / max)", self.objective)),
+            )
+            .x_axis(x_axis)
+            .y_axis(y_axis);
+
+        Widget::render(chart, area, buf);
+    }
+}
+
+/// Distribution of each weight in the latest generation, drawn as a bar of the min–max
+/// range with markers of the mean and of the best individual.
+struct WeightDistributions<'a> {
+    weight_names: &'a [String],
+    stats: &'a [StatsLog],
+    best: Option<&'a [f32]>,
+    selected_weight: usize,
+}
+
+impl WeightDistributions<'_> {
+    const NAME_WIDTH: usize = 32;
+
+    #[expect(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn bar(stats: &StatsLog, best: Option<f32>, range: [f32; 2], width: usize) -> String {
+        let column = |value: f32| {
+            let ratio = ((value - range[0]) / (range[1] - range[0])).clamp(0.0, 1.0);
+            (ratio * (width - 1) as f32).round() as usize
+        };
+        let mut bar = vec![' '; width];
+        for c in &mut bar[column(stats.min)..=column(stats.max)] {
+            *c = '─';
+        }
+        bar[column(stats.mean)] = '●';
+        if let Some(best) = best {
+            bar[column(best)] = '◆';
+        }
+        bar.into_iter().collect()
+    }
+}
+
+impl Widget for WeightDistributions<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let block = Block::bordered()
+            .merge_borders(MergeStrategy::Exact)
+            .title("Weights of the latest generation (─ min–max, ● mean, ◆ best)");
+
+        let lo = self.stats.iter().map(|s| s.min).fold(0.0, f32::min);
+        let hi = self
+            .stats
+            .iter()
+            .map(|s| s.max)
+            .chain(self.best.into_iter().flatten().copied())
+            .fold(lo + f32::EPSILON, f32::max);
+        // Name, mean and best columns, the highlight symbol and the borders
+        let bar_width = usize::from(area.width)
+            .saturating_sub(Self::NAME_WIDTH + 2 * 7 + 3 + 2 + 3)
+            .max(1);
+
+        let items = self
+            .stats
+            .iter()
+            .enumerate()
+            .map(|(i, stats)| {
+                let name = self.weight_names.get(i).map_or("", String::as_str);
+                let best = self.best.and_then(|weights| weights.get(i).copied());
+                ListItem::new(format!(
+                    "{name:<width$.width$} {:6.3} {} │{}│",
+                    stats.mean,
+                    best.map_or("     -".to_owned(), |best| format!("{best:6.3}")),
+                    Self::bar(stats, best, [lo, hi], bar_width),
+                    width = Self::NAME_WIDTH,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(">> ");
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(self.selected_weight));
+
+        StatefulWidget::render(list, area, buf, &mut list_state);
+    }
+}
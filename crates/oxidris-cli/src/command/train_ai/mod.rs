@@ -0,0 +1,1327 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, File, OpenOptions},
+    io::{BufWriter, Write as _},
+    iter,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::Instant,
+};
+
+use anyhow::Context as _;
+
+use chrono::Utc;
+use oxidris_analysis::feature_builder::KmTransform;
+use oxidris_engine::GameField;
+use oxidris_evaluator::{
+    board_feature::BoxedBoardFeature,
+    placement_evaluator::HeightGate,
+    session_evaluator::{
+        AggroSessionEvaluator, BackToBackSessionEvaluator, DefaultMultiObjectiveSessionEvaluator,
+        DefaultSessionEvaluator, DefensiveSessionEvaluator, FitnessDefinition,
+        FitnessSessionEvaluator, FitnessTerm, ScoreSessionEvaluator, SessionEvaluator,
+        SprintSessionEvaluator, TetrisRateSessionEvaluator, TimelineFitnessSessionEvaluator,
+        TimelineSessionStats, WeightedFitnessTerm,
+    },
+    turn_evaluator::TurnEvaluator,
+};
+use oxidris_training::{
+    cma_es::{CmaEs, CmaEsParams},
+    genetic::{Individual, Population, PopulationEvolver},
+    nsga2::ParetoPopulation,
+    weights,
+};
+use rand::{Rng as _, SeedableRng as _, rngs::StdRng};
+
+use self::dashboard::{TrainingBudget, TrainingEvent, TrainingOverview};
+use crate::{
+    schema::{
+        ai_model::{AiModel, ModelEvaluator, TrainedBoardFeature},
+        checkpoint::{CHECKPOINT_VERSION, CheckpointIndividual, EvolverParams, TrainingCheckpoint},
+        training_config::{EvolutionPhase, TrainingConfig},
+        training_log::{GenerationLog, GenerationTimings},
+    },
+    util::{self, FeatureSet, Output},
+};
+
+mod dashboard;
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, derive_more::FromStr)]
+#[from_str(rename_all = "kebab-case")]
+pub enum AiType {
+    #[default]
+    AggroKm,
+    DefensiveKm,
+    AggroRaw,
+    DefensiveRaw,
+    ScoreKm,
+    ScoreRaw,
+    SprintKm,
+    SprintRaw,
+    TetrisKm,
+    TetrisRaw,
+    BackToBackKm,
+    BackToBackRaw,
+}
+
+impl AiType {
+    pub(crate) fn session_evaluator(self, turn_limit: usize) -> Box<dyn SessionEvaluator> {
+        match self {
+            Self::AggroKm | Self::AggroRaw => Box::new(DefaultSessionEvaluator::new(
+                turn_limit,
+                AggroSessionEvaluator::new(),
+            )),
+            Self::DefensiveKm | Self::DefensiveRaw => Box::new(DefaultSessionEvaluator::new(
+                turn_limit,
+                DefensiveSessionEvaluator::new(),
+            )),
+            Self::ScoreKm | Self::ScoreRaw => Box::new(DefaultSessionEvaluator::new(
+                turn_limit,
+                ScoreSessionEvaluator::new(),
+            )),
+            Self::SprintKm | Self::SprintRaw => Box::new(DefaultSessionEvaluator::new(
+                turn_limit,
+                SprintSessionEvaluator::default(),
+            )),
+            Self::TetrisKm | Self::TetrisRaw => Box::new(DefaultSessionEvaluator::new(
+                turn_limit,
+                TetrisRateSessionEvaluator::new(),
+            )),
+            Self::BackToBackKm | Self::BackToBackRaw => Box::new(DefaultSessionEvaluator::new(
+                turn_limit,
+                BackToBackSessionEvaluator::new(),
+            )),
+        }
+    }
+
+    pub(crate) fn feature_set(self, km_transform: KmTransform) -> FeatureSet {
+        match self {
+            Self::AggroKm
+            | Self::DefensiveKm
+            | Self::ScoreKm
+            | Self::SprintKm
+            | Self::TetrisKm
+            | Self::BackToBackKm => FeatureSet::Km(km_transform),
+            Self::AggroRaw
+            | Self::DefensiveRaw
+            | Self::ScoreRaw
+            | Self::SprintRaw
+            | Self::TetrisRaw
+            | Self::BackToBackRaw => FeatureSet::Raw,
+        }
+    }
+
+    pub(crate) fn model_name(self) -> &'static str {
+        match self {
+            Self::AggroKm => "aggro-km",
+            Self::DefensiveKm => "defensive-km",
+            Self::AggroRaw => "aggro-raw",
+            Self::DefensiveRaw => "defensive-raw",
+            Self::ScoreKm => "score-km",
+            Self::ScoreRaw => "score-raw",
+            Self::SprintKm => "sprint-km",
+            Self::SprintRaw => "sprint-raw",
+            Self::TetrisKm => "tetris-km",
+            Self::TetrisRaw => "tetris-raw",
+            Self::BackToBackKm => "back-to-back-km",
+            Self::BackToBackRaw => "back-to-back-raw",
+        }
+    }
+}
+
+/// Training algorithm.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, derive_more::FromStr)]
+#[from_str(rename_all = "kebab-case")]
+pub enum Algorithm {
+    /// Single-objective genetic algorithm
+    #[default]
+    Ga,
+    /// NSGA-II optimizing survival and score as separate objectives
+    NsgaII,
+    /// CMA-ES with IPOP restarts
+    CmaEs,
+}
+
+/// Objectives of multi-objective training, as (name, fitness) pairs.
+fn objectives() -> Vec<(&'static str, FitnessDefinition)> {
+    vec![
+        ("survival", FitnessDefinition::defensive()),
+        (
+            "score",
+            FitnessDefinition {
+                bias: 0.0,
+                terms: vec![WeightedFitnessTerm {
+                    weight: 1.0,
+                    term: FitnessTerm::Score {},
+                }],
+            },
+        ),
+    ]
+}
+
+#[derive(Default, Debug, Clone, clap::Args)]
+pub(crate) struct TrainAiArg {
+    /// Boards data file path
+    #[arg(required_unless_present = "resume")]
+    boards_file: Option<PathBuf>,
+    /// Fitness function and feature set, as `<fitness>-km` or `<fitness>-raw`
+    /// (fitness: aggro, defensive, score, sprint, tetris, back-to-back)
+    #[arg(long, default_value = "aggro-km")]
+    ai: AiType,
+    /// Transform used for KM-based features
+    /// (table, piecewise-linear, spline, survival-probability, restricted-mean)
+    #[arg(long, default_value = "table")]
+    km_transform: KmTransform,
+    /// Stack heights splitting the game into phases with separate weight sets
+    /// (e.g., `8,14` for low / middle / high stacks)
+    #[arg(long, value_delimiter = ',')]
+    height_gate: Vec<u8>,
+    /// Fitness definition file (TOML or JSON) replacing the fitness function of `--ai`
+    #[arg(long)]
+    fitness: Option<PathBuf>,
+    /// Training algorithm (ga, cma-es, nsga-ii)
+    ///
+    /// `cma-es` runs CMA-ES with IPOP restarts on the same fitness and evaluation budget as
+    /// `ga`.
+    ///
+    /// `nsga-ii` optimizes survival and score as separate objectives and only uses the feature
+    /// set of `--ai`. It saves every model of the Pareto front into the `--output` directory
+    /// (`pareto/` of the `--run-dir`).
+    #[arg(long, default_value = "ga")]
+    algorithm: Algorithm,
+    /// Training config file (TOML or JSON); omitted values take the default preset
+    #[arg(long, conflicts_with = "resume")]
+    config: Option<PathBuf>,
+    #[command(flatten)]
+    config_overrides: Box<ConfigOverrides>,
+    /// Directory to write GA checkpoints into
+    #[arg(long)]
+    checkpoint_dir: Option<PathBuf>,
+    /// Number of generations between checkpoints
+    #[arg(long, default_value_t = 10)]
+    checkpoint_interval: usize,
+    /// Seed deriving all random number streams (random if omitted; printed and saved in
+    /// the model so the run can be reproduced)
+    #[arg(long)]
+    seed: Option<u64>,
+    /// Evaluate every generation on the same set of game fields
+    ///
+    /// Within a generation, all individuals always play the same fields. With this option,
+    /// fitness is also comparable across generations, at the risk of overfitting to the
+    /// piece sequences of these fields.
+    #[arg(long)]
+    common_fields: bool,
+    /// Resume GA training from a checkpoint file
+    ///
+    /// The fitness function, features, phases, seeds and training config are restored from
+    /// the checkpoint; the corresponding options are ignored. Training config overrides
+    /// still apply (e.g., `--max-generations` to extend a finished run).
+    #[arg(long, conflicts_with = "boards_file")]
+    resume: Option<PathBuf>,
+    /// Output file path (output directory for `--algorithm nsga-ii`)
+    #[arg(long)]
+    output: Option<PathBuf>,
+    /// Run directory collecting the training config, a JSONL log of every generation,
+    /// checkpoints and the final model
+    ///
+    /// When resuming, the log of the run directory is appended to.
+    #[arg(long, conflicts_with_all = ["output", "checkpoint_dir"])]
+    run_dir: Option<PathBuf>,
+    /// Show a live dashboard of the training progress instead of the per-generation report
+    ///
+    /// Requires `--output` or `--run-dir`. Quitting the dashboard stops training after the
+    /// current generation, then saves a checkpoint (GA) and the best model (the Pareto front
+    /// for NSGA-II) as if training had finished.
+    #[arg(long)]
+    tui: bool,
+}
+
+/// Command line overrides of [`TrainingConfig`] values.
+#[derive(Default, Debug, Clone, clap::Args)]
+struct ConfigOverrides {
+    /// Number of individuals per generation
+    #[arg(long)]
+    population_size: Option<usize>,
+    /// Number of generations
+    #[arg(long)]
+    max_generations: Option<usize>,
+    /// Number of games each individual plays per generation
+    #[arg(long)]
+    games_per_individual: Option<usize>,
+    /// Maximum number of turns per game
+    #[arg(long)]
+    turn_limit: Option<usize>,
+    /// Number of elite individuals kept unchanged
+    #[arg(long)]
+    elite_count: Option<usize>,
+    /// Tournament size of parent selection
+    #[arg(long)]
+    tournament_size: Option<usize>,
+    /// Mutation sigma of the exploration, transition and convergence phases
+    /// (e.g., `0.05,0.02,0.01`)
+    #[arg(long, value_delimiter = ',')]
+    mutation_sigma: Option<Vec<f32>>,
+}
+
+impl ConfigOverrides {
+    fn apply(&self, mut config: TrainingConfig) -> anyhow::Result<TrainingConfig> {
+        let Self {
+            population_size,
+            max_generations,
+            games_per_individual,
+            turn_limit,
+            elite_count,
+            tournament_size,
+            mutation_sigma,
+        } = self;
+        for (value, target) in [
+            (population_size, &mut config.population_size),
+            (max_generations, &mut config.max_generations),
+            (games_per_individual, &mut config.games_per_individual),
+            (turn_limit, &mut config.turn_limit),
+            (elite_count, &mut config.elite_count),
+            (tournament_size, &mut config.tournament_size),
+        ] {
+            if let Some(value) = value {
+                *target = *value;
+            }
+        }
+        if let Some(sigmas) = mutation_sigma {
+            anyhow::ensure!(
+                sigmas.len() == 3,
+                "--mutation-sigma takes 3 values (exploration, transition, convergence)"
+            );
+            let phases = &mut config.phases;
+            for (sigma, params) in iter::zip(
+                sigmas,
+                [
+                    &mut phases.exploration,
+                    &mut phases.transition,
+                    &mut phases.convergence,
+                ],
+            ) {
+                params.mutation_sigma = *sigma;
+            }
+        }
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+pub(crate) fn run(arg: &TrainAiArg) -> anyhow::Result<()> {
+    validate_args(arg)?;
+    let outputs = RunOutputs::new(arg)?;
+    let job = match &arg.resume {
+        Some(path) => TrainingJob::Resume(ResumedTraining::load(
+            path,
+            &outputs,
+            &arg.config_overrides,
+        )?),
+        None => TrainingJob::New(NewTraining::load(arg, &outputs)?),
+    };
+    if arg.tui {
+        dashboard::run(job, outputs)
+    } else {
+        job.run(outputs)
+    }
+}
+
+fn validate_args(arg: &TrainAiArg) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        arg.checkpoint_interval > 0,
+        "--checkpoint-interval must be positive"
+    );
+    anyhow::ensure!(
+        arg.algorithm == Algorithm::Ga || (arg.checkpoint_dir.is_none() && arg.resume.is_none()),
+        "checkpoints are only supported with --algorithm ga"
+    );
+    if arg.algorithm == Algorithm::NsgaII {
+        anyhow::ensure!(
+            arg.fitness.is_none(),
+            "--fitness cannot be used with --algorithm nsga-ii"
+        );
+        anyhow::ensure!(
+            arg.output.is_some() || arg.run_dir.is_some(),
+            "--output or --run-dir is required with --algorithm nsga-ii"
+        );
+    }
+    anyhow::ensure!(
+        !arg.tui || arg.output.is_some() || arg.run_dir.is_some(),
+        "--output or --run-dir is required with --tui"
+    );
+    Ok(())
+}
+
+/// Reads the boards file and builds the board features of `feature_set` from it.
+fn load_features(
+    boards_file: &PathBuf,
+    feature_set: FeatureSet,
+) -> anyhow::Result<Vec<BoxedBoardFeature>> {
+    eprintln!("Loading boards from {}...", boards_file.display());
+    let collection = util::read_boards_file(boards_file)?;
+    let sessions = collection.sessions;
+    eprintln!("Loaded {} sessions", sessions.len());
+    util::build_feature_from_session(feature_set, &sessions, collection.max_turns)
+}
+
+fn session_evaluator(
+    ai: AiType,
+    fitness: Option<&FitnessDefinition>,
+    turn_limit: usize,
+) -> anyhow::Result<Box<dyn SessionEvaluator>> {
+    let evaluator: Box<dyn SessionEvaluator> = match fitness {
+        // Recording the timeline is slower, so only do it for the terms that need it
+        Some(definition) if definition.requires_timeline() => {
+            Box::new(DefaultSessionEvaluator::new(
+                turn_limit,
+                TimelineFitnessSessionEvaluator::new(definition.clone())?,
+            ))
+        }
+        Some(definition) => Box::new(DefaultSessionEvaluator::new(
+            turn_limit,
+            FitnessSessionEvaluator::new(definition.clone())?,
+        )),
+        None => ai.session_evaluator(turn_limit),
+    };
+    Ok(evaluator)
+}
+
+/// Names of the weights of an individual: the feature names, per phase of `gate`.
+fn weight_names(features: &[BoxedBoardFeature], gate: &HeightGate) -> Vec<String> {
+    let num_phases = gate.num_phases();
+    (0..num_phases)
+        .flat_map(|phase| {
+            features.iter().map(move |f| {
+                if num_phases > 1 {
+                    format!("{} #{phase}", f.name())
+                } else {
+                    f.name().to_owned()
+                }
+            })
+        })
+        .collect()
+}
+
+/// Settings of a training run recorded in the saved models.
+struct TrainingInfo<'a> {
+    seed: u64,
+    config: &'a TrainingConfig,
+}
+
+/// Training run prepared from the options.
+///
+/// Everything that reads inputs or reports on stderr before training starts is done when
+/// preparing the job, so [`TrainingJob::run`] can run behind the dashboard.
+enum TrainingJob {
+    New(NewTraining),
+    Resume(ResumedTraining),
+}
+
+impl TrainingJob {
+    fn run(self, mut outputs: RunOutputs) -> anyhow::Result<()> {
+        match self {
+            Self::New(training) => training.run(&mut outputs),
+            Self::Resume(training) => training.run(&mut outputs),
+        }
+    }
+}
+
+/// Training from a random initial population.
+struct NewTraining {
+    ai: AiType,
+    algorithm: Algorithm,
+    feature_set: FeatureSet,
+    features: Vec<BoxedBoardFeature>,
+    gate: HeightGate,
+    fitness: Option<FitnessDefinition>,
+    config: TrainingConfig,
+    field_sampler: FieldSampler,
+}
+
+impl NewTraining {
+    fn load(arg: &TrainAiArg, outputs: &RunOutputs) -> anyhow::Result<Self> {
+        let config = arg
+            .config
+            .as_ref()
+            .map(|path| util::read_config_file("training config", path))
+            .transpose()?;
+        let config = arg.config_overrides.apply(config.unwrap_or_default())?;
+        outputs.save_config(&config)?;
+        let gate = HeightGate::new(arg.height_gate.clone())?;
+        let fitness = arg
+            .fitness
+            .as_ref()
+            .map(|path| {
+                let definition: FitnessDefinition = util::read_config_file("fitness", path)?;
+                definition
+                    .validate()
+                    .with_context(|| format!("Invalid fitness file: {}", path.display()))?;
+                anyhow::Ok(definition)
+            })
+            .transpose()?;
+        let feature_set = arg.ai.feature_set(arg.km_transform);
+        let features = load_features(arg.boards_file.as_ref().unwrap(), feature_set)?;
+        let field_sampler =
+            FieldSampler::new(arg.seed, arg.common_fields, config.games_per_individual);
+        Ok(Self {
+            ai: arg.ai,
+            algorithm: arg.algorithm,
+            feature_set,
+            features,
+            gate,
+            fitness,
+            config,
+            field_sampler,
+        })
+    }
+
+    fn run(self, outputs: &mut RunOutputs) -> anyhow::Result<()> {
+        let Self {
+            ai,
+            algorithm,
+            feature_set,
+            features,
+            gate,
+            fitness,
+            config,
+            field_sampler,
+        } = self;
+        let mut rng = StdRng::seed_from_u64(field_sampler.seed);
+        let session_evaluator = session_evaluator(ai, fitness.as_ref(), config.turn_limit)?;
+        let training = TrainingInfo {
+            seed: field_sampler.seed,
+            config: &config,
+        };
+        match algorithm {
+            Algorithm::Ga => {
+                let population = Population::random_phased(
+                    features,
+                    gate,
+                    config.population_size,
+                    &mut rng,
+                    config.phase_params(EvolutionPhase::default()).max_weight,
+                );
+                let run = GaRun {
+                    ai,
+                    fitness: fitness.as_ref(),
+                    config: &config,
+                    session_evaluator: session_evaluator.as_ref(),
+                    field_sampler,
+                    checkpoint_writer: outputs.checkpoint_writer.as_ref(),
+                };
+                let population = run.run(population, 0, rng.random(), &mut outputs.log)?;
+                save_population_best(ai, &population, &training, outputs)
+            }
+            Algorithm::CmaEs => {
+                outputs.log.start(TrainingOverview {
+                    title: format!("CMA-ES: {}", ai.model_name()),
+                    weight_names: weight_names(&features, &gate),
+                    budget: TrainingBudget::Evaluations(config.cma_es_budget()),
+                });
+                let best_individual = run_cma_es(
+                    features.clone(),
+                    gate.clone(),
+                    &config,
+                    session_evaluator.as_ref(),
+                    field_sampler,
+                    &mut rng,
+                    &mut outputs.log,
+                )?;
+                save_best_model(ai, &features, &gate, &best_individual, &training, outputs)
+            }
+            Algorithm::NsgaII => {
+                let model_name = match feature_set {
+                    FeatureSet::Km(_) => "pareto-km",
+                    FeatureSet::Raw => "pareto-raw",
+                    FeatureSet::All => "pareto",
+                };
+                outputs.log.start(TrainingOverview {
+                    title: format!("NSGA-II: {model_name}"),
+                    weight_names: weight_names(&features, &gate),
+                    budget: TrainingBudget::Generations {
+                        first: 0,
+                        total: config.max_generations,
+                    },
+                });
+                let population = run_nsga2(
+                    features,
+                    gate,
+                    &config,
+                    field_sampler,
+                    &mut rng,
+                    &mut outputs.log,
+                )?;
+                if outputs.log.verbose() {
+                    eprintln!("Multi-objective AI learning completed.");
+                }
+                save_pareto_front(model_name, &population, &training, outputs)
+            }
+        }
+    }
+}
+
+/// GA training resumed from a checkpoint.
+struct ResumedTraining {
+    ai: AiType,
+    fitness: Option<FitnessDefinition>,
+    config: TrainingConfig,
+    field_sampler: FieldSampler,
+    /// Unevaluated population of `next_generation` (the evaluated population of the
+    /// checkpoint if training has already finished)
+    population: Population,
+    next_generation: usize,
+    rng_seed: [u8; 32],
+}
+
+impl ResumedTraining {
+    fn load(
+        path: &PathBuf,
+        outputs: &RunOutputs,
+        config_overrides: &ConfigOverrides,
+    ) -> anyhow::Result<Self> {
+        let checkpoint: TrainingCheckpoint = util::read_json_file("checkpoint", path)?;
+        anyhow::ensure!(
+            checkpoint.version == CHECKPOINT_VERSION,
+            "Unsupported checkpoint version {} (expected {CHECKPOINT_VERSION})",
+            checkpoint.version
+        );
+        if let Some(fitness) = &checkpoint.fitness {
+            fitness
+                .validate()
+                .context("Invalid fitness definition in checkpoint")?;
+        }
+        let ai = checkpoint
+            .ai
+            .parse::<AiType>()
+            .map_err(|e| anyhow::anyhow!("Invalid AI type {} in checkpoint: {e}", checkpoint.ai))?;
+        eprintln!(
+            "Resuming {ai:?} AI learning from generation #{} ({})",
+            checkpoint.generation,
+            path.display()
+        );
+
+        let config = config_overrides.apply(checkpoint.config.clone())?;
+        outputs.save_config(&config)?;
+        let next_generation = checkpoint.generation + 1;
+        let (population, rng_seed) = if next_generation < config.max_generations {
+            checkpoint.evolve_next()?
+        } else {
+            (checkpoint.to_population()?, checkpoint.rng_seed)
+        };
+        let field_sampler = FieldSampler {
+            seed: checkpoint.seed,
+            common: checkpoint.common_fields,
+            games: config.games_per_individual,
+        };
+        Ok(Self {
+            ai,
+            fitness: checkpoint.fitness,
+            config,
+            field_sampler,
+            population,
+            next_generation,
+            rng_seed,
+        })
+    }
+
+    fn run(self, outputs: &mut RunOutputs) -> anyhow::Result<()> {
+        let Self {
+            ai,
+            fitness,
+            config,
+            field_sampler,
+            mut population,
+            next_generation,
+            rng_seed,
+        } = self;
+        let session_evaluator = session_evaluator(ai, fitness.as_ref(), config.turn_limit)?;
+        let run = GaRun {
+            ai,
+            fitness: fitness.as_ref(),
+            config: &config,
+            session_evaluator: session_evaluator.as_ref(),
+            field_sampler,
+            checkpoint_writer: outputs.checkpoint_writer.as_ref(),
+        };
+        if next_generation < config.max_generations {
+            population = run.run(population, next_generation, rng_seed, &mut outputs.log)?;
+        }
+        save_population_best(ai, &population, &run.training(), outputs)
+    }
+}
+
+fn save_best_model(
+    ai: AiType,
+    features: &[BoxedBoardFeature],
+    gate: &HeightGate,
+    individual: &Individual,
+    training: &TrainingInfo,
+    outputs: &RunOutputs,
+) -> anyhow::Result<()> {
+    if outputs.log.verbose() {
+        eprintln!("{ai:?} AI learning completed.");
+    }
+
+    // CMA-ES candidates are not L1-normalized
+    let mut best_weights = individual.weights().to_vec();
+    for phase_weights in best_weights.chunks_mut(features.len()) {
+        weights::normalize_l1(phase_weights);
+    }
+    let model = build_model(
+        ai.model_name(),
+        gate,
+        features,
+        &best_weights,
+        individual.fitness(),
+        training,
+    );
+    outputs.save_model(&model, outputs.output.as_ref())?;
+    outputs.save_timeline(&model, training)
+}
+
+fn save_population_best(
+    ai: AiType,
+    population: &Population,
+    training: &TrainingInfo,
+    outputs: &RunOutputs,
+) -> anyhow::Result<()> {
+    let best_individual = population.individuals().first().unwrap();
+    save_best_model(
+        ai,
+        &population.individual_features(best_individual),
+        population.gate(),
+        best_individual,
+        training,
+        outputs,
+    )
+}
+
+/// Destinations of the training outputs.
+struct RunOutputs {
+    run_dir: Option<PathBuf>,
+    checkpoint_writer: Option<CheckpointWriter>,
+    log: TrainingLog,
+    /// Model file (model directory for NSGA-II)
+    output: Option<PathBuf>,
+}
+
+impl RunOutputs {
+    /// Sets up the outputs of the options: either the layout of `--run-dir`
+    ///
+    /// ```text
+    /// <run-dir>/
+    ///   config.json          effective training config (usable with `--config`)
+    ///   generations.jsonl    one `GenerationLog` per evaluated generation
+    ///   checkpoints/         GA checkpoints
+    ///   model.json           final model (`pareto/` with the Pareto front for NSGA-II)
+    ///   timeline.json        per-turn timeline of one game of the final model (not for NSGA-II)
+    /// ```
+    ///
+    /// or `--checkpoint-dir` and `--output` without a log.
+    fn new(arg: &TrainAiArg) -> anyhow::Result<Self> {
+        let Some(run_dir) = &arg.run_dir else {
+            return Ok(Self {
+                run_dir: None,
+                checkpoint_writer: arg
+                    .checkpoint_dir
+                    .as_ref()
+                    .map(|dir| CheckpointWriter::new(dir.clone(), arg.checkpoint_interval))
+                    .transpose()?,
+                log: TrainingLog::new(None)?,
+                output: arg.output.clone(),
+            });
+        };
+        fs::create_dir_all(run_dir)?;
+        let checkpoint_writer = (arg.algorithm == Algorithm::Ga)
+            .then(|| CheckpointWriter::new(run_dir.join("checkpoints"), arg.checkpoint_interval))
+            .transpose()?;
+        let output = match arg.algorithm {
+            Algorithm::Ga | Algorithm::CmaEs => run_dir.join("model.json"),
+            Algorithm::NsgaII => run_dir.join("pareto"),
+        };
+        Ok(Self {
+            run_dir: Some(run_dir.clone()),
+            checkpoint_writer,
+            log: TrainingLog::new(Some(&run_dir.join("generations.jsonl")))?,
+            output: Some(output),
+        })
+    }
+
+    fn save_config(&self, config: &TrainingConfig) -> anyhow::Result<()> {
+        if let Some(run_dir) = &self.run_dir {
+            Output::save_json(config, Some(run_dir.join("config.json")))?;
+        }
+        Ok(())
+    }
+
+    /// Plays one game with `model` and saves its per-turn timeline into the run directory,
+    /// for plotting the trajectory of the final model.
+    fn save_timeline(&self, model: &AiModel, training: &TrainingInfo) -> anyhow::Result<()> {
+        let Some(run_dir) = &self.run_dir else {
+            return Ok(());
+        };
+        let turn_evaluator = TurnEvaluator::new(model.to_placement_evaluator()?);
+        let mut field = GameField::with_seed(StdRng::seed_from_u64(training.seed).random());
+        let stats: TimelineSessionStats =
+            turn_evaluator.play_session(&mut field, training.config.turn_limit);
+        Output::save_json(stats.timeline(), Some(run_dir.join("timeline.json")))
+    }
+
+    /// Saves a trained model, reporting it on stderr unless the dashboard is shown.
+    fn save_model(&self, model: &AiModel, path: Option<&PathBuf>) -> anyhow::Result<()> {
+        if self.log.verbose() {
+            util::save_ai_model(model, path)
+        } else {
+            Output::save_json(model, path.cloned())
+        }
+    }
+}
+
+/// Writes [`GenerationLog`]s to the JSONL training log (and the dashboard, if shown) and
+/// measures training time.
+struct TrainingLog {
+    writer: Option<BufWriter<File>>,
+    dashboard: Option<mpsc::Sender<TrainingEvent>>,
+    /// Whether the dashboard has been closed
+    stopped: bool,
+    start: Instant,
+}
+
+impl TrainingLog {
+    /// Opens the log at `path` for appending, or discards records if `path` is `None`.
+    fn new(path: Option<&Path>) -> anyhow::Result<Self> {
+        let writer = path
+            .map(|path| {
+                OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .with_context(|| format!("Failed to open training log: {}", path.display()))
+            })
+            .transpose()?
+            .map(BufWriter::new);
+        Ok(Self {
+            writer,
+            dashboard: None,
+            stopped: false,
+            start: Instant::now(),
+        })
+    }
+
+    /// Sends the training progress to the dashboard instead of reporting it on stderr.
+    fn connect_dashboard(&mut self, tx: mpsc::Sender<TrainingEvent>) {
+        self.dashboard = Some(tx);
+    }
+
+    /// Returns `true` if the training progress is reported on stderr.
+    fn verbose(&self) -> bool {
+        self.dashboard.is_none()
+    }
+
+    /// Returns `true` once the dashboard has been closed.
+    ///
+    /// Training loops then stop after the current generation and save their results as if
+    /// training had finished.
+    fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+
+    /// Marks the start of training; elapsed times are measured from here.
+    fn start(&mut self, overview: TrainingOverview) {
+        self.start = Instant::now();
+        self.send(TrainingEvent::Started(overview));
+    }
+
+    /// Returns the timings of a generation whose evaluation started at `evaluation_start`.
+    fn timings(&self, evaluation_start: Instant) -> GenerationTimings {
+        GenerationTimings {
+            evaluation: evaluation_start.elapsed(),
+            elapsed: self.start.elapsed(),
+        }
+    }
+
+    fn record(&mut self, log: GenerationLog) -> anyhow::Result<()> {
+        if let Some(writer) = &mut self.writer {
+            serde_json::to_writer(&mut *writer, &log)?;
+            writeln!(writer)?;
+            writer.flush()?;
+        }
+        self.send(TrainingEvent::Generation(Box::new(log)));
+        Ok(())
+    }
+
+    /// Sends `event` to the dashboard, marking training as stopped if it is closed.
+    fn send(&mut self, event: TrainingEvent) {
+        if let Some(tx) = &self.dashboard
+            && tx.send(event).is_err()
+        {
+            self.stopped = true;
+        }
+    }
+}
+
+/// Writes GA checkpoints every `interval` generations.
+#[derive(Debug)]
+struct CheckpointWriter {
+    dir: PathBuf,
+    interval: usize,
+}
+
+impl CheckpointWriter {
+    fn new(dir: PathBuf, interval: usize) -> anyhow::Result<Self> {
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir, interval })
+    }
+
+    fn should_save(&self, generation: usize) -> bool {
+        (generation + 1).is_multiple_of(self.interval)
+    }
+
+    /// Saves `checkpoint` and returns its path.
+    fn save(&self, checkpoint: &TrainingCheckpoint) -> anyhow::Result<PathBuf> {
+        let path = self
+            .dir
+            .join(format!("checkpoint-{:04}.json", checkpoint.generation));
+        Output::save_json(checkpoint, Some(path.clone()))?;
+        Ok(path)
+    }
+}
+
+/// Genetic algorithm training run.
+struct GaRun<'a> {
+    ai: AiType,
+    fitness: Option<&'a FitnessDefinition>,
+    config: &'a TrainingConfig,
+    session_evaluator: &'a dyn SessionEvaluator,
+    field_sampler: FieldSampler,
+    checkpoint_writer: Option<&'a CheckpointWriter>,
+}
+
+impl GaRun<'_> {
+    /// Evolves `population` (the unevaluated population of `first_generation`) until
+    /// `max_generations` and returns the evaluated last generation.
+    ///
+    /// If the dashboard is closed, stops after the current generation and saves a
+    /// checkpoint of it.
+    ///
+    /// `rng_seed` seeds the random number generator of each evolution step, so a run
+    /// resumed from a checkpoint evolves the same way as an uninterrupted run.
+    fn run(
+        &self,
+        mut population: Population,
+        first_generation: usize,
+        mut rng_seed: [u8; 32],
+        log: &mut TrainingLog,
+    ) -> anyhow::Result<Population> {
+        let max_generations = self.config.max_generations;
+        log.start(TrainingOverview {
+            title: format!("GA: {}", self.ai.model_name()),
+            weight_names: weight_names(population.board_features(), population.gate()),
+            budget: TrainingBudget::Generations {
+                first: first_generation,
+                total: max_generations,
+            },
+        });
+        for generation in first_generation..max_generations {
+            let phase = self.config.phase(generation);
+            if log.verbose() {
+                eprintln!("Generation #{generation} ({phase:?}):");
+            }
+            let evolver = self.config.evolver(phase);
+            let fields = self.field_sampler.fields(generation);
+            let evaluation_start = Instant::now();
+            population.evaluate_fitness(&fields, self.session_evaluator);
+            let timings = log.timings(evaluation_start);
+
+            if log.verbose() {
+                print_generation_summary(&population);
+            }
+            log.record(GenerationLog::from_population(
+                generation,
+                Some(phase),
+                &population,
+                timings,
+            ))?;
+
+            let stopped = log.is_stopped();
+            if let Some(writer) = self.checkpoint_writer
+                && (stopped || writer.should_save(generation))
+            {
+                let path =
+                    writer.save(&self.checkpoint(generation, &population, &evolver, rng_seed))?;
+                if log.verbose() {
+                    eprintln!("  Checkpoint saved: {}", path.display());
+                }
+            }
+
+            if stopped {
+                break;
+            }
+            if generation + 1 < max_generations {
+                let mut rng = StdRng::from_seed(rng_seed);
+                population = evolver.evolve(&population, &mut rng);
+                rng_seed = rng.random();
+            }
+        }
+
+        if log.verbose() {
+            print_best_individuals(&population);
+        }
+        Ok(population)
+    }
+
+    fn training(&self) -> TrainingInfo<'_> {
+        TrainingInfo {
+            seed: self.field_sampler.seed,
+            config: self.config,
+        }
+    }
+
+    fn checkpoint(
+        &self,
+        generation: usize,
+        population: &Population,
+        evolver: &PopulationEvolver,
+        rng_seed: [u8; 32],
+    ) -> TrainingCheckpoint {
+        TrainingCheckpoint {
+            version: CHECKPOINT_VERSION,
+            saved_at: Utc::now(),
+            ai: self.ai.model_name().to_owned(),
+            fitness: self.fitness.cloned(),
+            seed: self.field_sampler.seed,
+            common_fields: self.field_sampler.common,
+            config: self.config.clone(),
+            generation,
+            rng_seed,
+            evolver: EvolverParams::from(evolver),
+            board_features: population
+                .board_features()
+                .iter()
+                .map(|f| TrainedBoardFeature::new(f, 0.0))
+                .collect(),
+            gate: population.gate().clone(),
+            individuals: population
+                .individuals()
+                .iter()
+                .map(CheckpointIndividual::from)
+                .collect(),
+        }
+    }
+}
+
+/// Runs CMA-ES with the evaluation budget of the GA (`population_size × max_generations`
+/// individuals), so restarts with larger populations run fewer generations.
+fn run_cma_es(
+    features: Vec<BoxedBoardFeature>,
+    gate: HeightGate,
+    config: &TrainingConfig,
+    session_evaluator: &dyn SessionEvaluator,
+    field_sampler: FieldSampler,
+    rng: &mut StdRng,
+    log: &mut TrainingLog,
+) -> anyhow::Result<Individual> {
+    let params = CmaEsParams {
+        initial_sigma: config.cma_es_initial_sigma,
+        population_size: config.population_size,
+        ..CmaEsParams::default()
+    };
+    let mut optimizer = CmaEs::new(features, gate, params, rng);
+    let mut evaluations = 0;
+    let mut generation = 0;
+    while evaluations < config.cma_es_budget() {
+        if log.verbose() {
+            eprintln!(
+                "Generation #{generation} (Run #{}, λ={}, σ={:.4}):",
+                optimizer.restarts(),
+                optimizer.population_size(),
+                optimizer.sigma(),
+            );
+        }
+        let mut population = optimizer.ask(rng);
+        let fields = field_sampler.fields(generation);
+        let evaluation_start = Instant::now();
+        population.evaluate_fitness(&fields, session_evaluator);
+        let timings = log.timings(evaluation_start);
+        evaluations += population.individuals().len();
+
+        if log.verbose() {
+            print_generation_summary(&population);
+        }
+        log.record(GenerationLog::from_population(
+            generation,
+            None,
+            &population,
+            timings,
+        ))?;
+
+        if optimizer.tell(&population, rng) && log.verbose() {
+            eprintln!(
+                "  Restarting with population size {}",
+                optimizer.population_size()
+            );
+        }
+        generation += 1;
+        if log.is_stopped() {
+            break;
+        }
+    }
+
+    // The best fitness seen is biased towards lucky evaluations, so compare the best
+    // individual and mean of every run on fields no generation played
+    let mut candidates = optimizer.final_candidates();
+    if log.verbose() {
+        eprintln!(
+            "Re-evaluating {} final candidates...",
+            candidates.individuals().len()
+        );
+    }
+    candidates.evaluate_fitness(&field_sampler.validation_fields(), session_evaluator);
+    let best = candidates.individuals().first().unwrap().clone();
+    if log.verbose() {
+        eprintln!("Best Individual:");
+        eprintln!("  {:?} => {}", best.weights(), best.fitness());
+    }
+    Ok(best)
+}
+
+fn run_nsga2(
+    features: Vec<BoxedBoardFeature>,
+    gate: HeightGate,
+    config: &TrainingConfig,
+    field_sampler: FieldSampler,
+    rng: &mut StdRng,
+    log: &mut TrainingLog,
+) -> anyhow::Result<ParetoPopulation> {
+    let (names, definitions): (Vec<_>, Vec<_>) = objectives().into_iter().unzip();
+    let objectives = definitions
+        .into_iter()
+        .map(FitnessSessionEvaluator::new)
+        .collect::<Result<Vec<_>, _>>()?;
+    let session_evaluator =
+        DefaultMultiObjectiveSessionEvaluator::new(config.turn_limit, objectives);
+
+    let mut population = ParetoPopulation::random_phased(
+        features,
+        gate,
+        config.population_size,
+        rng,
+        config.phase_params(EvolutionPhase::default()).max_weight,
+    );
+    let evaluation_start = Instant::now();
+    population.evaluate_objectives(&field_sampler.fields(0), &session_evaluator);
+    let mut timings = log.timings(evaluation_start);
+    for generation in 0..config.max_generations {
+        let phase = config.phase(generation);
+        if log.verbose() {
+            eprintln!("Generation #{generation} ({phase:?}):");
+            print_pareto_summary(&names, &population);
+        }
+        log.record(GenerationLog::from_pareto_population(
+            generation,
+            phase,
+            &names,
+            &population,
+            timings,
+        ))?;
+
+        if log.is_stopped() {
+            break;
+        }
+        if generation + 1 < config.max_generations {
+            let evolver = config.nsga2_evolver(phase);
+            let mut combined = evolver.evolve(&population, rng);
+            let evaluation_start = Instant::now();
+            combined.evaluate_objectives(&field_sampler.fields(generation + 1), &session_evaluator);
+            timings = log.timings(evaluation_start);
+            population = combined.select(config.population_size);
+        }
+    }
+    Ok(population)
+}
+
+/// Game fields each generation is evaluated on.
+///
+/// Fields are generated with [`GameField::with_seed`] from a stream derived from `seed` and
+/// the generation index, so all individuals of a generation play the same piece sequences
+/// (common random numbers) and a run with the same seed plays the same games. With
+/// `common`, every generation plays the fields of generation 0.
+#[derive(Debug, Clone, Copy)]
+struct FieldSampler {
+    seed: u64,
+    common: bool,
+    /// Number of fields per generation
+    games: usize,
+}
+
+impl FieldSampler {
+    fn new(seed: Option<u64>, common: bool, games: usize) -> Self {
+        let seed = seed.unwrap_or_else(|| rand::rng().random());
+        eprintln!("Seed: {seed}");
+        Self {
+            seed,
+            common,
+            games,
+        }
+    }
+
+    fn fields(&self, generation: usize) -> Vec<GameField> {
+        self.stream_fields(if self.common { 0 } else { generation as u64 })
+    }
+
+    /// Fields that no generation plays, for comparing final candidates.
+    fn validation_fields(&self) -> Vec<GameField> {
+        self.stream_fields(u64::MAX)
+    }
+
+    fn stream_fields(&self, stream: u64) -> Vec<GameField> {
+        let mut key = [0; 32];
+        key[..8].copy_from_slice(&self.seed.to_le_bytes());
+        key[8..16].copy_from_slice(&stream.to_le_bytes());
+        let mut rng = StdRng::from_seed(key);
+        (0..self.games)
+            .map(|_| GameField::with_seed(rng.random()))
+            .collect()
+    }
+}
+
+fn print_generation_summary(population: &Population) {
+    let weight_stats = population.compute_weight_stats();
+    #[expect(clippy::cast_precision_loss)]
+    let weight_norm_std_dev_mean = weight_stats
+        .iter()
+        .map(|s| s.normalized_std_dev)
+        .sum::<f32>()
+        / weight_stats.len() as f32;
+
+    let fitness_stats = population.compute_fitness_stats();
+
+    eprintln!("  Individuals:");
+    for (i, ind) in population.individuals().iter().enumerate() {
+        eprintln!("  {i:2}: {:.3?} => {:.3}", ind.weights(), ind.fitness());
+    }
+
+    eprintln!("  Weights Stats:");
+    eprintln!(
+        "    Min:        {:.3?}",
+        weight_stats.iter().map(|s| s.min).collect::<Vec<_>>(),
+    );
+    eprintln!(
+        "    Max:        {:.3?}",
+        weight_stats.iter().map(|s| s.max).collect::<Vec<_>>(),
+    );
+    eprintln!(
+        "    Mean:       {:.3?}",
+        weight_stats.iter().map(|s| s.mean).collect::<Vec<_>>(),
+    );
+    eprintln!(
+        "    NormStddev: {:.3?}",
+        weight_stats
+            .iter()
+            .map(|s| s.normalized_std_dev)
+            .collect::<Vec<_>>(),
+    );
+    eprintln!("    => Mean:    {weight_norm_std_dev_mean:.3}");
+
+    eprintln!("  Fitness Stats:");
+    eprintln!("    Min:  {:.3}", fitness_stats.min);
+    eprintln!("    Max:  {:.3}", fitness_stats.max);
+    eprintln!("    Mean: {:.3}", fitness_stats.mean);
+}
+
+fn print_best_individuals(population: &Population) {
+    eprintln!("Best Individuals:");
+    for (i, ind) in population.individuals().iter().take(5).enumerate() {
+        eprintln!("  {i:2}: {:?} => {}", ind.weights(), ind.fitness());
+    }
+}
+
+fn print_pareto_summary(names: &[&str], population: &ParetoPopulation) {
+    let front = population.pareto_front();
+    eprintln!("  Pareto Front ({} individuals):", front.len());
+    for (i, ind) in front.iter().enumerate() {
+        let objectives = iter::zip(names, ind.objectives())
+            .map(|(name, value)| format!("{name}={value:.3}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        eprintln!("  {i:2}: {:.3?} => {objectives}", ind.weights());
+    }
+}
+
+fn save_pareto_front(
+    model_name: &str,
+    population: &ParetoPopulation,
+    training: &TrainingInfo,
+    outputs: &RunOutputs,
+) -> anyhow::Result<()> {
+    let output_dir = outputs.output.as_ref().unwrap();
+    let names = objectives()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+    let mut front = population.pareto_front();
+    // Order the front along the trade-off, from the most survival-oriented model
+    front.sort_by(|a, b| b.objectives()[0].total_cmp(&a.objectives()[0]));
+
+    fs::create_dir_all(output_dir)?;
+    for (i, ind) in front.into_iter().enumerate() {
+        let mut model = build_model(
+            &format!("{model_name}-{i:02}"),
+            population.gate(),
+            population.board_features(),
+            ind.weights(),
+            ind.objectives()[0],
+            training,
+        );
+        model.objectives = iter::zip(&names, ind.objectives())
+            .map(|(name, value)| ((*name).to_owned(), *value))
+            .collect();
+        let path = output_dir.join(format!("{}.json", model.name));
+        outputs.save_model(&model, Some(&path))?;
+    }
+    Ok(())
+}
+
+fn build_model(
+    model_name: &str,
+    gate: &HeightGate,
+    features: &[BoxedBoardFeature],
+    weights: &[f32],
+    fitness: f32,
+    training: &TrainingInfo,
+) -> AiModel {
+    if gate.num_phases() == 1 {
+        AiModel {
+            name: model_name.to_owned(),
+            trained_at: Utc::now(),
+            final_fitness: fitness,
+            objectives: BTreeMap::new(),
+            training_seed: Some(training.seed),
+            training_config: Some(Box::new(training.config.clone())),
+            board_features: iter::zip(features, weights)
+                .map(|(f, w)| TrainedBoardFeature::new(f, *w))
+                .collect(),
+            evaluator: ModelEvaluator::Linear,
+        }
+    } else {
+        AiModel {
+            name: format!("{model_name}-phased"),
+            trained_at: Utc::now(),
+            final_fitness: fitness,
+            objectives: BTreeMap::new(),
+            training_seed: Some(training.seed),
+            training_config: Some(Box::new(training.config.clone())),
+            board_features: features
+                .iter()
+                .map(|f| TrainedBoardFeature::new(f, 0.0))
+                .collect(),
+            evaluator: ModelEvaluator::Phased {
+                gate: gate.clone(),
+                weights: weights
+                    .chunks(features.len())
+                    .map(<[f32]>::to_vec)
+                    .collect(),
+            },
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use chrono::Utc;
+use oxidris_analysis::{feature_builder::KmTransform, survival_regression::SurvivalRegressionData};
+use oxidris_engine::GameField;
+use oxidris_evaluator::{
+    placement_evaluator::TreePlacementEvaluator, turn_evaluator::TurnEvaluator,
+};
+use oxidris_training::gbdt::GbdtTrainer;
+use rand::{Rng as _, SeedableRng as _, rngs::StdRng};
+
+use crate::{
+    command::train_ai::AiType,
+    schema::{
+        ai_model::{AiModel, ModelEvaluator, TrainedBoardFeature},
+        training_config::TrainingConfig,
+    },
+    util,
+};
+
+#[derive(Default, Debug, Clone, clap::Args)]
+pub(crate) struct TrainTreeArg {
+    /// Boards data file path
+    boards_file: PathBuf,
+    /// Feature set and fitness function used to evaluate the trained model
+    #[arg(long, default_value = "aggro-km")]
+    ai: AiType,
+    /// Transform used for KM-based features
+    /// (table, piecewise-linear, spline, survival-probability, restricted-mean)
+    #[arg(long, default_value = "table")]
+    km_transform: KmTransform,
+    /// Number of trees in the ensemble
+    #[arg(long, default_value_t = GbdtTrainer::default().num_trees)]
+    num_trees: usize,
+    /// Maximum depth of each tree
+    #[arg(long, default_value_t = GbdtTrainer::default().max_depth)]
+    max_depth: usize,
+    /// Shrinkage applied to each tree
+    #[arg(long, default_value_t = GbdtTrainer::default().learning_rate)]
+    learning_rate: f32,
+    /// Minimum number of boards in each leaf
+    #[arg(long, default_value_t = GbdtTrainer::default().min_samples_leaf)]
+    min_samples_leaf: usize,
+    /// Seed for the evaluation games (use the same seed to compare models on the same games)
+    #[arg(long)]
+    seed: Option<u64>,
+    /// Output file path
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+pub(crate) fn run(arg: &TrainTreeArg) -> anyhow::Result<()> {
+    let TrainTreeArg {
+        boards_file,
+        ai,
+        km_transform,
+        num_trees,
+        max_depth,
+        learning_rate,
+        min_samples_leaf,
+        seed,
+        output,
+    } = arg;
+
+    eprintln!("Loading boards from {}...", boards_file.display());
+    let collection = util::read_boards_file(boards_file)?;
+    let sessions = &collection.sessions;
+    eprintln!("Loaded {} sessions", sessions.len());
+
+    let features = util::build_feature_from_session(
+        ai.feature_set(*km_transform),
+        sessions,
+        collection.max_turns,
+    )?;
+
+    eprintln!("Computing survival labels...");
+    let data = SurvivalRegressionData::from_sessions(&features, sessions, collection.max_turns);
+    eprintln!("Computed {} labels", data.labels.len());
+
+    eprintln!("Fitting gradient-boosted trees...");
+    let trainer = GbdtTrainer {
+        num_trees: *num_trees,
+        max_depth: *max_depth,
+        learning_rate: *learning_rate,
+        min_samples_leaf: *min_samples_leaf,
+        ..GbdtTrainer::default()
+    };
+    let model = trainer.fit(&data.inputs, &data.labels);
+    eprintln!("Fitted {} trees", model.trees().len());
+
+    let TrainingConfig {
+        games_per_individual,
+        turn_limit,
+        ..
+    } = TrainingConfig::default();
+    eprintln!("Evaluating model on {games_per_individual} games...");
+    let fields: Vec<GameField> = match seed {
+        Some(seed) => {
+            let mut rng = StdRng::seed_from_u64(*seed);
+            (0..games_per_individual)
+                .map(|_| GameField::with_seed(rng.random()))
+                .collect()
+        }
+        None => (0..games_per_individual)
+            .map(|_| GameField::new())
+            .collect(),
+    };
+    let placement_evaluator = TreePlacementEvaluator::new(features.clone(), model.clone())?;
+    let turn_evaluator = TurnEvaluator::new(Box::new(placement_evaluator));
+    let fitness = ai
+        .session_evaluator(turn_limit)
+        .play_and_evaluate_sessions(&fields, &turn_evaluator);
+
+    let model = AiModel {
+        name: format!("{}-gbdt", ai.model_name()),
+        trained_at: Utc::now(),
+        final_fitness: fitness,
+        objectives: BTreeMap::new(),
+        training_seed: *seed,
+        training_config: None,
+        board_features: features
+            .iter()
+            .map(|f| TrainedBoardFeature::new(f, 0.0))
+            .collect(),
+        evaluator: ModelEvaluator::Gbdt { model },
+    };
+    util::save_ai_model(&model, output.as_ref())
+}
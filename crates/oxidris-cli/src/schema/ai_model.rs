@@ -1,13 +1,93 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
 use chrono::{DateTime, Utc};
-use oxidris_evaluator::board_feature::{self, BoxedBoardFeature, transform::FeatureProcessing};
+use oxidris_evaluator::{
+    board_feature::{self, BoxedBoardFeature, transform::FeatureProcessing},
+    placement_evaluator::{
+        EnsemblePlacementEvaluator, EnsembleRule, FeatureBasedPlacementEvaluator,
+        GradientBoostedTrees, HeightGate, MlpNetwork, MlpPlacementEvaluator,
+        PhasedPlacementEvaluator, PlacementEvaluator, TreePlacementEvaluator,
+    },
+};
 use serde::{Deserialize, Serialize};
 
+use crate::{schema::training_config::TrainingConfig, util};
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AiModel {
     pub name: String,
     pub trained_at: DateTime<Utc>,
     pub final_fitness: f32,
+    /// Objective values of a model from a Pareto front (multi-objective training)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub objectives: BTreeMap<String, f32>,
+    /// Seed of the training run (`train-ai --seed`; for `train-tree`, the seed of the
+    /// evaluation games)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub training_seed: Option<u64>,
+    /// Hyperparameters of the training run (`train-ai --config` and overrides)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub training_config: Option<Box<TrainingConfig>>,
+    #[serde(default)]
     pub board_features: Vec<TrainedBoardFeature>,
+    #[serde(default, skip_serializing_if = "ModelEvaluator::is_linear")]
+    pub evaluator: ModelEvaluator,
+}
+
+/// How board feature values are combined into a placement score.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ModelEvaluator {
+    /// Weighted sum using [`TrainedBoardFeature::weight`]
+    #[default]
+    Linear,
+    /// Multi-layer perceptron over the normalized feature values (feature weights are unused)
+    Mlp {
+        /// Also feed the column heights to the network
+        #[serde(default)]
+        column_heights: bool,
+        network: MlpNetwork,
+    },
+    /// Gradient-boosted regression trees over the normalized feature values
+    /// (feature weights are unused)
+    Gbdt { model: GradientBoostedTrees },
+    /// Weighted sum with one weight set per stack height phase (feature weights are unused)
+    Phased {
+        /// Stack heights splitting the phases
+        gate: HeightGate,
+        /// Weight set of each phase, in feature order
+        weights: Vec<Vec<f32>>,
+    },
+    /// Combination of other models (board features are unused)
+    Ensemble {
+        members: Vec<EnsembleMember>,
+        rule: EnsembleRule,
+    },
+}
+
+impl ModelEvaluator {
+    fn is_linear(&self) -> bool {
+        matches!(self, Self::Linear)
+    }
+}
+
+/// Member model of an ensemble.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EnsembleMember {
+    /// Path to the member model file, relative to the ensemble model file
+    pub model: PathBuf,
+    /// Weight of the member's vote
+    #[serde(default = "EnsembleMember::default_weight")]
+    pub weight: f32,
+}
+
+impl EnsembleMember {
+    fn default_weight() -> f32 {
+        1.0
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -19,24 +99,90 @@ pub struct TrainedBoardFeature {
     pub weight: f32,
 }
 
+impl TrainedBoardFeature {
+    pub(crate) fn new(feature: &BoxedBoardFeature, weight: f32) -> Self {
+        Self {
+            id: feature.id().to_owned(),
+            name: feature.name().to_owned(),
+            source_id: feature.feature_source().id().to_owned(),
+            processing: feature.feature_processing(),
+            weight,
+        }
+    }
+
+    /// Rebuilds the board feature from its source and processing.
+    pub(crate) fn to_board_feature(&self) -> anyhow::Result<BoxedBoardFeature> {
+        let source = board_feature::source::board_feature_source_by_id(&self.source_id)
+            .ok_or_else(|| anyhow::anyhow!("Feature source ID {} not found", self.source_id))?;
+        Ok(self
+            .processing
+            .apply(self.id.clone(), self.name.clone(), source)?)
+    }
+}
+
 impl AiModel {
+    /// Resolves relative paths in the model against the directory of the model file.
+    pub(crate) fn resolve_paths(&mut self, model_dir: &Path) {
+        if let ModelEvaluator::Ensemble { members, .. } = &mut self.evaluator {
+            for member in members {
+                member.model = model_dir.join(&member.model);
+            }
+        }
+    }
+
     pub(crate) fn to_feature_weights(&self) -> anyhow::Result<(Vec<BoxedBoardFeature>, Vec<f32>)> {
-        let all_sources = board_feature::source::all_board_feature_sources();
         self.board_features
             .iter()
-            .map(|tf| {
-                let source = all_sources
-                    .iter()
-                    .find(|s| s.id() == tf.source_id)
-                    .ok_or_else(|| {
-                        anyhow::anyhow!("Feature source ID {} in model not found", tf.source_id)
-                    })?
-                    .clone();
-                Ok((
-                    tf.processing.apply(tf.id.clone(), tf.name.clone(), source),
-                    tf.weight,
-                ))
-            })
+            .map(|tf| Ok((tf.to_board_feature()?, tf.weight)))
             .collect()
     }
+
+    pub(crate) fn to_placement_evaluator(&self) -> anyhow::Result<Box<dyn PlacementEvaluator>> {
+        let (features, weights) = self.to_feature_weights()?;
+        let evaluator: Box<dyn PlacementEvaluator> =
+            match &self.evaluator {
+                ModelEvaluator::Linear => {
+                    Box::new(FeatureBasedPlacementEvaluator::new(features, weights))
+                }
+                ModelEvaluator::Mlp {
+                    column_heights,
+                    network,
+                } => Box::new(MlpPlacementEvaluator::new(
+                    features,
+                    *column_heights,
+                    network.clone(),
+                )?),
+                ModelEvaluator::Gbdt { model } => {
+                    Box::new(TreePlacementEvaluator::new(features, model.clone())?)
+                }
+                ModelEvaluator::Phased { gate, weights } => Box::new(
+                    PhasedPlacementEvaluator::new(features, gate.clone(), weights.clone())?,
+                ),
+                ModelEvaluator::Ensemble { members, rule } => {
+                    Box::new(Self::ensemble_evaluator(members, rule.clone())?)
+                }
+            };
+        Ok(evaluator)
+    }
+
+    fn ensemble_evaluator(
+        members: &[EnsembleMember],
+        rule: EnsembleRule,
+    ) -> anyhow::Result<EnsemblePlacementEvaluator<'static>> {
+        let evaluators = members
+            .iter()
+            .map(|member| {
+                let model = util::read_ai_model_file(&member.model)?;
+                if matches!(model.evaluator, ModelEvaluator::Ensemble { .. }) {
+                    anyhow::bail!(
+                        "Nested ensemble model is not supported: {}",
+                        member.model.display()
+                    );
+                }
+                model.to_placement_evaluator()
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let weights = members.iter().map(|member| member.weight).collect();
+        Ok(EnsemblePlacementEvaluator::new(evaluators, weights, rule)?)
+    }
 }
@@ -0,0 +1,290 @@
+use chrono::{DateTime, Utc};
+use oxidris_evaluator::{placement_evaluator::HeightGate, session_evaluator::FitnessDefinition};
+use oxidris_training::genetic::{Individual, Population, PopulationEvolver};
+use rand::{Rng as _, SeedableRng as _, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ai_model::TrainedBoardFeature, training_config::TrainingConfig};
+
+/// Current version of [`TrainingCheckpoint`].
+///
+/// Bump when the format changes incompatibly; older checkpoints are rejected on resume.
+pub const CHECKPOINT_VERSION: u32 = 1;
+
+/// Snapshot of a genetic algorithm training run, written every few generations.
+///
+/// The population is stored after fitness evaluation, so resuming continues with the
+/// evolution step of [`generation`](Self::generation).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingCheckpoint {
+    /// Format version ([`CHECKPOINT_VERSION`])
+    pub version: u32,
+    pub saved_at: DateTime<Utc>,
+    /// Fitness function and feature set (`--ai` value)
+    pub ai: String,
+    /// Fitness definition replacing the fitness function of `ai` (`--fitness` file contents)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fitness: Option<FitnessDefinition>,
+    /// Seed of the training run (`--seed`), from which evaluation fields are derived
+    pub seed: u64,
+    /// Whether every generation is evaluated on the same fields (`--common-fields`)
+    pub common_fields: bool,
+    /// Training hyperparameters
+    pub config: TrainingConfig,
+    /// Index of the evaluated generation
+    pub generation: usize,
+    /// Seed of the random number generator used to evolve the next generation (hex)
+    #[serde(with = "hex_seed")]
+    pub rng_seed: [u8; 32],
+    /// Evolver parameters used to evolve the next generation
+    pub evolver: EvolverParams,
+    /// Feature definitions (weights are unused)
+    pub board_features: Vec<TrainedBoardFeature>,
+    pub gate: HeightGate,
+    /// Evaluated individuals, sorted by fitness descending
+    pub individuals: Vec<CheckpointIndividual>,
+}
+
+/// Parameters of [`PopulationEvolver`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvolverParams {
+    pub elite_count: usize,
+    pub max_weight: f32,
+    pub tournament_size: usize,
+    pub mutation_sigma: f32,
+    pub blx_alpha: f32,
+    pub mutation_rate: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckpointIndividual {
+    /// Feature weights (weight sets of all phases concatenated)
+    pub weights: Vec<f32>,
+    /// Transform shape parameters of all features concatenated (e.g., piecewise-linear knot
+    /// outputs)
+    pub shape_params: Vec<f32>,
+    pub fitness: f32,
+}
+
+impl TrainingCheckpoint {
+    /// Rebuilds the evaluated population.
+    pub(crate) fn to_population(&self) -> anyhow::Result<Population> {
+        let features = self
+            .board_features
+            .iter()
+            .map(TrainedBoardFeature::to_board_feature)
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let individuals = self
+            .individuals
+            .iter()
+            .map(|ind| {
+                Individual::new(ind.weights.clone(), ind.fitness)
+                    .with_shape_params(ind.shape_params.clone())
+            })
+            .collect();
+        Ok(Population::new(features, self.gate.clone(), individuals))
+    }
+
+    /// Evolves the next generation from the evaluated population, as the interrupted run
+    /// would have, and returns it with the RNG seed for the evolution after it.
+    pub(crate) fn evolve_next(&self) -> anyhow::Result<(Population, [u8; 32])> {
+        let population = self.to_population()?;
+        let mut rng = StdRng::from_seed(self.rng_seed);
+        let population = PopulationEvolver::from(&self.evolver).evolve(&population, &mut rng);
+        Ok((population, rng.random()))
+    }
+}
+
+impl From<&PopulationEvolver> for EvolverParams {
+    fn from(evolver: &PopulationEvolver) -> Self {
+        Self {
+            elite_count: evolver.elite_count,
+            max_weight: evolver.max_weight,
+            tournament_size: evolver.tournament_size,
+            mutation_sigma: evolver.mutation_sigma,
+            blx_alpha: evolver.blx_alpha,
+            mutation_rate: evolver.mutation_rate,
+        }
+    }
+}
+
+impl From<&EvolverParams> for PopulationEvolver {
+    fn from(params: &EvolverParams) -> Self {
+        Self {
+            elite_count: params.elite_count,
+            max_weight: params.max_weight,
+            tournament_size: params.tournament_size,
+            mutation_sigma: params.mutation_sigma,
+            blx_alpha: params.blx_alpha,
+            mutation_rate: params.mutation_rate,
+        }
+    }
+}
+
+impl From<&Individual> for CheckpointIndividual {
+    fn from(individual: &Individual) -> Self {
+        Self {
+            weights: individual.weights().to_vec(),
+            shape_params: individual.shape_params().to_vec(),
+            fitness: individual.fitness(),
+        }
+    }
+}
+
+mod hex_seed {
+    use std::fmt::Write as _;
+
+    use serde::{Deserialize as _, Deserializer, Serializer, de::Error as _};
+
+    pub fn serialize<S>(seed: &[u8; 32], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let hex = seed.iter().fold(String::new(), |mut hex, b| {
+            write!(hex, "{b:02x}").unwrap();
+            hex
+        });
+        serializer.serialize_str(&hex)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<[u8; 32], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let hex = String::deserialize(deserializer)?;
+        let invalid = || D::Error::custom(format!("invalid seed (expected 64 hex digits): {hex}"));
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(invalid());
+        }
+        let mut seed = [0; 32];
+        for (byte, i) in seed.iter_mut().zip((0..64).step_by(2)) {
+            *byte = u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid())?;
+        }
+        Ok(seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use oxidris_evaluator::board_feature::{
+        BoxedBoardFeature, FeatureSignal,
+        source::{MaxHeight, NumHoles},
+        transform::{PiecewiseLinearParam, PiecewiseLinearTransform},
+    };
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Seed(#[serde(with = "super::hex_seed")] [u8; 32]);
+
+    #[test]
+    fn test_hex_seed_roundtrip() {
+        let seed = Seed(std::array::from_fn(|i| u8::try_from(i * 8).unwrap()));
+        let json = serde_json::to_string(&seed).unwrap();
+        assert_eq!(
+            json,
+            r#""0008101820283038404850586068707880889098a0a8b0b8c0c8d0d8e0e8f0f8""#
+        );
+        assert_eq!(serde_json::from_str::<Seed>(&json).unwrap(), seed);
+        assert!(serde_json::from_str::<Seed>(r#""00""#).is_err());
+    }
+
+    #[test]
+    fn test_resume_roundtrip() {
+        let param = PiecewiseLinearParam::new(
+            FeatureSignal::Negative,
+            vec![0.0, 2.0, 10.0],
+            vec![0.0, 0.8, 1.0],
+        )
+        .unwrap();
+        let features: Vec<BoxedBoardFeature> = vec![
+            Box::new(PiecewiseLinearTransform::new(
+                "holes".to_owned(),
+                "Holes".to_owned(),
+                NumHoles,
+                param,
+            )),
+            Box::new(PiecewiseLinearTransform::new(
+                "height".to_owned(),
+                "Height".to_owned(),
+                MaxHeight,
+                PiecewiseLinearParam::new(FeatureSignal::Negative, vec![0.0], vec![0.0]).unwrap(),
+            )),
+        ];
+        let mut rng = StdRng::seed_from_u64(0);
+        let population = Population::random(features, 6, &mut rng, 1.0);
+        #[expect(clippy::cast_precision_loss)]
+        let individuals = population
+            .individuals()
+            .iter()
+            .enumerate()
+            .map(|(i, ind)| {
+                // Evaluated populations are sorted by fitness descending
+                Individual::new(ind.weights().to_vec(), -(i as f32))
+                    .with_shape_params(ind.shape_params().to_vec())
+            })
+            .collect();
+        let population = Population::new(
+            population.board_features().to_vec(),
+            population.gate().clone(),
+            individuals,
+        );
+        let evolver = PopulationEvolver {
+            elite_count: 1,
+            max_weight: 1.0,
+            tournament_size: 2,
+            mutation_sigma: 0.1,
+            blx_alpha: 0.5,
+            mutation_rate: 0.5,
+        };
+        let rng_seed = rng.random();
+
+        let checkpoint = TrainingCheckpoint {
+            version: CHECKPOINT_VERSION,
+            saved_at: Utc::now(),
+            ai: "aggro-km".to_owned(),
+            fitness: None,
+            seed: 42,
+            common_fields: false,
+            config: TrainingConfig::default(),
+            generation: 7,
+            rng_seed,
+            evolver: EvolverParams::from(&evolver),
+            board_features: population
+                .board_features()
+                .iter()
+                .map(|f| TrainedBoardFeature::new(f, 0.0))
+                .collect(),
+            gate: population.gate().clone(),
+            individuals: population
+                .individuals()
+                .iter()
+                .map(CheckpointIndividual::from)
+                .collect(),
+        };
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        let restored: TrainingCheckpoint = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.generation, 7);
+        assert_eq!(restored.rng_seed, rng_seed);
+        let to_individuals = |population: &Population| {
+            population
+                .individuals()
+                .iter()
+                .map(CheckpointIndividual::from)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            to_individuals(&restored.to_population().unwrap()),
+            to_individuals(&population)
+        );
+
+        // The resumed run evolves the same generation as the uninterrupted run
+        let mut rng = StdRng::from_seed(rng_seed);
+        let expected = evolver.evolve(&population, &mut rng);
+        let expected_seed: [u8; 32] = rng.random();
+        let (actual, actual_seed) = restored.evolve_next().unwrap();
+        assert_eq!(to_individuals(&actual), to_individuals(&expected));
+        assert_eq!(actual_seed, expected_seed);
+    }
+}
@@ -1,3 +1,6 @@
 pub mod ai_model;
+pub mod checkpoint;
 pub mod km_normalization;
 pub mod record;
+pub mod training_config;
+pub mod training_log;
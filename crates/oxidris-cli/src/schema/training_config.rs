@@ -0,0 +1,283 @@
+use oxidris_training::{genetic::PopulationEvolver, nsga2::Nsga2Evolver};
+use serde::{Deserialize, Serialize};
+
+/// Hyperparameters of a `train-ai` run.
+///
+/// Loaded from a TOML or JSON file (`train-ai --config`); omitted fields take the values of
+/// the default preset. The config is saved in trained models and checkpoints.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TrainingConfig {
+    /// Number of individuals per generation (initial population size for CMA-ES)
+    pub population_size: usize,
+    /// Number of generations (CMA-ES: evaluation budget of `population_size × max_generations`)
+    pub max_generations: usize,
+    /// Number of games each individual plays per generation
+    pub games_per_individual: usize,
+    /// Maximum number of turns per game
+    pub turn_limit: usize,
+    /// Number of best individuals copied unchanged into the next generation
+    pub elite_count: usize,
+    /// Number of individuals competing in each tournament selection
+    pub tournament_size: usize,
+    /// Probability of mutating each weight
+    pub mutation_rate: f32,
+    /// BLX-α crossover range extension
+    pub blx_alpha: f32,
+    /// Initial step size of CMA-ES
+    pub cma_es_initial_sigma: f32,
+    /// Evolution phases
+    pub phases: PhasesConfig,
+}
+
+impl Default for TrainingConfig {
+    fn default() -> Self {
+        Self {
+            population_size: 30,
+            max_generations: 200,
+            games_per_individual: 3,
+            turn_limit: 3000,
+            elite_count: 2,
+            tournament_size: 2,
+            mutation_rate: 0.3,
+            blx_alpha: 0.2,
+            cma_es_initial_sigma: 0.02,
+            phases: PhasesConfig::default(),
+        }
+    }
+}
+
+/// Evolution phases of the genetic algorithms, from broad exploration to fine convergence.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EvolutionPhase {
+    #[default]
+    Exploration,
+    Transition,
+    Convergence,
+}
+
+/// Generation ranges and parameters of the [`EvolutionPhase`]s.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PhasesConfig {
+    /// First generation of the transition phase
+    pub transition_start: usize,
+    /// First generation of the convergence phase
+    pub convergence_start: usize,
+    pub exploration: PhaseParams,
+    pub transition: PhaseParams,
+    pub convergence: PhaseParams,
+}
+
+impl Default for PhasesConfig {
+    fn default() -> Self {
+        Self {
+            transition_start: 30,
+            convergence_start: 80,
+            exploration: PhaseParams {
+                max_weight: 0.5,
+                mutation_sigma: 0.05,
+            },
+            transition: PhaseParams {
+                max_weight: 0.8,
+                mutation_sigma: 0.02,
+            },
+            convergence: PhaseParams {
+                max_weight: 1.0,
+                mutation_sigma: 0.01,
+            },
+        }
+    }
+}
+
+/// Parameters of an [`EvolutionPhase`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PhaseParams {
+    /// Upper bound of each weight
+    pub max_weight: f32,
+    /// Standard deviation of Gaussian mutation
+    pub mutation_sigma: f32,
+}
+
+impl TrainingConfig {
+    /// Checks that the parameters describe a runnable training.
+    pub(crate) fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.population_size >= 2,
+            "population_size must be at least 2"
+        );
+        anyhow::ensure!(self.max_generations > 0, "max_generations must be positive");
+        anyhow::ensure!(
+            self.games_per_individual > 0,
+            "games_per_individual must be positive"
+        );
+        anyhow::ensure!(self.turn_limit > 0, "turn_limit must be positive");
+        anyhow::ensure!(
+            self.elite_count <= self.population_size,
+            "elite_count must not exceed population_size"
+        );
+        anyhow::ensure!(
+            (1..=self.population_size).contains(&self.tournament_size),
+            "tournament_size must be between 1 and population_size"
+        );
+        anyhow::ensure!(
+            self.phases.transition_start <= self.phases.convergence_start,
+            "phases.transition_start must not exceed phases.convergence_start"
+        );
+        anyhow::ensure!(
+            (0.0..=1.0).contains(&self.mutation_rate),
+            "mutation_rate must be between 0 and 1"
+        );
+        anyhow::ensure!(
+            self.blx_alpha.is_finite() && self.blx_alpha >= 0.0,
+            "blx_alpha must be finite and non-negative"
+        );
+        anyhow::ensure!(
+            self.cma_es_initial_sigma.is_finite() && self.cma_es_initial_sigma > 0.0,
+            "cma_es_initial_sigma must be finite and positive"
+        );
+        for (name, params) in [
+            ("exploration", &self.phases.exploration),
+            ("transition", &self.phases.transition),
+            ("convergence", &self.phases.convergence),
+        ] {
+            anyhow::ensure!(
+                params.max_weight.is_finite() && params.max_weight > 0.0,
+                "phases.{name}.max_weight must be finite and positive"
+            );
+            anyhow::ensure!(
+                params.mutation_sigma.is_finite() && params.mutation_sigma >= 0.0,
+                "phases.{name}.mutation_sigma must be finite and non-negative"
+            );
+        }
+        Ok(())
+    }
+
+    /// Number of individuals CMA-ES evaluates, the evaluation budget of the GA.
+    pub(crate) fn cma_es_budget(&self) -> usize {
+        self.population_size * self.max_generations
+    }
+
+    pub(crate) fn phase(&self, generation: usize) -> EvolutionPhase {
+        if generation < self.phases.transition_start {
+            EvolutionPhase::Exploration
+        } else if generation < self.phases.convergence_start {
+            EvolutionPhase::Transition
+        } else {
+            EvolutionPhase::Convergence
+        }
+    }
+
+    pub(crate) fn phase_params(&self, phase: EvolutionPhase) -> &PhaseParams {
+        match phase {
+            EvolutionPhase::Exploration => &self.phases.exploration,
+            EvolutionPhase::Transition => &self.phases.transition,
+            EvolutionPhase::Convergence => &self.phases.convergence,
+        }
+    }
+
+    pub(crate) fn evolver(&self, phase: EvolutionPhase) -> PopulationEvolver {
+        let params = self.phase_params(phase);
+        PopulationEvolver {
+            elite_count: self.elite_count,
+            tournament_size: self.tournament_size,
+            max_weight: params.max_weight,
+            mutation_sigma: params.mutation_sigma,
+            blx_alpha: self.blx_alpha,
+            mutation_rate: self.mutation_rate,
+        }
+    }
+
+    pub(crate) fn nsga2_evolver(&self, phase: EvolutionPhase) -> Nsga2Evolver {
+        let params = self.phase_params(phase);
+        Nsga2Evolver {
+            tournament_size: self.tournament_size,
+            max_weight: params.max_weight,
+            mutation_sigma: params.mutation_sigma,
+            blx_alpha: self.blx_alpha,
+            mutation_rate: self.mutation_rate,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_config_uses_defaults() {
+        let config: TrainingConfig = toml::from_str(
+            "
+            population_size = 50
+
+            [phases]
+            transition_start = 10
+
+            [phases.convergence]
+            max_weight = 2.0
+            mutation_sigma = 0.005
+            ",
+        )
+        .unwrap();
+        config.validate().unwrap();
+
+        let default = TrainingConfig::default();
+        assert_eq!(config.population_size, 50);
+        assert_eq!(config.max_generations, default.max_generations);
+        assert_eq!(config.phases.exploration, default.phases.exploration);
+        assert!((config.phases.convergence.max_weight - 2.0).abs() < f32::EPSILON);
+
+        assert_eq!(config.phase(9), EvolutionPhase::Exploration);
+        assert_eq!(config.phase(10), EvolutionPhase::Transition);
+        assert_eq!(config.phase(80), EvolutionPhase::Convergence);
+    }
+
+    fn validate_with(modify: impl FnOnce(&mut TrainingConfig)) -> anyhow::Result<()> {
+        let mut config = TrainingConfig::default();
+        modify(&mut config);
+        config.validate()
+    }
+
+    #[test]
+    fn test_invalid_values_are_rejected() {
+        let errors = [
+            validate_with(|c| c.mutation_rate = 1.5),
+            validate_with(|c| c.mutation_rate = f32::NAN),
+            validate_with(|c| c.blx_alpha = -0.1),
+            validate_with(|c| c.cma_es_initial_sigma = 0.0),
+            validate_with(|c| c.cma_es_initial_sigma = f32::INFINITY),
+            validate_with(|c| c.phases.exploration.max_weight = 0.0),
+            validate_with(|c| c.phases.transition.mutation_sigma = -0.01),
+            validate_with(|c| c.phases.convergence.mutation_sigma = f32::NAN),
+        ]
+        .map(|result| result.unwrap_err().to_string());
+        assert_eq!(
+            errors.map(|e| e.split_whitespace().next().unwrap().to_owned()),
+            [
+                "mutation_rate",
+                "mutation_rate",
+                "blx_alpha",
+                "cma_es_initial_sigma",
+                "cma_es_initial_sigma",
+                "phases.exploration.max_weight",
+                "phases.transition.mutation_sigma",
+                "phases.convergence.mutation_sigma",
+            ]
+        );
+
+        // Boundary values are accepted
+        validate_with(|c| {
+            c.mutation_rate = 1.0;
+            c.blx_alpha = 0.0;
+            c.phases.exploration.mutation_sigma = 0.0;
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn test_unknown_field_is_rejected() {
+        assert!(toml::from_str::<TrainingConfig>("population = 50").is_err());
+    }
+}
@@ -0,0 +1,144 @@
+use std::{collections::BTreeMap, iter, time::Duration};
+
+use oxidris_stats::descriptive::DescriptiveStats;
+use oxidris_training::{genetic::Population, nsga2::ParetoPopulation};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::training_config::EvolutionPhase;
+
+/// Record of one evaluated generation, written as one line of the training log
+/// (`generations.jsonl` in the run directory).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerationLog {
+    /// Index of the generation
+    pub generation: usize,
+    /// Evolution phase (GA and NSGA-II)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phase: Option<EvolutionPhase>,
+    /// Wall-clock time spent evaluating the generation, in seconds
+    pub evaluation_secs: f64,
+    /// Wall-clock time since the start of training (or of resuming), in seconds
+    pub elapsed_secs: f64,
+    /// Fitness statistics (single-objective training)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fitness_stats: Option<StatsLog>,
+    /// Statistics of each weight (of each phase) across all individuals
+    pub weight_stats: Vec<StatsLog>,
+    /// Evaluated individuals, best first
+    pub individuals: Vec<IndividualLog>,
+}
+
+/// Summary of [`DescriptiveStats`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsLog {
+    pub min: f32,
+    pub max: f32,
+    pub mean: f32,
+    pub median: f32,
+    pub std_dev: f32,
+    pub normalized_std_dev: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndividualLog {
+    /// Feature weights (weight sets of all phases concatenated)
+    pub weights: Vec<f32>,
+    /// Fitness (single-objective training)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fitness: Option<f32>,
+    /// Objective values (multi-objective training)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub objectives: BTreeMap<String, f32>,
+    /// Non-domination rank (multi-objective training; 0 is the Pareto front)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rank: Option<usize>,
+}
+
+/// Timings of a generation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GenerationTimings {
+    pub(crate) evaluation: Duration,
+    pub(crate) elapsed: Duration,
+}
+
+impl GenerationLog {
+    /// Builds the record of an evaluated single-objective population.
+    pub(crate) fn from_population(
+        generation: usize,
+        phase: Option<EvolutionPhase>,
+        population: &Population,
+        timings: GenerationTimings,
+    ) -> Self {
+        Self {
+            generation,
+            phase,
+            evaluation_secs: timings.evaluation.as_secs_f64(),
+            elapsed_secs: timings.elapsed.as_secs_f64(),
+            fitness_stats: Some(StatsLog::from(&population.compute_fitness_stats())),
+            weight_stats: population
+                .compute_weight_stats()
+                .iter()
+                .map(StatsLog::from)
+                .collect(),
+            individuals: population
+                .individuals()
+                .iter()
+                .map(|ind| IndividualLog {
+                    weights: ind.weights().to_vec(),
+                    fitness: Some(ind.fitness()),
+                    objectives: BTreeMap::new(),
+                    rank: None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Builds the record of an evaluated multi-objective population.
+    ///
+    /// `names` are the names of the objectives, in objective order.
+    pub(crate) fn from_pareto_population(
+        generation: usize,
+        phase: EvolutionPhase,
+        names: &[&str],
+        population: &ParetoPopulation,
+        timings: GenerationTimings,
+    ) -> Self {
+        Self {
+            generation,
+            phase: Some(phase),
+            evaluation_secs: timings.evaluation.as_secs_f64(),
+            elapsed_secs: timings.elapsed.as_secs_f64(),
+            fitness_stats: None,
+            weight_stats: population
+                .compute_weight_stats()
+                .iter()
+                .map(StatsLog::from)
+                .collect(),
+            individuals: population
+                .individuals()
+                .iter()
+                .map(|ind| IndividualLog {
+                    weights: ind.weights().to_vec(),
+                    fitness: None,
+                    objectives: iter::zip(names, ind.objectives())
+                        .map(|(name, value)| ((*name).to_owned(), *value))
+                        .collect(),
+                    rank: Some(ind.rank()),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&DescriptiveStats> for StatsLog {
+    fn from(stats: &DescriptiveStats) -> Self {
+        Self {
+            min: stats.min,
+            max: stats.max,
+            mean: stats.mean,
+            median: stats.median,
+            std_dev: stats.std_dev,
+            normalized_std_dev: stats.normalized_std_dev,
+        }
+    }
+}
@@ -1,12 +1,12 @@
 use std::{
-    fs::File,
+    fs::{self, File},
     io::{self, BufWriter, StdoutLock, Write as _},
     path::{Path, PathBuf},
 };
 
 use anyhow::Context;
 use oxidris_analysis::{
-    feature_builder::FeatureBuilder,
+    feature_builder::{FeatureBuilder, KmTransform},
     normalization::BoardFeatureNormalizationParamCollection,
     sample::RawBoardSample,
     session::{SessionCollection, SessionData},
@@ -121,6 +121,32 @@ where
     Ok(value)
 }
 
+/// Read a config file in TOML (`.toml` extension) or JSON format
+///
+/// # Errors
+///
+/// Returns error if file cannot be read or parsed
+pub fn read_config_file<T, P>(file_kind: &str, path: P) -> anyhow::Result<T>
+where
+    T: serde::de::DeserializeOwned,
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    if path.extension().is_none_or(|ext| ext != "toml") {
+        return read_json_file(file_kind, path);
+    }
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("Failed to open {} file: {}", file_kind, path.display()))?;
+    let value = toml::from_str(&text).with_context(|| {
+        format!(
+            "Failed to parse {} TOML file: {}",
+            file_kind,
+            path.display()
+        )
+    })?;
+    Ok(value)
+}
+
 /// Read board session data from a JSON file
 ///
 /// # Arguments
@@ -149,7 +175,8 @@ where
 ///
 /// # Returns
 ///
-/// Deserialized AI model configuration
+/// Deserialized AI model configuration, with paths in the model (e.g., ensemble members)
+/// resolved against the directory of the model file
 ///
 /// # Errors
 ///
@@ -158,7 +185,36 @@ pub fn read_ai_model_file<P>(path: P) -> anyhow::Result<AiModel>
 where
     P: AsRef<Path>,
 {
-    read_json_file("AI model", path)
+    let path = path.as_ref();
+    let mut model: AiModel = read_json_file("AI model", path)?;
+    model.resolve_paths(path.parent().unwrap_or(Path::new("")));
+    Ok(model)
+}
+
+/// Save an AI model to a JSON file (or stdout) and print a summary
+///
+/// # Arguments
+///
+/// * `model` - Trained AI model
+/// * `output_path` - Output file path, or `None` for stdout
+///
+/// # Errors
+///
+/// Returns error if the output cannot be created or written
+pub fn save_ai_model(model: &AiModel, output_path: Option<&PathBuf>) -> anyhow::Result<()> {
+    Output::save_json(model, output_path.cloned())?;
+
+    eprintln!();
+    eprintln!("Model saved successfully");
+    if let Some(path) = output_path {
+        eprintln!("  Path: {}", path.display());
+    }
+    eprintln!("  Name: {}", model.name);
+    eprintln!("  Trained at: {}", model.trained_at);
+    eprintln!("  Final fitness: {:.3}", model.final_fitness);
+    eprintln!("  Weights: {} features", model.board_features.len());
+
+    Ok(())
 }
 
 /// Feature set selection for feature building
@@ -168,8 +224,8 @@ where
 pub enum FeatureSet {
     /// All features including raw and table transforms
     All,
-    /// Only features with Kaplan-Meier normalization
-    Km,
+    /// Only features with Kaplan-Meier normalization, using the given KM transform
+    Km(KmTransform),
     /// Only raw-transform features (penalty/risk)
     Raw,
 }
@@ -184,10 +240,16 @@ pub enum FeatureSet {
 /// 4. Generate normalization parameters from statistics
 /// 5. Build feature set (raw only or all including table transforms)
 ///
+/// Queue-aware features are built only if some session records piece queues.
+///
 /// # Arguments
 ///
 /// * `feature_set` - Which features to build (All or Raw only)
 /// * `sessions` - Session data containing board states and placements
+/// * `max_turns` - Turn limit of the sessions
+///
+/// Survival probability and restricted mean KM features use `max_turns / 2` as the
+/// horizon, so that boards from the first half of censored sessions are observed up to it.
 ///
 /// # Returns
 ///
@@ -208,6 +270,7 @@ pub enum FeatureSet {
 pub fn build_feature_from_session(
     feature_set: FeatureSet,
     sessions: &[SessionData],
+    max_turns: usize,
 ) -> anyhow::Result<Vec<BoxedBoardFeature>> {
     let sources = board_feature::source::all_board_feature_sources();
 
@@ -224,15 +287,20 @@ pub fn build_feature_from_session(
     eprintln!("Feature survival statistics computed");
 
     eprintln!("Computing feature normalization parameters...");
-    let norm_params =
-        BoardFeatureNormalizationParamCollection::from_stats(&sources, &raw_stats, &survival_stats);
+    let norm_params = BoardFeatureNormalizationParamCollection::from_stats(
+        &sources,
+        &raw_stats,
+        &survival_stats,
+        max_turns / 2,
+    );
     eprintln!("Normalization parameters computed");
 
     eprintln!("Building feature builder...");
-    let feature_builder = FeatureBuilder::new(norm_params);
+    let has_queue = sessions.iter().any(SessionData::has_queue);
+    let feature_builder = FeatureBuilder::new(norm_params).with_queue_features(has_queue);
     let features = match feature_set {
         FeatureSet::All => feature_builder.build_all_features()?,
-        FeatureSet::Km => feature_builder.build_km_features()?,
+        FeatureSet::Km(km) => feature_builder.build_km_features_with(km)?,
         FeatureSet::Raw => feature_builder.build_raw_features()?,
     };
     eprintln!("Built {} features", features.len());
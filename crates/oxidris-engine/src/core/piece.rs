@@ -447,6 +447,17 @@ impl PieceKind {
     /// Number of piece types (7).
     pub const LEN: usize = 7;
 
+    /// All piece types, in discriminant order.
+    pub const ALL: [Self; Self::LEN] = [
+        PieceKind::I,
+        PieceKind::O,
+        PieceKind::S,
+        PieceKind::Z,
+        PieceKind::J,
+        PieceKind::L,
+        PieceKind::T,
+    ];
+
     pub(crate) fn mask(self, rotation: PieceRotation) -> PieceMask {
         PIECE_MASKS[self as usize][rotation.as_usize()]
     }
@@ -0,0 +1,116 @@
+//! Compares full and incremental placement analysis over all candidates of a turn.
+//!
+//! Each iteration analyzes every placement of every piece kind on a fixed board and
+//! extracts all board feature sources, like a turn evaluation with a feature-based model.
+
+use std::{hint::black_box, iter};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use oxidris_engine::{BitBoard, Piece, PieceKind};
+use oxidris_evaluator::{
+    board_analysis::BoardAnalysis,
+    board_feature::source::{BoxedBoardFeatureSource, all_board_feature_sources},
+    placement_analysis::PlacementAnalysis,
+};
+
+const MIDGAME_BOARD: &str = "
+    ..........
+    ..........
+    ..........
+    ..........
+    ..........
+    ..........
+    ..........
+    ..........
+    ..........
+    ..........
+    ..#.......
+    .###....#.
+    ####..###.
+    #####.###.
+    ##.######.
+    ###.#####.
+    #########.
+    ####.####.
+    #########.
+    ##.######.
+";
+
+fn placements(board: &BitBoard) -> Vec<Piece> {
+    let shift = |piece: Piece, step: fn(&Piece) -> Option<Piece>| {
+        iter::successors(Some(piece), move |p| {
+            step(p).filter(|moved| !board.is_colliding(*moved))
+        })
+    };
+    PieceKind::ALL
+        .into_iter()
+        .flat_map(|kind| Piece::new(kind).super_rotations(board))
+        .flat_map(|piece| shift(piece, Piece::left).chain(shift(piece, Piece::right).skip(1)))
+        .map(|piece| piece.simulate_drop_position(board))
+        .collect()
+}
+
+fn extract_all(sources: &[BoxedBoardFeatureSource], analysis: &PlacementAnalysis) -> u32 {
+    sources.iter().map(|s| s.extract_raw(analysis)).sum()
+}
+
+/// Metrics maintained incrementally (and the ones derived from them).
+fn base_metrics(analysis: &PlacementAnalysis) -> u32 {
+    let board = analysis.board_analysis();
+    u32::from(board.num_holes())
+        + u32::from(board.max_height())
+        + board.surface_bumpiness()
+        + board.row_transitions()
+        + board.column_transitions()
+}
+
+fn bench_placement_analysis(c: &mut Criterion) {
+    let sources = all_board_feature_sources();
+    for (name, board) in [
+        ("empty", BitBoard::INITIAL),
+        ("midgame", BitBoard::from_ascii(MIDGAME_BOARD)),
+    ] {
+        let placements = placements(&board);
+        let mut group = c.benchmark_group(format!("placement_analysis/{name}"));
+        group.bench_function("full", |b| {
+            b.iter(|| {
+                let board = black_box(&board);
+                placements
+                    .iter()
+                    .map(|p| extract_all(&sources, &PlacementAnalysis::from_board(board, *p)))
+                    .sum::<u32>()
+            });
+        });
+        group.bench_function("incremental", |b| {
+            b.iter(|| {
+                let parent = BoardAnalysis::from_board(black_box(&board));
+                placements
+                    .iter()
+                    .map(|p| extract_all(&sources, &PlacementAnalysis::from_parent(&parent, *p)))
+                    .sum::<u32>()
+            });
+        });
+        group.bench_function("full_base_metrics", |b| {
+            b.iter(|| {
+                let board = black_box(&board);
+                placements
+                    .iter()
+                    .map(|p| base_metrics(&PlacementAnalysis::from_board(board, *p)))
+                    .sum::<u32>()
+            });
+        });
+        group.bench_function("incremental_base_metrics", |b| {
+            b.iter(|| {
+                let parent = BoardAnalysis::from_board(black_box(&board));
+                placements
+                    .iter()
+                    .map(|p| base_metrics(&PlacementAnalysis::from_parent(&parent, *p)))
+                    .sum::<u32>()
+            });
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_placement_analysis);
+criterion_main!(benches);
@@ -8,6 +8,9 @@
 //! - **Lazy evaluation**: Metrics are computed only when accessed, using `OnceCell` for caching
 //! - **Efficient**: Each metric is calculated once and reused by multiple features
 //! - **Complete**: Provides all raw metrics needed by board features
+//! - **Incremental**: Per-column and per-row base metrics of a board after a placement can be
+//!   derived from the analysis of the board before it (see
+//!   [`PlacementAnalysis::from_parent`](crate::placement_analysis::PlacementAnalysis::from_parent))
 //!
 //! # Usage
 //!
@@ -23,15 +26,22 @@
 //! # Available Metrics
 //!
 //! - **Heights**: `column_heights`, `max_height`, `center_column_max_height`, `total_height`
-//! - **Holes**: `num_holes`, `sum_of_hole_depth`
+//! - **Holes**: `num_holes`, `sum_of_hole_depth`, `rows_with_holes`, `cells_above_holes`,
+//!   `reachable_overhangs`
 //! - **Transitions**: `row_transitions`, `column_transitions`
 //! - **Surface**: `surface_bumpiness`, `surface_roughness`
-//! - **Wells**: `column_well_depths`, `sum_of_deep_well_depth`, `edge_i_well_depth`
+//! - **Wells**: `column_well_depths`, `sum_of_deep_well_depth`, `edge_i_well_depth`,
+//!   `open_well_column`, `open_well_depth`, `open_well_clean_rows`, `i_dependencies`,
+//!   `is_tetris_ready`
+//! - **Parity**: `checkerboard_parity_imbalance`, `column_parity_imbalance`
+//! - **T-slots**: `t_slot_count`, `t_slot_depth`
+//! - **Piece fits**: `has_clean_fit`, `num_unfittable_piece_kinds`
 //! - **Other**: `column_occupied_cells`
 
-use std::{cell::OnceCell, iter};
+use std::{array, cell::OnceCell, iter};
 
-use oxidris_engine::BitBoard;
+use arrayvec::ArrayVec;
+use oxidris_engine::{BitBoard, Piece, PieceKind, PieceRotation};
 
 #[derive(Debug)]
 pub struct BoardAnalysis {
@@ -39,39 +49,236 @@ pub struct BoardAnalysis {
     column_heights: OnceCell<[u8; BitBoard::PLAYABLE_WIDTH]>,
     column_occupied_cells: OnceCell<[u8; BitBoard::PLAYABLE_WIDTH]>,
     column_well_depths: OnceCell<[u8; BitBoard::PLAYABLE_WIDTH]>,
+    row_transition_counts: OnceCell<[u8; BitBoard::PLAYABLE_HEIGHT]>,
+    column_transition_counts: OnceCell<[u8; BitBoard::PLAYABLE_WIDTH]>,
     max_height: OnceCell<u8>,
     center_column_max_height: OnceCell<u8>,
     total_height: OnceCell<u8>,
     num_holes: OnceCell<u8>,
     sum_of_hole_depth: OnceCell<u32>,
+    rows_with_holes: OnceCell<u8>,
+    cells_above_holes: OnceCell<u32>,
+    reachable_overhangs: OnceCell<u8>,
     row_transitions: OnceCell<u32>,
     column_transitions: OnceCell<u32>,
     surface_bumpiness: OnceCell<u32>,
     surface_roughness: OnceCell<u32>,
     sum_of_deep_well_depth: OnceCell<u32>,
     edge_i_well_depth: OnceCell<u8>,
+    open_well: OnceCell<OpenWell>,
+    i_dependencies: OnceCell<u8>,
+    checkerboard_parity_imbalance: OnceCell<u8>,
+    column_parity_imbalance: OnceCell<u8>,
+    t_slots: OnceCell<TSlots>,
+    clean_fits: OnceCell<[bool; PieceKind::LEN]>,
 }
 
+/// The deepest well on a board and the rows beside it.
+#[derive(Debug, Clone, Copy)]
+struct OpenWell {
+    column: Option<u8>,
+    depth: u8,
+    clean_rows: u8,
+}
+
+/// Minimum well depth that can only be filled efficiently by an I piece.
+const I_DEPENDENCY_DEPTH: u8 = 3;
+
+/// Number of clean rows beside a well required for a tetris (4-line clear).
+const TETRIS_LINES: u8 = 4;
+
+/// Summary of the T-slots detected on a board.
+#[derive(Debug, Clone, Copy)]
+struct TSlots {
+    count: u8,
+    max_line_clears: u8,
+}
+
+/// T piece shapes that can be spun into a slot, as `(dx, dy)` offsets from the T center.
+///
+/// `y` grows downward. The flat orientation with the stem up is omitted, because it is
+/// simply dropped onto the surface rather than spun into a slot.
+const T_SLOT_SHAPES: [[(isize, isize); 4]; 3] = [
+    // Stem down (T-spin double shape)
+    [(0, 0), (-1, 0), (1, 0), (0, 1)],
+    // Stem left (T-spin triple shape)
+    [(0, 0), (0, -1), (0, 1), (-1, 0)],
+    // Stem right (T-spin triple shape)
+    [(0, 0), (0, -1), (0, 1), (1, 0)],
+];
+
+/// Diagonal corners around the T center used by the 3-corner T-spin rule.
+const T_SLOT_CORNERS: [(isize, isize); 4] = [(-1, -1), (1, -1), (-1, 1), (1, 1)];
+
 impl BoardAnalysis {
     #[must_use]
     pub fn from_board(board: &BitBoard) -> Self {
-        let board = board.clone();
+        Self::from_owned_board(board.clone())
+    }
+
+    fn from_owned_board(board: BitBoard) -> Self {
         Self {
             board,
             column_heights: OnceCell::new(),
             column_occupied_cells: OnceCell::new(),
             column_well_depths: OnceCell::new(),
+            row_transition_counts: OnceCell::new(),
+            column_transition_counts: OnceCell::new(),
             max_height: OnceCell::new(),
             center_column_max_height: OnceCell::new(),
             total_height: OnceCell::new(),
             num_holes: OnceCell::new(),
             sum_of_hole_depth: OnceCell::new(),
+            rows_with_holes: OnceCell::new(),
+            cells_above_holes: OnceCell::new(),
+            reachable_overhangs: OnceCell::new(),
             row_transitions: OnceCell::new(),
             column_transitions: OnceCell::new(),
             surface_bumpiness: OnceCell::new(),
             surface_roughness: OnceCell::new(),
             sum_of_deep_well_depth: OnceCell::new(),
             edge_i_well_depth: OnceCell::new(),
+            open_well: OnceCell::new(),
+            i_dependencies: OnceCell::new(),
+            checkerboard_parity_imbalance: OnceCell::new(),
+            column_parity_imbalance: OnceCell::new(),
+            t_slots: OnceCell::new(),
+            clean_fits: OnceCell::new(),
+        }
+    }
+
+    /// Creates an analysis of `board`, the result of placing `placement` on the board of
+    /// `parent` and clearing `cleared_lines` lines.
+    ///
+    /// Instead of scanning the whole board, the base metrics (column heights, occupied cells
+    /// and transitions) are updated from `parent` for the columns and rows touched by the
+    /// piece, then shifted by the cleared rows. All metrics are the same as with
+    /// [`from_board`](Self::from_board).
+    pub(crate) fn from_parent(
+        parent: &Self,
+        board: BitBoard,
+        placement: Piece,
+        cleared_lines: usize,
+    ) -> Self {
+        let analysis = Self::from_owned_board(board);
+        let cells = placement
+            .occupied_positions()
+            .map(|(x, y)| (x - BitBoard::PLAYABLE_X_RANGE.start, y))
+            .collect::<ArrayVec<_, 4>>();
+        if cells
+            .iter()
+            .any(|(_col, y)| !BitBoard::PLAYABLE_Y_RANGE.contains(y))
+        {
+            // Rows above the playable area may shift into it on line clears
+            return analysis;
+        }
+
+        let mut occupied = *parent.column_occupied_cells();
+        for (col, _y) in &cells {
+            occupied[*col] += 1;
+        }
+        let cleared = u8::try_from(cleared_lines).unwrap();
+        for o in &mut occupied {
+            *o -= cleared;
+        }
+        analysis.column_occupied_cells.set(occupied).unwrap();
+
+        // Update the metrics of the board before the line clear, which differs from `parent`
+        // only in the rows and columns of the piece
+        let filled = (cleared_lines > 0).then(|| {
+            let mut board = parent.board.clone();
+            board.fill_piece(placement);
+            Self::from_owned_board(board)
+        });
+        let filled_analysis = filled.as_ref().unwrap_or(&analysis);
+        let mut heights = *parent.column_heights();
+        let mut row_transitions = *parent.row_transition_counts();
+        let mut column_transitions = *parent.column_transition_counts();
+        let mut cleared_rows = ArrayVec::<usize, 4>::new();
+        for (col, y) in cells {
+            let y = y - BitBoard::PLAYABLE_Y_RANGE.start;
+            let height = u8::try_from(BitBoard::PLAYABLE_HEIGHT - y).unwrap();
+            heights[col] = u8::max(heights[col], height);
+            row_transitions[y] = filled_analysis.row_transitions_at(y);
+            column_transitions[col] = filled_analysis.column_transitions_at(col);
+            if cleared_lines > 0
+                && !cleared_rows.contains(&y)
+                && filled_analysis.board.playable_row(y).is_playable_filled()
+            {
+                cleared_rows.push(y);
+            }
+        }
+        if let Some(filled) = &filled {
+            cleared_rows.sort_unstable();
+            filled.shift_cleared_rows(
+                &cleared_rows,
+                &mut heights,
+                &mut row_transitions,
+                &mut column_transitions,
+            );
+        }
+        analysis.column_heights.set(heights).unwrap();
+        analysis.row_transition_counts.set(row_transitions).unwrap();
+        analysis
+            .column_transition_counts
+            .set(column_transitions)
+            .unwrap();
+        analysis
+    }
+
+    /// Updates the base metrics of this board (before clearing lines) to the board after
+    /// clearing `cleared_rows` (sorted ascending).
+    ///
+    /// Cleared rows are filled, so every column loses one cell per cleared row, the rows
+    /// above move down, and empty rows enter at the top.
+    fn shift_cleared_rows(
+        &self,
+        cleared_rows: &[usize],
+        heights: &mut [u8; BitBoard::PLAYABLE_WIDTH],
+        row_transitions: &mut [u8; BitBoard::PLAYABLE_HEIGHT],
+        column_transitions: &mut [u8; BitBoard::PLAYABLE_WIDTH],
+    ) {
+        for (x, h) in iter::zip(BitBoard::PLAYABLE_X_RANGE, heights) {
+            // The top cell may be cleared, exposing the empty cells below it
+            let top = BitBoard::PLAYABLE_HEIGHT - usize::from(*h);
+            let new_top = (top..BitBoard::PLAYABLE_HEIGHT).find(|y| {
+                !cleared_rows.contains(y) && self.board.playable_row(*y).is_cell_occupied(x)
+            });
+            *h = new_top.map_or(0, |y| {
+                let cleared_below = cleared_rows.iter().filter(|c| **c > y).count();
+                u8::try_from(BitBoard::PLAYABLE_HEIGHT - y - cleared_below).unwrap()
+            });
+        }
+
+        let mut shifted = [0; BitBoard::PLAYABLE_HEIGHT];
+        let kept_rows = (0..BitBoard::PLAYABLE_HEIGHT).filter(|y| !cleared_rows.contains(y));
+        for (dst, y) in iter::zip((0..BitBoard::PLAYABLE_HEIGHT).rev(), kept_rows.rev()) {
+            shifted[dst] = row_transitions[y];
+        }
+        *row_transitions = shifted;
+
+        let top_kept_row = (0..BitBoard::PLAYABLE_HEIGHT)
+            .find(|y| !cleared_rows.contains(y))
+            .unwrap();
+        for (col, transitions) in column_transitions.iter_mut().enumerate() {
+            let x = BitBoard::PLAYABLE_X_RANGE.start + col;
+            let occupied_at = |y: usize| self.board.playable_row(y).is_cell_occupied(x);
+            // Each run of cleared (filled) rows is replaced by a direct edge between the
+            // cells above and below it
+            for run in cleared_rows.chunk_by(|a, b| a + 1 == *b) {
+                let above = run[0].checked_sub(1).map(occupied_at);
+                let below = Some(run[run.len() - 1] + 1)
+                    .filter(|y| *y < BitBoard::PLAYABLE_HEIGHT)
+                    .map(occupied_at);
+                let removed = [above, below].into_iter().flatten().filter(|o| !o).count();
+                let added = match (above, below) {
+                    (Some(above), Some(below)) => usize::from(above != below),
+                    _ => 0,
+                };
+                *transitions = u8::try_from(usize::from(*transitions) + added - removed).unwrap();
+            }
+            // The empty rows entering at the top border the old top row
+            *transitions += u8::from(occupied_at(top_kept_row));
         }
     }
 
@@ -191,42 +398,137 @@ impl BoardAnalysis {
         })
     }
 
+    /// Number of rows containing at least one hole.
     #[must_use]
-    pub fn row_transitions(&self) -> u32 {
-        *self.row_transitions.get_or_init(|| {
-            let mut transitions = 0;
-            for row in self.board.playable_rows() {
-                let mut cells = row.playable_cells();
-                let mut prev_occupied = cells.next().unwrap();
-                for occupied in cells {
-                    if occupied != prev_occupied {
-                        transitions += 1;
+    pub fn rows_with_holes(&self) -> u8 {
+        *self.rows_with_holes.get_or_init(|| {
+            let rows = (0..BitBoard::PLAYABLE_HEIGHT)
+                .filter(|y| (0..BitBoard::PLAYABLE_WIDTH).any(|col| self.is_hole(col, *y)))
+                .count();
+            u8::try_from(rows).unwrap()
+        })
+    }
+
+    /// Number of occupied cells stacked above the lowest hole of each column.
+    ///
+    /// These are the cells that must be cleared before the hole can be filled.
+    #[must_use]
+    pub fn cells_above_holes(&self) -> u32 {
+        *self.cells_above_holes.get_or_init(|| {
+            let mut cells = 0;
+            for (col, x) in BitBoard::PLAYABLE_X_RANGE.enumerate() {
+                let Some(lowest_hole_y) = (0..BitBoard::PLAYABLE_HEIGHT)
+                    .rev()
+                    .find(|y| self.is_hole(col, *y))
+                else {
+                    continue;
+                };
+                cells += (0..lowest_hole_y)
+                    .filter(|y| self.board.playable_row(*y).is_cell_occupied(x))
+                    .count();
+            }
+            u32::try_from(cells).unwrap()
+        })
+    }
+
+    /// Number of holes that are reachable by sliding a piece horizontally.
+    ///
+    /// A hole is reachable when it is connected, through empty cells in the same row,
+    /// to a cell that is open to the sky. Such holes are overhangs that can still be
+    /// filled by a tuck, unlike fully enclosed holes.
+    #[must_use]
+    pub fn reachable_overhangs(&self) -> u8 {
+        *self.reachable_overhangs.get_or_init(|| {
+            let mut count = 0;
+            for y in 0..BitBoard::PLAYABLE_HEIGHT {
+                let row = self.board.playable_row(y);
+                let is_empty =
+                    |col: usize| !row.is_cell_occupied(BitBoard::PLAYABLE_X_RANGE.start + col);
+                for col in (0..BitBoard::PLAYABLE_WIDTH).filter(|col| self.is_hole(*col, y)) {
+                    let left = (0..col).rev().take_while(|c| is_empty(*c));
+                    let mut right =
+                        (col + 1..BitBoard::PLAYABLE_WIDTH).take_while(|c| is_empty(*c));
+                    let is_open = |c: usize| !self.is_covered(c, y);
+                    if left.clone().any(is_open) || right.any(is_open) {
+                        count += 1;
                     }
-                    prev_occupied = occupied;
                 }
             }
-            transitions
+            count
+        })
+    }
+
+    /// Returns whether the cell at playable column `col` and row `y` lies below the column surface.
+    fn is_covered(&self, col: usize, y: usize) -> bool {
+        let row_height = BitBoard::PLAYABLE_HEIGHT - y;
+        row_height < usize::from(self.column_heights()[col])
+    }
+
+    /// Returns whether the cell at playable column `col` and row `y` is a hole.
+    fn is_hole(&self, col: usize, y: usize) -> bool {
+        let x = BitBoard::PLAYABLE_X_RANGE.start + col;
+        self.is_covered(col, y) && !self.board.playable_row(y).is_cell_occupied(x)
+    }
+
+    #[must_use]
+    pub fn row_transitions(&self) -> u32 {
+        *self.row_transitions.get_or_init(|| {
+            self.row_transition_counts()
+                .iter()
+                .map(|t| u32::from(*t))
+                .sum()
         })
     }
 
     #[must_use]
     pub fn column_transitions(&self) -> u32 {
         *self.column_transitions.get_or_init(|| {
-            let mut transitions = 0;
-            for x in BitBoard::PLAYABLE_X_RANGE {
-                let mut prev_occupied = self.board.playable_row(0).is_cell_occupied(x);
-                for y in 1..BitBoard::PLAYABLE_HEIGHT {
-                    let occupied = self.board.playable_row(y).is_cell_occupied(x);
-                    if occupied != prev_occupied {
-                        transitions += 1;
-                    }
-                    prev_occupied = occupied;
-                }
-            }
-            transitions
+            self.column_transition_counts()
+                .iter()
+                .map(|t| u32::from(*t))
+                .sum()
         })
     }
 
+    fn row_transition_counts(&self) -> &[u8; BitBoard::PLAYABLE_HEIGHT] {
+        self.row_transition_counts
+            .get_or_init(|| array::from_fn(|y| self.row_transitions_at(y)))
+    }
+
+    fn column_transition_counts(&self) -> &[u8; BitBoard::PLAYABLE_WIDTH] {
+        self.column_transition_counts
+            .get_or_init(|| array::from_fn(|col| self.column_transitions_at(col)))
+    }
+
+    /// Number of occupied/empty changes between horizontally adjacent cells of playable row `y`.
+    fn row_transitions_at(&self, y: usize) -> u8 {
+        let mut cells = self.board.playable_row(y).playable_cells();
+        let mut prev_occupied = cells.next().unwrap();
+        let mut transitions = 0;
+        for occupied in cells {
+            if occupied != prev_occupied {
+                transitions += 1;
+            }
+            prev_occupied = occupied;
+        }
+        transitions
+    }
+
+    /// Number of occupied/empty changes between vertically adjacent cells of playable column `col`.
+    fn column_transitions_at(&self, col: usize) -> u8 {
+        let x = BitBoard::PLAYABLE_X_RANGE.start + col;
+        let mut prev_occupied = self.board.playable_row(0).is_cell_occupied(x);
+        let mut transitions = 0;
+        for y in 1..BitBoard::PLAYABLE_HEIGHT {
+            let occupied = self.board.playable_row(y).is_cell_occupied(x);
+            if occupied != prev_occupied {
+                transitions += 1;
+            }
+            prev_occupied = occupied;
+        }
+        transitions
+    }
+
     #[must_use]
     pub fn surface_bumpiness(&self) -> u32 {
         *self.surface_bumpiness.get_or_init(|| {
@@ -277,6 +579,309 @@ impl BoardAnalysis {
             u8::max(left_well_depth, right_well_depth)
         })
     }
+
+    /// Column (0-9) of the deepest well on the board, or `None` if there is no well.
+    ///
+    /// Unlike [`edge_i_well_depth`](Self::edge_i_well_depth), the well may be in any
+    /// column. Ties are resolved toward the leftmost column.
+    #[must_use]
+    pub fn open_well_column(&self) -> Option<u8> {
+        self.open_well().column
+    }
+
+    /// Depth of the deepest well on the board (0 if there is no well).
+    #[must_use]
+    pub fn open_well_depth(&self) -> u8 {
+        self.open_well().depth
+    }
+
+    /// Number of rows beside the deepest well that are filled in every other column.
+    ///
+    /// Counted upward from the bottom of the well and stopping at the first row with
+    /// a gap. These are the rows a vertical I piece dropped into the well would clear.
+    #[must_use]
+    pub fn open_well_clean_rows(&self) -> u8 {
+        self.open_well().clean_rows
+    }
+
+    /// Returns whether dropping an I piece into the deepest well clears 4 lines.
+    #[must_use]
+    pub fn is_tetris_ready(&self) -> bool {
+        self.open_well_clean_rows() >= TETRIS_LINES
+    }
+
+    /// Number of columns that need an I piece to fill (wells at least 3 deep).
+    #[must_use]
+    pub fn i_dependencies(&self) -> u8 {
+        *self.i_dependencies.get_or_init(|| {
+            let count = self
+                .column_well_depths()
+                .iter()
+                .filter(|depth| **depth >= I_DEPENDENCY_DEPTH)
+                .count();
+            u8::try_from(count).unwrap()
+        })
+    }
+
+    fn open_well(&self) -> OpenWell {
+        *self.open_well.get_or_init(|| {
+            let deepest = self
+                .column_well_depths()
+                .iter()
+                .enumerate()
+                .filter(|(_col, depth)| **depth > 0)
+                .rev()
+                .max_by_key(|(_col, depth)| **depth);
+            let Some((col, &depth)) = deepest else {
+                return OpenWell {
+                    column: None,
+                    depth: 0,
+                    clean_rows: 0,
+                };
+            };
+
+            let well_x = BitBoard::PLAYABLE_X_RANGE.start + col;
+            let bottom_y = BitBoard::PLAYABLE_HEIGHT - usize::from(self.column_heights()[col]);
+            let clean_rows = (0..bottom_y)
+                .rev()
+                .take(usize::from(depth))
+                .take_while(|y| {
+                    let row = self.board.playable_row(*y);
+                    BitBoard::PLAYABLE_X_RANGE
+                        .filter(|x| *x != well_x)
+                        .all(|x| row.is_cell_occupied(x))
+                })
+                .count();
+            OpenWell {
+                column: Some(u8::try_from(col).unwrap()),
+                depth,
+                clean_rows: u8::try_from(clean_rows).unwrap(),
+            }
+        })
+    }
+
+    /// Difference between occupied cells on the two colors of a checkerboard pattern.
+    ///
+    /// Every tetromino except T covers two cells of each color, so a large imbalance
+    /// can only be repaired with T pieces.
+    #[must_use]
+    pub fn checkerboard_parity_imbalance(&self) -> u8 {
+        *self.checkerboard_parity_imbalance.get_or_init(|| {
+            let mut counts = [0_i32; 2];
+            for y in 0..BitBoard::PLAYABLE_HEIGHT {
+                let row = self.board.playable_row(y);
+                for (col, x) in BitBoard::PLAYABLE_X_RANGE.enumerate() {
+                    if row.is_cell_occupied(x) {
+                        counts[(col + y) % 2] += 1;
+                    }
+                }
+            }
+            u8::try_from((counts[0] - counts[1]).unsigned_abs()).unwrap()
+        })
+    }
+
+    /// Difference between occupied cells in even and odd columns.
+    ///
+    /// Pieces covering 3 cells of one column parity and 1 of the other change this balance by
+    /// 2: vertical I (by 4), L, J and T, and flat L and J. Flat I, flat T, O, S and Z keep it.
+    #[must_use]
+    pub fn column_parity_imbalance(&self) -> u8 {
+        *self.column_parity_imbalance.get_or_init(|| {
+            let mut counts = [0_i32; 2];
+            for (col, occupied) in self.column_occupied_cells().iter().enumerate() {
+                counts[col % 2] += i32::from(*occupied);
+            }
+            u8::try_from((counts[0] - counts[1]).unsigned_abs()).unwrap()
+        })
+    }
+
+    /// Number of T-slots (T-spin double/triple shapes) on the board.
+    ///
+    /// See [`t_slot_depth`](Self::t_slot_depth) for the detection rule. Slot shapes sharing
+    /// a cell belong to the same cavity (only one T can fill it), so they count as one slot.
+    #[must_use]
+    pub fn t_slot_count(&self) -> u8 {
+        self.t_slots().count
+    }
+
+    /// Maximum number of lines cleared by filling any detected T-slot with a T piece.
+    ///
+    /// A T-slot is a position where a T piece (stem down, left or right) fits into empty
+    /// cells such that:
+    ///
+    /// - At least 3 of the 4 diagonal corners around the T center are occupied (walls and
+    ///   floor count as occupied), following the 3-corner T-spin rule
+    /// - At least one of the T cells is covered by the stack, so it cannot be hard-dropped
+    /// - The T rests on an occupied cell or the floor
+    ///
+    /// Reachability of the slot by actual piece movement is not checked.
+    #[must_use]
+    pub fn t_slot_depth(&self) -> u8 {
+        self.t_slots().max_line_clears
+    }
+
+    fn t_slots(&self) -> TSlots {
+        *self.t_slots.get_or_init(|| {
+            let mut max_line_clears = 0;
+            // Cells of each cavity, merged from the overlapping slot shapes
+            let mut cavities: Vec<Vec<(isize, isize)>> = vec![];
+            for y in 0..BitBoard::PLAYABLE_HEIGHT {
+                for col in 0..BitBoard::PLAYABLE_WIDTH {
+                    for shape in &T_SLOT_SHAPES {
+                        let Some(line_clears) = self.t_slot_line_clears(col, y, shape) else {
+                            continue;
+                        };
+                        max_line_clears = u8::max(max_line_clears, line_clears);
+                        let (col, y) = (col.cast_signed(), y.cast_signed());
+                        let mut cavity = shape.map(|(dx, dy)| (col + dx, y + dy)).to_vec();
+                        // A slot can connect several cavities found so far
+                        cavities.retain(|other| {
+                            if other.iter().any(|cell| cavity.contains(cell)) {
+                                cavity.extend_from_slice(other);
+                                false
+                            } else {
+                                true
+                            }
+                        });
+                        cavities.push(cavity);
+                    }
+                }
+            }
+            TSlots {
+                count: u8::try_from(cavities.len()).unwrap(),
+                max_line_clears,
+            }
+        })
+    }
+
+    /// Returns the number of lines cleared if the T-slot centered at (`col`, `y`) is filled,
+    /// or `None` if there is no T-slot of the given shape there.
+    fn t_slot_line_clears(&self, col: usize, y: usize, shape: &[(isize, isize); 4]) -> Option<u8> {
+        let (col, y) = (col.cast_signed(), y.cast_signed());
+        let cells = shape.map(|(dx, dy)| (col + dx, y + dy));
+        // Slots reaching above the playable area cannot be covered by the stack
+        if cells
+            .iter()
+            .any(|&(c, y)| y < 0 || self.is_occupied_or_wall(c, y))
+        {
+            return None;
+        }
+        let occupied_corners = T_SLOT_CORNERS
+            .iter()
+            .filter(|(dx, dy)| self.is_occupied_or_wall(col + dx, y + dy))
+            .count();
+        if occupied_corners < 3 {
+            return None;
+        }
+        // All T cells are inside the playable area here, since walls and floor count as occupied.
+        let is_covered = cells
+            .iter()
+            .any(|&(c, y)| self.is_covered(c.cast_unsigned(), y.cast_unsigned()));
+        let is_resting = cells
+            .iter()
+            .any(|&(c, y)| !cells.contains(&(c, y + 1)) && self.is_occupied_or_wall(c, y + 1));
+        if !is_covered || !is_resting {
+            return None;
+        }
+
+        let mut rows = cells.map(|(_c, y)| y);
+        rows.sort_unstable();
+        let mut line_clears = 0;
+        for (i, row_y) in rows.iter().enumerate() {
+            if rows[..i].contains(row_y) {
+                continue;
+            }
+            let is_full = (0..BitBoard::PLAYABLE_WIDTH.cast_signed())
+                .all(|c| cells.contains(&(c, *row_y)) || self.is_occupied_or_wall(c, *row_y));
+            if is_full {
+                line_clears += 1;
+            }
+        }
+        Some(line_clears)
+    }
+
+    /// Whether a piece of the given kind can be dropped somewhere without creating holes.
+    ///
+    /// A placement is clean if every column under the piece is filled exactly up to the
+    /// piece's bottom cell in that column, so no empty cell gets covered. Only straight
+    /// drops onto the surface are considered (no slides or spins under overhangs), and the
+    /// piece must fit below the top of the playable area.
+    #[must_use]
+    pub fn has_clean_fit(&self, kind: PieceKind) -> bool {
+        self.clean_fits()[kind as usize]
+    }
+
+    /// Number of piece kinds that have no clean placement (see [`has_clean_fit`](Self::has_clean_fit)).
+    #[must_use]
+    pub fn num_unfittable_piece_kinds(&self) -> u8 {
+        let count = self.clean_fits().iter().filter(|fits| !**fits).count();
+        u8::try_from(count).unwrap()
+    }
+
+    fn clean_fits(&self) -> &[bool; PieceKind::LEN] {
+        self.clean_fits.get_or_init(|| {
+            let heights = self.column_heights();
+            PieceKind::ALL.map(|kind| {
+                iter::successors(Some(PieceRotation::default()), |r| Some(r.rotated_right()))
+                    .take(4)
+                    .any(|rotation| has_clean_fit_with_rotation(heights, kind, rotation))
+            })
+        })
+    }
+
+    /// Returns whether the cell is occupied, treating walls and floor as occupied
+    /// and cells above the playable area as empty.
+    fn is_occupied_or_wall(&self, col: isize, y: isize) -> bool {
+        let Ok(col) = usize::try_from(col) else {
+            return true;
+        };
+        if col >= BitBoard::PLAYABLE_WIDTH {
+            return true;
+        }
+        let Ok(y) = usize::try_from(y) else {
+            return false;
+        };
+        if y >= BitBoard::PLAYABLE_HEIGHT {
+            return true;
+        }
+        self.board
+            .playable_row(y)
+            .is_cell_occupied(BitBoard::PLAYABLE_X_RANGE.start + col)
+    }
+}
+
+/// Returns whether the piece in the given rotation can be dropped onto the surface
+/// described by `heights` without covering any empty cell.
+fn has_clean_fit_with_rotation(
+    heights: &[u8; BitBoard::PLAYABLE_WIDTH],
+    kind: PieceKind,
+    rotation: PieceRotation,
+) -> bool {
+    // Lowest and highest occupied `dy` of each column of the 4x4 bounding box (y grows downward)
+    let mut bottoms = [None::<usize>; 4];
+    let mut top = usize::MAX;
+    for (dx, dy) in kind.occupied_positions(rotation) {
+        bottoms[dx] = Some(bottoms[dx].map_or(dy, |b| usize::max(b, dy)));
+        top = usize::min(top, dy);
+    }
+    let bottoms = bottoms
+        .into_iter()
+        .flatten()
+        .collect::<ArrayVec<usize, 4>>();
+    let lowest = *bottoms.iter().max().unwrap();
+    let piece_height = lowest - top + 1;
+
+    // The piece rests at `base` (height of its lowest row) if each column under it is filled
+    // exactly up to `base + lift`, where `lift` is the height of the column's bottom cell
+    // above the lowest row of the piece.
+    heights.windows(bottoms.len()).any(|columns| {
+        let mut bases =
+            iter::zip(columns, &bottoms).map(|(&h, &b)| usize::from(h).checked_sub(lowest - b));
+        let Some(Some(base)) = bases.next() else {
+            return false;
+        };
+        bases.all(|b| b == Some(base)) && base + piece_height <= BitBoard::PLAYABLE_HEIGHT
+    })
 }
 
 #[cfg(test)]
@@ -532,6 +1137,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_from_parent_with_line_clears() {
+        let boards = [
+            // Rows 17 and 19 are cleared, the hole row between them is kept (column 3 becomes
+            // empty)
+            &[
+                "....#.....",
+                ".#..##....",
+                "#########.",
+                "#.#.#####.",
+                "#########.",
+            ][..],
+            // Consecutive rows 18 and 19 are cleared
+            &["##..#.....", "#.#.####..", "#########.", "#########."],
+        ];
+        for bottom_rows in boards {
+            let mut rows = vec![".........."; BitBoard::PLAYABLE_HEIGHT - bottom_rows.len()];
+            rows.extend(bottom_rows);
+            let parent = BoardAnalysis::from_board(&BitBoard::from_ascii(&rows.join("\n")));
+            let mut placement = Piece::new(PieceKind::I).rotated_right();
+            while let Some(right) = placement
+                .right()
+                .filter(|p| !parent.board().is_colliding(*p))
+            {
+                placement = right;
+            }
+            let placement = placement.simulate_drop_position(parent.board());
+            let mut board = parent.board().clone();
+            board.fill_piece(placement);
+            let cleared_lines = board.clear_lines();
+            assert_eq!(cleared_lines, 2);
+
+            let incremental = BoardAnalysis::from_parent(&parent, board, placement, cleared_lines);
+            let full = BoardAnalysis::from_board(incremental.board());
+            // The base metrics are derived from the parent, not left to lazy recomputation
+            assert_eq!(
+                incremental.column_heights.get(),
+                Some(full.column_heights())
+            );
+            assert_eq!(
+                incremental.column_occupied_cells.get(),
+                Some(full.column_occupied_cells())
+            );
+            assert_eq!(
+                incremental.row_transition_counts.get(),
+                Some(full.row_transition_counts())
+            );
+            assert_eq!(
+                incremental.column_transition_counts.get(),
+                Some(full.column_transition_counts())
+            );
+        }
+    }
+
     #[test]
     fn test_column_heights() {
         let board = test_boards::staircase();
@@ -609,6 +1268,292 @@ mod tests {
         assert_eq!(analysis.sum_of_hole_depth(), 4);
     }
 
+    #[test]
+    fn test_hole_structure_metrics() {
+        // Format: (name, board, rows_with_holes, cells_above_holes, reachable_overhangs)
+        let test_cases = vec![
+            ("empty", test_boards::empty(), 0, 0, 0),
+            ("flat", test_boards::flat(), 0, 0, 0),
+            ("single_hole", test_boards::single_hole(), 1, 1, 1),
+        ];
+
+        for (name, board, rows, cells, overhangs) in test_cases {
+            let analysis = BoardAnalysis::from_board(&board);
+            assert_eq!(analysis.rows_with_holes(), rows, "{name}: rows_with_holes");
+            assert_eq!(
+                analysis.cells_above_holes(),
+                cells,
+                "{name}: cells_above_holes"
+            );
+            assert_eq!(
+                analysis.reachable_overhangs(),
+                overhangs,
+                "{name}: reachable_overhangs"
+            );
+        }
+    }
+
+    #[test]
+    fn test_hole_structure_metrics_complex() {
+        // Columns 0-1: 2x2 overhang that can be reached from column 2 (open to the sky)
+        // Column 5: enclosed hole under a 2-cell stack
+        let board = BitBoard::from_ascii(
+            "
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ##...#....
+            .....#....
+            ....#.#...
+            #########.
+            ",
+        );
+        let analysis = BoardAnalysis::from_board(&board);
+        assert_eq!(analysis.num_holes(), 5);
+        assert_eq!(analysis.rows_with_holes(), 2);
+        assert_eq!(analysis.cells_above_holes(), 4);
+        assert_eq!(analysis.reachable_overhangs(), 4);
+    }
+
+    #[test]
+    fn test_open_well() {
+        // Format: (name, board, column, depth, clean_rows, i_dependencies, tetris_ready)
+        let test_cases = vec![
+            ("empty", test_boards::empty(), None, 0, 0, 0, false),
+            ("flat", test_boards::flat(), None, 0, 0, 0, false),
+            (
+                "edge_well",
+                test_boards::edge_well_tetris_ready(),
+                Some(0),
+                4,
+                0,
+                1,
+                false,
+            ),
+        ];
+
+        for (name, board, column, depth, clean_rows, i_dependencies, tetris_ready) in test_cases {
+            let analysis = BoardAnalysis::from_board(&board);
+            assert_eq!(analysis.open_well_column(), column, "{name}: column");
+            assert_eq!(analysis.open_well_depth(), depth, "{name}: depth");
+            assert_eq!(
+                analysis.open_well_clean_rows(),
+                clean_rows,
+                "{name}: clean_rows"
+            );
+            assert_eq!(
+                analysis.i_dependencies(),
+                i_dependencies,
+                "{name}: i_dependencies"
+            );
+            assert_eq!(
+                analysis.is_tetris_ready(),
+                tetris_ready,
+                "{name}: tetris_ready"
+            );
+        }
+    }
+
+    #[test]
+    fn test_open_well_tetris_ready() {
+        // Well in column 6 with 4 clean rows, the row above has a gap in column 0
+        let board = BitBoard::from_ascii(
+            "
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            .#####.###
+            ######.###
+            ######.###
+            ######.###
+            ######.###
+            ",
+        );
+        let analysis = BoardAnalysis::from_board(&board);
+        assert_eq!(analysis.open_well_column(), Some(6));
+        assert_eq!(analysis.open_well_depth(), 5);
+        assert_eq!(analysis.open_well_clean_rows(), 4);
+        assert_eq!(analysis.i_dependencies(), 1);
+        assert!(analysis.is_tetris_ready());
+    }
+
+    #[test]
+    fn test_clean_fits() {
+        // S and Z always cover a cell on a flat surface
+        let analysis = BoardAnalysis::from_board(&test_boards::empty());
+        assert!(!analysis.has_clean_fit(PieceKind::S));
+        assert!(!analysis.has_clean_fit(PieceKind::Z));
+        assert_eq!(analysis.num_unfittable_piece_kinds(), 2);
+
+        // Alternating surface: O, J and L cannot be placed without covering a cell
+        let board = BitBoard::from_ascii(
+            "
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            #.#.#.#.#.
+            ",
+        );
+        let analysis = BoardAnalysis::from_board(&board);
+        for (kind, fits) in [
+            (PieceKind::I, true),
+            (PieceKind::O, false),
+            (PieceKind::S, true),
+            (PieceKind::Z, true),
+            (PieceKind::J, false),
+            (PieceKind::L, false),
+            (PieceKind::T, true),
+        ] {
+            assert_eq!(analysis.has_clean_fit(kind), fits, "{kind:?}");
+        }
+        assert_eq!(analysis.num_unfittable_piece_kinds(), 3);
+    }
+
+    #[test]
+    fn test_parity_imbalance() {
+        // Format: (name, board, checkerboard, column)
+        let test_cases = vec![
+            ("empty", test_boards::empty(), 0, 0),
+            ("flat", test_boards::flat(), 0, 0),
+            ("alternating", test_boards::alternating_pattern(), 5, 5),
+        ];
+
+        for (name, board, checkerboard, column) in test_cases {
+            let analysis = BoardAnalysis::from_board(&board);
+            assert_eq!(
+                analysis.checkerboard_parity_imbalance(),
+                checkerboard,
+                "{name}: checkerboard_parity_imbalance"
+            );
+            assert_eq!(
+                analysis.column_parity_imbalance(),
+                column,
+                "{name}: column_parity_imbalance"
+            );
+        }
+    }
+
+    #[test]
+    fn test_t_slots() {
+        // No T-slots on simple boards
+        for board in [
+            test_boards::empty(),
+            test_boards::flat(),
+            test_boards::staircase(),
+        ] {
+            let analysis = BoardAnalysis::from_board(&board);
+            assert_eq!(analysis.t_slot_count(), 0);
+            assert_eq!(analysis.t_slot_depth(), 0);
+        }
+
+        // T-spin double setup with the overhang on the left side
+        let board = BitBoard::from_ascii(
+            "
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ##........
+            #...######
+            ##.#######
+            ",
+        );
+        let analysis = BoardAnalysis::from_board(&board);
+        // The stem-down slot and a stem-left slot clearing the bottom row share one cavity
+        assert_eq!(analysis.t_slot_count(), 1);
+        assert_eq!(analysis.t_slot_depth(), 2);
+
+        // T-spin triple setup: vertical slot with the stem tucked under the left side
+        let board = BitBoard::from_ascii(
+            "
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            #####.####
+            ####..####
+            #####.####
+            ",
+        );
+        let analysis = BoardAnalysis::from_board(&board);
+        assert_eq!(analysis.t_slot_count(), 1);
+        assert_eq!(analysis.t_slot_depth(), 3);
+
+        // A stem-right shape at the top row would reach above the playable area
+        let mut rows = vec![".........."];
+        rows.extend([".#########"; 19]);
+        let board = BitBoard::from_ascii(&rows.join("\n"));
+        let analysis = BoardAnalysis::from_board(&board);
+        assert_eq!(analysis.t_slot_count(), 0);
+    }
+
     #[test]
     fn test_row_transitions() {
         let test_cases = vec![
@@ -15,9 +15,12 @@
 //! **Survival Features** - Directly affect game termination:
 //! - [`source::NumHoles`] - Count of covered empty cells
 //! - [`source::SumOfHoleDepth`] - Cumulative depth of buried holes
+//! - [`source::RowsWithHoles`] - Rows containing at least one hole
+//! - [`source::CellsAboveHoles`] - Occupied cells covering holes
 //! - [`source::MaxHeight`] - Tallest column height
 //! - [`source::CenterColumnMaxHeight`] - Tallest among center 4 columns
 //! - [`source::TotalHeight`] - Sum of all column heights
+//! - [`source::LandingHeight`] - Height at which the placed piece landed
 //!
 //! **Structure Features** - Affect placement flexibility:
 //! - [`source::SurfaceBumpiness`] - Height variation between adjacent columns (first-order)
@@ -25,18 +28,34 @@
 //! - [`source::RowTransitions`] - Horizontal fragmentation
 //! - [`source::ColumnTransitions`] - Vertical fragmentation
 //! - [`source::SumOfWellDepth`] - Cumulative well depth
+//! - [`source::ReachableOverhangs`] - Holes that can still be filled by sliding a piece
+//! - [`source::CheckerboardParityImbalance`] - Checkerboard color imbalance of occupied cells
+//! - [`source::ColumnParityImbalance`] - Even/odd column imbalance of occupied cells
+//! - [`source::IDependencies`] - Columns that need an I piece to fill
 //!
 //! **Score Features** - Directly contribute to game score:
 //! - [`source::NumClearedLines`] - Lines cleared by placement
 //! - [`source::EdgeIWellDepth`] - I-piece well quality at board edges
+//! - [`source::OpenWellColumn`] - Location of the deepest well
+//! - [`source::OpenWellDepth`] - Depth of the deepest well in any column
+//! - [`source::OpenWellCleanRows`] - Complete rows beside the deepest well
+//! - [`source::TetrisReady`] - Whether an I piece would clear 4 lines
+//! - [`source::ErodedPieceCells`] - Cleared lines × placed piece cells removed
+//! - [`source::TSlotCount`] - Number of T-spin double/triple slots
+//! - [`source::TSlotDepth`] - Lines cleared by the best T-slot
 //!
 //! ## Feature Types
 //!
 //! Feature sources are wrapped by feature types that provide transformation and normalization:
 //!
 //! - [`transform::RawTransform`] - Linear transformation with percentile-based normalization
+//! - [`transform::PiecewiseLinearTransform`] - Monotone piecewise-linear curve with
+//!   learnable knots
+//! - [`transform::SplineTransform`] - Cubic spline (e.g., smoothed KM survival curve)
 //! - [`transform::LineClearBonus`] - Discrete bonus mapping for line clears
 //! - [`transform::IWellReward`] - Triangular reward for optimal I-well depth
+//!   (used for both edge wells and the deepest open well)
+//! - [`transform::InteractionFeature`] - Combination of two features (product, min/max, gating)
 //!
 //! # Feature Processing Pipeline
 //!
@@ -84,6 +103,27 @@ pub trait BoardFeature: fmt::Debug + Send + Sync {
     fn feature_processing(&self) -> FeatureProcessing;
     fn clone_boxed(&self) -> BoxedBoardFeature;
 
+    /// Returns the trainable parameters of the transform shape, empty if it has none.
+    ///
+    /// See [`transform::PiecewiseLinearTransform`] for the parameters of a learnable curve.
+    #[must_use]
+    fn shape_params(&self) -> Vec<f32> {
+        vec![]
+    }
+
+    /// Returns a copy of this feature with the transform shape rebuilt from `params`.
+    ///
+    /// `params` has the layout of [`shape_params()`](Self::shape_params).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `params` has a different length than [`shape_params()`](Self::shape_params).
+    #[must_use]
+    fn with_shape_params(&self, params: &[f32]) -> BoxedBoardFeature {
+        assert!(params.is_empty());
+        self.clone_boxed()
+    }
+
     #[must_use]
     fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32;
 
@@ -135,6 +175,14 @@ impl BoardFeature for BoxedBoardFeature {
         self.as_ref().clone_boxed()
     }
 
+    fn shape_params(&self) -> Vec<f32> {
+        self.as_ref().shape_params()
+    }
+
+    fn with_shape_params(&self, params: &[f32]) -> BoxedBoardFeature {
+        self.as_ref().with_shape_params(params)
+    }
+
     fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
         self.as_ref().extract_raw(analysis)
     }
@@ -146,4 +194,8 @@ impl BoardFeature for BoxedBoardFeature {
     fn normalize(&self, transformed: f32) -> f32 {
         self.as_ref().normalize(transformed)
     }
+
+    fn compute_feature_value(&self, analysis: &PlacementAnalysis) -> BoardFeatureValue {
+        self.as_ref().compute_feature_value(analysis)
+    }
 }
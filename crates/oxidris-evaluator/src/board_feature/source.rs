@@ -6,7 +6,9 @@
 
 use std::fmt;
 
-use crate::placement_analysis::PlacementAnalysis;
+use oxidris_engine::PieceKind;
+
+use crate::placement_analysis::{PieceQueue, PlacementAnalysis};
 
 #[must_use]
 pub fn all_board_feature_sources() -> Vec<BoxedBoardFeatureSource> {
@@ -14,21 +16,47 @@ pub fn all_board_feature_sources() -> Vec<BoxedBoardFeatureSource> {
         // survival features
         Box::new(NumHoles),
         Box::new(SumOfHoleDepth),
+        Box::new(RowsWithHoles),
+        Box::new(CellsAboveHoles),
         Box::new(MaxHeight),
         Box::new(CenterColumnMaxHeight),
         Box::new(TotalHeight),
+        Box::new(LandingHeight),
+        Box::new(UnfittablePieceKinds),
         // structure features
         Box::new(SurfaceBumpiness),
         Box::new(SurfaceRoughness),
         Box::new(RowTransitions),
         Box::new(ColumnTransitions),
         Box::new(SumOfWellDepth),
+        Box::new(ReachableOverhangs),
+        Box::new(CheckerboardParityImbalance),
+        Box::new(ColumnParityImbalance),
+        Box::new(IDependencies),
         // score features
         Box::new(NumClearedLines),
         Box::new(EdgeIWellDepth),
+        Box::new(OpenWellColumn),
+        Box::new(OpenWellDepth),
+        Box::new(OpenWellCleanRows),
+        Box::new(TetrisReady),
+        Box::new(ErodedPieceCells),
+        Box::new(TSlotCount),
+        Box::new(TSlotDepth),
+        // queue features
+        Box::new(HeldIPiece),
+        Box::new(QueueFitCount),
     ]
 }
 
+/// Looks up a feature source by its ID among [`all_board_feature_sources()`].
+#[must_use]
+pub fn board_feature_source_by_id(id: &str) -> Option<BoxedBoardFeatureSource> {
+    all_board_feature_sources()
+        .into_iter()
+        .find(|source| source.id() == id)
+}
+
 pub trait BoardFeatureSource: fmt::Debug + Send + Sync {
     #[must_use]
     fn id(&self) -> &str;
@@ -132,6 +160,62 @@ impl BoardFeatureSource for SumOfHoleDepth {
     }
 }
 
+/// Number of rows containing at least one hole.
+///
+/// Unlike [`NumHoles`], several holes in the same row count once, because a single
+/// line clear above them exposes the whole row at once.
+///
+/// # Raw measurement
+///
+/// - A hole is an empty cell below the top occupied cell of its column
+/// - `raw = number of rows with at least one hole`
+#[derive(Debug, Clone)]
+pub struct RowsWithHoles;
+
+impl BoardFeatureSource for RowsWithHoles {
+    fn id(&self) -> &'static str {
+        "rows_with_holes"
+    }
+    fn name(&self) -> &'static str {
+        "Rows with Holes"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        analysis.board_analysis().rows_with_holes().into()
+    }
+}
+
+/// Number of occupied cells stacked above holes.
+///
+/// Measures how much material covers the holes. Unlike [`SumOfHoleDepth`], only occupied
+/// cells are counted, and each column is counted once regardless of how many holes it has.
+///
+/// # Raw measurement
+///
+/// For each column with at least one hole:
+/// - Find the lowest hole in the column
+/// - Count the occupied cells above it
+/// - `raw = Σ(occupied cells above the lowest hole)` across all columns
+#[derive(Debug, Clone)]
+pub struct CellsAboveHoles;
+
+impl BoardFeatureSource for CellsAboveHoles {
+    fn id(&self) -> &'static str {
+        "cells_above_holes"
+    }
+    fn name(&self) -> &'static str {
+        "Cells above Holes"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        analysis.board_analysis().cells_above_holes()
+    }
+}
+
 /// Maximum column height across the board.
 ///
 /// Measures the height of the tallest column, which directly relates to top-out risk
@@ -212,6 +296,67 @@ impl BoardFeatureSource for TotalHeight {
     }
 }
 
+/// Height at which the placed piece landed (Dellacherie's landing height).
+///
+/// Captures how high the current placement is made. Placing pieces high up on the
+/// stack leaves less room for later pieces, even before the stack is compacted by
+/// line clears.
+///
+/// # Raw measurement
+///
+/// - Take the lowest and highest rows occupied by the placed piece, counted from the floor
+///   (the bottom row has height 1)
+/// - `raw = ⌊(lowest + highest) / 2⌋` (height of the piece's middle row)
+/// - Measured on the board before lines are cleared
+#[derive(Debug, Clone)]
+pub struct LandingHeight;
+
+impl BoardFeatureSource for LandingHeight {
+    fn id(&self) -> &'static str {
+        "landing_height"
+    }
+    fn name(&self) -> &'static str {
+        "Landing Height"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        analysis.landing_height().into()
+    }
+}
+
+/// Number of piece kinds that cannot be placed without creating a hole.
+///
+/// A surface that only accepts a few piece kinds forces holes as soon as the other kinds
+/// arrive, so this measures how robust the stack is to an unlucky queue.
+///
+/// # Raw measurement
+///
+/// - See [`BoardAnalysis::has_clean_fit`](crate::board_analysis::BoardAnalysis::has_clean_fit)
+///   for the placement rule
+/// - `raw = number of piece kinds without a clean placement` (0-7)
+#[derive(Debug, Clone)]
+pub struct UnfittablePieceKinds;
+
+impl BoardFeatureSource for UnfittablePieceKinds {
+    fn id(&self) -> &'static str {
+        "unfittable_piece_kinds"
+    }
+    fn name(&self) -> &'static str {
+        "Unfittable Piece Kinds"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        analysis
+            .board_analysis()
+            .num_unfittable_piece_kinds()
+            .into()
+    }
+}
+
 /// Horizontal fragmentation (occupancy changes between adjacent cells within rows).
 ///
 /// Counts transitions where adjacent cells differ in occupancy (empty ↔ filled) within each row.
@@ -359,6 +504,119 @@ impl BoardFeatureSource for SumOfWellDepth {
     }
 }
 
+/// Number of holes reachable by sliding a piece under an overhang.
+///
+/// Distinguishes overhangs from true holes: a covered empty cell that connects, through
+/// empty cells in the same row, to a column open to the sky can still be filled by a
+/// tuck. [`NumHoles`] counts both kinds.
+///
+/// # Raw measurement
+///
+/// For each hole, scan left and right along its row while cells are empty:
+/// - If an empty cell above its column's surface is reached, the hole is reachable
+/// - `raw = number of reachable holes`
+#[derive(Debug, Clone)]
+pub struct ReachableOverhangs;
+
+impl BoardFeatureSource for ReachableOverhangs {
+    fn id(&self) -> &'static str {
+        "reachable_overhangs"
+    }
+    fn name(&self) -> &'static str {
+        "Reachable Overhangs"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        analysis.board_analysis().reachable_overhangs().into()
+    }
+}
+
+/// Checkerboard parity imbalance of the occupied cells.
+///
+/// Coloring the board like a checkerboard, every piece except T covers two cells of each
+/// color. A large imbalance therefore requires T pieces to fix, which matters for 7-bag
+/// aware play.
+///
+/// # Raw measurement
+///
+/// - Color each cell by `(column + row) mod 2`
+/// - `raw = |occupied cells of color 0 - occupied cells of color 1|`
+#[derive(Debug, Clone)]
+pub struct CheckerboardParityImbalance;
+
+impl BoardFeatureSource for CheckerboardParityImbalance {
+    fn id(&self) -> &'static str {
+        "checkerboard_parity_imbalance"
+    }
+    fn name(&self) -> &'static str {
+        "Checkerboard Parity Imbalance"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        analysis
+            .board_analysis()
+            .checkerboard_parity_imbalance()
+            .into()
+    }
+}
+
+/// Column parity imbalance of the occupied cells.
+///
+/// Flat placements keep the balance between even and odd columns, while vertical I, L, J
+/// and T placements shift it.
+///
+/// # Raw measurement
+///
+/// - `raw = |occupied cells in even columns - occupied cells in odd columns|`
+#[derive(Debug, Clone)]
+pub struct ColumnParityImbalance;
+
+impl BoardFeatureSource for ColumnParityImbalance {
+    fn id(&self) -> &'static str {
+        "column_parity_imbalance"
+    }
+    fn name(&self) -> &'static str {
+        "Column Parity Imbalance"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        analysis.board_analysis().column_parity_imbalance().into()
+    }
+}
+
+/// Number of I-dependencies (columns that need an I piece to fill).
+///
+/// Each well deeper than 2 can only be filled cleanly by an I piece. One such well is a
+/// tetris setup, but more than one means the stack depends on I pieces arriving in time.
+///
+/// # Raw measurement
+///
+/// - For each column, calculate well depth (how much lower it is than neighbors)
+/// - `raw = number of columns with depth >= 3`
+#[derive(Debug, Clone)]
+pub struct IDependencies;
+
+impl BoardFeatureSource for IDependencies {
+    fn id(&self) -> &'static str {
+        "i_dependencies"
+    }
+    fn name(&self) -> &'static str {
+        "I-Dependencies"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        analysis.board_analysis().i_dependencies().into()
+    }
+}
+
 /// Number of lines cleared by a placement.
 ///
 /// Counts how many lines were cleared as a result of the current piece placement.
@@ -414,3 +672,265 @@ impl BoardFeatureSource for EdgeIWellDepth {
         analysis.board_analysis().edge_i_well_depth().into()
     }
 }
+
+/// Location of the deepest well on the board.
+///
+/// The value is categorical, so it is intended for table-based transforms, which can map
+/// each column to its own survival time.
+///
+/// # Raw measurement
+///
+/// - Find the column with the deepest well (leftmost on ties)
+/// - `raw = column + 1` (1-10), or `0` if there is no well
+#[derive(Debug, Clone)]
+pub struct OpenWellColumn;
+
+impl BoardFeatureSource for OpenWellColumn {
+    fn id(&self) -> &'static str {
+        "open_well_column"
+    }
+    fn name(&self) -> &'static str {
+        "Open Well Column"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        analysis
+            .board_analysis()
+            .open_well_column()
+            .map_or(0, |col| u32::from(col) + 1)
+    }
+}
+
+/// Depth of the deepest well anywhere on the board.
+///
+/// Generalizes [`EdgeIWellDepth`] to wells in any column, so that center and
+/// off-center wells can be evaluated as well.
+///
+/// # Raw measurement
+///
+/// - For each column, calculate well depth (how much lower it is than neighbors)
+/// - `raw = max(well depth)` across all columns
+#[derive(Debug, Clone)]
+pub struct OpenWellDepth;
+
+impl BoardFeatureSource for OpenWellDepth {
+    fn id(&self) -> &'static str {
+        "open_well_depth"
+    }
+    fn name(&self) -> &'static str {
+        "Open Well Depth"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        analysis.board_analysis().open_well_depth().into()
+    }
+}
+
+/// Cleanliness of the rows beside the deepest well.
+///
+/// A deep well is only worth keeping if the rows beside it are complete; rows with gaps
+/// will not be cleared by the I piece.
+///
+/// # Raw measurement
+///
+/// - Starting at the bottom of the deepest well, move upward while the row is filled
+///   in every column except the well
+/// - `raw = number of such rows` (limited by the well depth)
+#[derive(Debug, Clone)]
+pub struct OpenWellCleanRows;
+
+impl BoardFeatureSource for OpenWellCleanRows {
+    fn id(&self) -> &'static str {
+        "open_well_clean_rows"
+    }
+    fn name(&self) -> &'static str {
+        "Open Well Clean Rows"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        analysis.board_analysis().open_well_clean_rows().into()
+    }
+}
+
+/// Whether the board is ready for a tetris (4-line clear).
+///
+/// # Raw measurement
+///
+/// - `raw = 1` if at least 4 clean rows are beside the deepest well, otherwise `0`
+#[derive(Debug, Clone)]
+pub struct TetrisReady;
+
+impl BoardFeatureSource for TetrisReady {
+    fn id(&self) -> &'static str {
+        "tetris_ready"
+    }
+    fn name(&self) -> &'static str {
+        "Tetris Ready"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        analysis.board_analysis().is_tetris_ready().into()
+    }
+}
+
+/// Eroded piece cells (Dellacherie's line-clear measure).
+///
+/// Rewards placements that clear lines using the cells of the placed piece itself.
+/// Clearing many lines with most of the piece contributes more than clearing a single
+/// line with one cell.
+///
+/// # Raw measurement
+///
+/// - `cells = number of placed piece cells that lie in cleared rows`
+/// - `raw = cleared_lines × cells` (0-16)
+#[derive(Debug, Clone)]
+pub struct ErodedPieceCells;
+
+impl BoardFeatureSource for ErodedPieceCells {
+    fn id(&self) -> &'static str {
+        "eroded_piece_cells"
+    }
+    fn name(&self) -> &'static str {
+        "Eroded Piece Cells"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        analysis.eroded_piece_cells().into()
+    }
+}
+
+/// Number of T-slots (T-spin double/triple shapes) on the board.
+///
+/// Counts positions where a T piece fits under the stack following the 3-corner rule.
+/// This prepares models to build for spins once T-spin scoring is available.
+///
+/// # Raw measurement
+///
+/// - See [`BoardAnalysis::t_slot_depth`](crate::board_analysis::BoardAnalysis::t_slot_depth)
+///   for the detection rule
+/// - `raw = number of detected T-slots`
+#[derive(Debug, Clone)]
+pub struct TSlotCount;
+
+impl BoardFeatureSource for TSlotCount {
+    fn id(&self) -> &'static str {
+        "t_slot_count"
+    }
+    fn name(&self) -> &'static str {
+        "T-Slot Count"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        analysis.board_analysis().t_slot_count().into()
+    }
+}
+
+/// Depth of the best T-slot on the board.
+///
+/// Measures how many lines the best T-slot would clear: 2 for a ready T-spin double,
+/// 3 for a ready T-spin triple.
+///
+/// # Raw measurement
+///
+/// - For each detected T-slot, count the rows completed by filling it with a T piece
+/// - `raw = maximum over all T-slots` (0-3, 0 if there is no T-slot)
+#[derive(Debug, Clone)]
+pub struct TSlotDepth;
+
+impl BoardFeatureSource for TSlotDepth {
+    fn id(&self) -> &'static str {
+        "t_slot_depth"
+    }
+    fn name(&self) -> &'static str {
+        "T-Slot Depth"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        analysis.board_analysis().t_slot_depth().into()
+    }
+}
+
+/// Whether an I piece is held after the turn.
+///
+/// A held I piece can be spent on a tetris whenever the well is ready, so keeping it in
+/// hold is valuable while building for tetrises.
+///
+/// # Raw measurement
+///
+/// - `raw = 1` if the held piece is I, otherwise `0` (also `0` without a piece queue)
+#[derive(Debug, Clone)]
+pub struct HeldIPiece;
+
+impl BoardFeatureSource for HeldIPiece {
+    fn id(&self) -> &'static str {
+        "held_i_piece"
+    }
+    fn name(&self) -> &'static str {
+        "Held I Piece"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        let held = analysis.queue().and_then(PieceQueue::held);
+        (held == Some(PieceKind::I)).into()
+    }
+}
+
+/// Number of upcoming pieces that have a clean spot on the board.
+///
+/// # Raw measurement
+///
+/// - For each of the next [`QueueFitCount::LOOKAHEAD`] pieces, check whether it can be
+///   placed without creating a hole (see
+///   [`BoardAnalysis::has_clean_fit`](crate::board_analysis::BoardAnalysis::has_clean_fit))
+/// - `raw = number of such pieces` (`0` without a piece queue)
+///
+/// Each piece is checked against the board after this placement, not after the earlier
+/// queued pieces are placed.
+#[derive(Debug, Clone)]
+pub struct QueueFitCount;
+
+impl QueueFitCount {
+    /// Number of upcoming pieces checked.
+    pub const LOOKAHEAD: usize = 3;
+}
+
+impl BoardFeatureSource for QueueFitCount {
+    fn id(&self) -> &'static str {
+        "queue_fit_count"
+    }
+    fn name(&self) -> &'static str {
+        "Queue Fit Count"
+    }
+    fn clone_boxed(&self) -> BoxedBoardFeatureSource {
+        Box::new(self.clone())
+    }
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        let Some(queue) = analysis.queue() else {
+            return 0;
+        };
+        let board = analysis.board_analysis();
+        let count = queue
+            .next()
+            .iter()
+            .take(Self::LOOKAHEAD)
+            .filter(|kind| board.has_clean_fit(**kind))
+            .count();
+        u32::try_from(count).unwrap()
+    }
+}
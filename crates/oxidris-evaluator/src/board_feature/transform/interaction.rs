@@ -0,0 +1,357 @@
+use serde::{Deserialize, Serialize};
+
+use super::{FeatureProcessing, FeatureProcessingError};
+use crate::{
+    board_feature::{
+        BoardFeature, BoardFeatureSource, BoardFeatureValue, BoxedBoardFeature, source,
+    },
+    placement_analysis::PlacementAnalysis,
+};
+
+/// Composite feature combining the normalized values of two features.
+///
+/// A linear weighted sum cannot express that a penalty matters more in some situations
+/// than in others (e.g., holes are more dangerous on a high stack). This feature combines
+/// two existing features so that such interactions can be learned by assigning a single
+/// weight, while both operands stay individually interpretable.
+///
+/// # Transform
+///
+/// Both operands are computed with their own transform and normalization, then combined
+/// with an [`InteractionOperator`]:
+///
+/// - `Product`: `left × right` (high only when both are good)
+/// - `Min` / `Max`: the worse / better of the two
+/// - `GateAbove` / `GateBelow`: `right` when `left` is above / below a threshold, else `0.0`
+///
+/// Since the combined value requires both operands, it is computed in
+/// [`compute_feature_value()`](BoardFeature::compute_feature_value). The `raw` value
+/// reported is the raw value of the left operand.
+///
+/// # Normalization
+///
+/// Operands are normalized to \[0.0, 1.0\] and all operators preserve that range,
+/// so the combined value is only clamped.
+#[derive(Debug, Clone)]
+pub struct InteractionFeature {
+    id: String,
+    name: String,
+    operator: InteractionOperator,
+    left: BoxedBoardFeature,
+    right: BoxedBoardFeature,
+}
+
+/// Operator used by [`InteractionFeature`] to combine normalized operand values.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InteractionOperator {
+    /// `left × right`
+    Product,
+    /// `min(left, right)`
+    Min,
+    /// `max(left, right)`
+    Max,
+    /// `right` if `left >= threshold`, otherwise `0.0`
+    GateAbove { threshold: f32 },
+    /// `right` if `left < threshold`, otherwise `0.0`
+    GateBelow { threshold: f32 },
+}
+
+/// Serializable definition of an [`InteractionFeature`].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct InteractionParam {
+    operator: InteractionOperator,
+    left: InteractionOperand,
+    right: InteractionOperand,
+}
+
+/// Serializable definition of one operand of an [`InteractionFeature`].
+///
+/// Stores the same information as a top-level model feature, so operands can be any
+/// feature, including other interactions.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct InteractionOperand {
+    id: String,
+    name: String,
+    source_id: String,
+    processing: Box<FeatureProcessing>,
+}
+
+impl InteractionFeature {
+    #[must_use]
+    pub fn new(
+        id: String,
+        name: String,
+        operator: InteractionOperator,
+        left: BoxedBoardFeature,
+        right: BoxedBoardFeature,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            operator,
+            left,
+            right,
+        }
+    }
+
+    #[must_use]
+    pub fn operator(&self) -> InteractionOperator {
+        self.operator
+    }
+
+    #[must_use]
+    pub fn left(&self) -> &BoxedBoardFeature {
+        &self.left
+    }
+
+    #[must_use]
+    pub fn right(&self) -> &BoxedBoardFeature {
+        &self.right
+    }
+}
+
+impl InteractionOperator {
+    /// Combines two normalized values.
+    #[must_use]
+    pub fn combine(self, left: f32, right: f32) -> f32 {
+        match self {
+            Self::Product => left * right,
+            Self::Min => f32::min(left, right),
+            Self::Max => f32::max(left, right),
+            Self::GateAbove { threshold } => {
+                if left >= threshold {
+                    right
+                } else {
+                    0.0
+                }
+            }
+            Self::GateBelow { threshold } => {
+                if left < threshold {
+                    right
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+impl InteractionParam {
+    /// Builds the interaction feature described by this parameter.
+    ///
+    /// Operand sources are resolved by ID from
+    /// [`all_board_feature_sources()`](source::all_board_feature_sources).
+    pub fn build(
+        &self,
+        id: String,
+        name: String,
+    ) -> Result<BoxedBoardFeature, FeatureProcessingError> {
+        Ok(Box::new(InteractionFeature::new(
+            id,
+            name,
+            self.operator,
+            self.left.build()?,
+            self.right.build()?,
+        )))
+    }
+}
+
+impl InteractionOperand {
+    fn from_feature(feature: &BoxedBoardFeature) -> Self {
+        Self {
+            id: feature.id().to_owned(),
+            name: feature.name().to_owned(),
+            source_id: feature.feature_source().id().to_owned(),
+            processing: Box::new(feature.feature_processing()),
+        }
+    }
+
+    fn build(&self) -> Result<BoxedBoardFeature, FeatureProcessingError> {
+        let source = source::board_feature_source_by_id(&self.source_id).ok_or_else(|| {
+            FeatureProcessingError::UnknownSource {
+                source_id: self.source_id.clone(),
+            }
+        })?;
+        self.processing
+            .apply(self.id.clone(), self.name.clone(), source)
+    }
+}
+
+impl BoardFeature for InteractionFeature {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn feature_source(&self) -> &dyn BoardFeatureSource {
+        self.left.feature_source()
+    }
+
+    fn feature_processing(&self) -> FeatureProcessing {
+        FeatureProcessing::Interaction(InteractionParam {
+            operator: self.operator,
+            left: InteractionOperand::from_feature(&self.left),
+            right: InteractionOperand::from_feature(&self.right),
+        })
+    }
+
+    fn clone_boxed(&self) -> BoxedBoardFeature {
+        Box::new(self.clone())
+    }
+
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        self.left.extract_raw(analysis)
+    }
+
+    /// Transforms the left operand alone (see the type-level docs).
+    fn transform(&self, raw: u32) -> f32 {
+        self.left.normalize(self.left.transform(raw))
+    }
+
+    fn normalize(&self, transformed: f32) -> f32 {
+        transformed.clamp(0.0, 1.0)
+    }
+
+    fn compute_feature_value(&self, analysis: &PlacementAnalysis) -> BoardFeatureValue {
+        let left = self.left.compute_feature_value(analysis);
+        let right = self.right.compute_feature_value(analysis);
+        let transformed = self.operator.combine(left.normalized, right.normalized);
+        BoardFeatureValue {
+            raw: left.raw,
+            transformed,
+            normalized: self.normalize(transformed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use oxidris_engine::{BitBoard, Piece, PieceKind};
+
+    use super::*;
+    use crate::{
+        board_feature::{
+            FeatureSignal,
+            source::{MaxHeight, NumHoles},
+            transform::{RawTransform, RawTransformParam},
+        },
+        placement_evaluator::{FeatureBasedPlacementEvaluator, PlacementEvaluator},
+    };
+
+    fn raw_feature<S>(source: S, max: f32) -> BoxedBoardFeature
+    where
+        S: BoardFeatureSource + Clone + 'static,
+    {
+        let id = format!("{}_raw_penalty", source.id());
+        Box::new(RawTransform::new(
+            id.clone(),
+            id,
+            source,
+            RawTransformParam::new(FeatureSignal::Negative, 0.0, max),
+        ))
+    }
+
+    #[test]
+    fn test_operators() {
+        let cases = [
+            (InteractionOperator::Product, 0.5, 0.4, 0.2),
+            (InteractionOperator::Min, 0.5, 0.4, 0.4),
+            (InteractionOperator::Max, 0.5, 0.4, 0.5),
+            (
+                InteractionOperator::GateAbove { threshold: 0.5 },
+                0.5,
+                0.4,
+                0.4,
+            ),
+            (
+                InteractionOperator::GateAbove { threshold: 0.6 },
+                0.5,
+                0.4,
+                0.0,
+            ),
+            (
+                InteractionOperator::GateBelow { threshold: 0.5 },
+                0.5,
+                0.4,
+                0.0,
+            ),
+            (
+                InteractionOperator::GateBelow { threshold: 0.6 },
+                0.5,
+                0.4,
+                0.4,
+            ),
+        ];
+        for (operator, left, right, expected) in cases {
+            let actual = operator.combine(left, right);
+            assert!((actual - expected).abs() < 1e-6, "{operator:?}: {actual}");
+        }
+    }
+
+    #[test]
+    fn test_compute_feature_value_and_roundtrip() {
+        let feature: BoxedBoardFeature = Box::new(InteractionFeature::new(
+            "height_x_holes".into(),
+            "Height x Holes".into(),
+            InteractionOperator::Product,
+            raw_feature(MaxHeight, 20.0),
+            raw_feature(NumHoles, 10.0),
+        ));
+
+        // One hole under the stack in the leftmost column
+        let board = BitBoard::from_ascii(
+            "
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            #.........
+            .#########
+            ",
+        );
+        let piece = Piece::new(PieceKind::O).simulate_drop_position(&board);
+        let analysis = PlacementAnalysis::from_board(&board, piece);
+        // Called through `BoxedBoardFeature`, as evaluators do
+        let value = feature.compute_feature_value(&analysis);
+        // max_height = 3 → 0.85, num_holes = 1 → 0.9
+        assert_eq!(value.raw, 3);
+        assert!((value.normalized - 0.85 * 0.9).abs() < 1e-6);
+
+        let evaluator = FeatureBasedPlacementEvaluator::new(vec![feature.clone()], vec![1.0]);
+        assert!((evaluator.evaluate_placement(&analysis) - value.normalized).abs() < 1e-6);
+        let mut scores = [0.0];
+        evaluator.evaluate_placements(std::slice::from_ref(&analysis), &mut scores);
+        assert!((scores[0] - value.normalized).abs() < 1e-6);
+
+        let processing = feature.feature_processing();
+        let json = serde_json::to_string(&processing).unwrap();
+        let processing: FeatureProcessing = serde_json::from_str(&json).unwrap();
+        let restored = processing
+            .apply(
+                feature.id().to_owned(),
+                feature.name().to_owned(),
+                feature.feature_source().clone_boxed(),
+            )
+            .unwrap();
+        let restored_value = restored.compute_feature_value(&analysis);
+        assert!((restored_value.normalized - value.normalized).abs() < 1e-6);
+    }
+}
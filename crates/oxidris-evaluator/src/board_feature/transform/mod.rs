@@ -1,15 +1,26 @@
 use serde::{Deserialize, Serialize};
 
-pub use self::{raw::*, specialized::*, table::*};
+pub use self::{interaction::*, piecewise::*, raw::*, specialized::*, spline::*, table::*};
 use crate::board_feature::{BoardFeatureSource, BoxedBoardFeature, FeatureSignal};
 
+mod interaction;
+mod piecewise;
 mod raw;
 mod specialized;
+mod spline;
 mod table;
 
 fn linear_normalize(val: f32, signal: FeatureSignal, min: f32, max: f32) -> f32 {
     let span = max - min;
-    let norm = ((val - min) / span).clamp(0.0, 1.0);
+    let norm = if span > 0.0 {
+        ((val - min) / span).clamp(0.0, 1.0)
+    } else if val > min {
+        // Degenerate range (e.g., a sparse feature whose percentiles coincide):
+        // treat it as a step at `min` instead of dividing by zero.
+        1.0
+    } else {
+        0.0
+    };
     match signal {
         FeatureSignal::Positive => norm,
         FeatureSignal::Negative => 1.0 - norm,
@@ -21,24 +32,94 @@ fn linear_normalize(val: f32, signal: FeatureSignal, min: f32, max: f32) -> f32
 pub enum FeatureProcessing {
     RawTransform(RawTransformParam),
     TableTransform(TableTransformParam),
+    PiecewiseLinear(PiecewiseLinearParam),
+    Spline(SplineTransformParam),
     LineClearBonus,
     IWellReward,
+    Interaction(InteractionParam),
+}
+
+/// Invalid parameters of a knot-based transform ([`PiecewiseLinearParam`] or
+/// [`SplineTransformParam`]).
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+pub enum KnotParamError {
+    #[display("at least one knot is required")]
+    NoKnots,
+    #[display("{field} has {actual} entries, but there are {expected} knots")]
+    LengthMismatch {
+        field: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    #[display("knots must be finite and strictly increasing")]
+    UnsortedKnots,
+    #[display("outputs must be non-decreasing and in [0.0, 1.0]")]
+    InvalidOutputs,
+    #[display("{field} must be finite")]
+    NonFinite { field: &'static str },
+}
+
+impl KnotParamError {
+    fn check_knots(knots: &[f32]) -> Result<(), Self> {
+        if knots.is_empty() {
+            return Err(Self::NoKnots);
+        }
+        if !knots.iter().all(|k| k.is_finite()) || !knots.windows(2).all(|w| w[0] < w[1]) {
+            return Err(Self::UnsortedKnots);
+        }
+        Ok(())
+    }
+
+    fn check_len(field: &'static str, knots: &[f32], values: &[f32]) -> Result<(), Self> {
+        if knots.len() != values.len() {
+            return Err(Self::LengthMismatch {
+                field,
+                expected: knots.len(),
+                actual: values.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, derive_more::Display, derive_more::Error)]
+pub enum FeatureProcessingError {
+    #[display("Feature source ID {source_id} not found")]
+    UnknownSource { source_id: String },
 }
 
 impl FeatureProcessing {
-    pub fn apply<S>(&self, id: String, name: String, source: S) -> BoxedBoardFeature
+    /// Builds a feature applying this processing to `source`.
+    ///
+    /// For [`FeatureProcessing::Interaction`], `source` is ignored and the operand
+    /// sources are resolved from the parameter instead.
+    pub fn apply<S>(
+        &self,
+        id: String,
+        name: String,
+        source: S,
+    ) -> Result<BoxedBoardFeature, FeatureProcessingError>
     where
         S: BoardFeatureSource + Clone + 'static,
     {
-        match self {
+        let feature: BoxedBoardFeature = match self {
             Self::RawTransform(param) => {
                 Box::new(RawTransform::new(id, name, source, param.clone()))
             }
             Self::TableTransform(param) => {
                 Box::new(TableTransform::new(id, name, source, param.clone()))
             }
+            Self::PiecewiseLinear(param) => Box::new(PiecewiseLinearTransform::new(
+                id,
+                name,
+                source,
+                param.clone(),
+            )),
+            Self::Spline(param) => Box::new(SplineTransform::new(id, name, source, param.clone())),
             Self::LineClearBonus => Box::new(LineClearBonus::new(id, name, source)),
             Self::IWellReward => Box::new(IWellReward::new(id, name, source)),
-        }
+            Self::Interaction(param) => return param.build(id, name),
+        };
+        Ok(feature)
     }
 }
@@ -0,0 +1,384 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::KnotParamError;
+use crate::{
+    board_feature::{
+        BoardFeature, BoardFeatureSource, BoxedBoardFeature, FeatureProcessing, FeatureSignal,
+    },
+    placement_analysis::PlacementAnalysis,
+};
+
+/// Monotone piecewise-linear feature transformation.
+///
+/// Maps raw values through a monotone curve defined by knots, so that non-linear effects
+/// (e.g., the first hole hurting more than the tenth) can be expressed with a handful of
+/// parameters.
+///
+/// # Transform
+///
+/// Linear interpolation between knots `(xᵢ, yᵢ)`, where `yᵢ` is non-decreasing.
+/// Outside the knot range, the first / last segment is extended linearly, so rare values
+/// beyond the observed range keep the trend until the value saturates at 0.0 or 1.0.
+///
+/// # Normalization
+///
+/// Knot outputs are in \[0.0, 1.0\], so the transformed value is only clamped:
+///
+/// ```text
+/// normalized = transformed.clamp(0.0, 1.0)
+/// if signal == Negative:
+///     normalized = 1.0 - normalized
+/// ```
+///
+/// # Learnable Knots
+///
+/// The output increments between consecutive knots are the feature's
+/// [shape parameters](BoardFeature::shape_params), which the genetic algorithm evolves
+/// together with the feature weights. [`PiecewiseLinearParam::from_increments()`] builds a
+/// valid curve from any increments, keeping the knot positions.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use oxidris_evaluator::board_feature::{
+///     FeatureSignal,
+///     source::NumHoles,
+///     transform::{PiecewiseLinearParam, PiecewiseLinearTransform},
+/// };
+///
+/// // The first two holes matter most
+/// let param = PiecewiseLinearParam::new(
+///     FeatureSignal::Negative,
+///     vec![0.0, 2.0, 10.0],
+///     vec![0.0, 0.7, 1.0],
+/// )
+/// .unwrap();
+/// let feature = PiecewiseLinearTransform::new(
+///     "num_holes_pwl".to_owned(),
+///     "Number of Holes (Piecewise Linear)".to_owned(),
+///     NumHoles,
+///     param,
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct PiecewiseLinearTransform<S> {
+    id: String,
+    name: String,
+    source: S,
+    param: PiecewiseLinearParam,
+}
+
+/// Parameters for [`PiecewiseLinearTransform`].
+///
+/// # Invariants
+///
+/// * At least one knot
+/// * `knots` are strictly increasing
+/// * `outputs` are non-decreasing and in \[0.0, 1.0\]
+///
+/// The invariants are also checked on deserialization.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "UncheckedPiecewiseLinearParam")]
+pub struct PiecewiseLinearParam {
+    signal: FeatureSignal,
+    knots: Vec<f32>,
+    outputs: Vec<f32>,
+}
+
+#[derive(Deserialize)]
+struct UncheckedPiecewiseLinearParam {
+    signal: FeatureSignal,
+    knots: Vec<f32>,
+    outputs: Vec<f32>,
+}
+
+impl<S> PiecewiseLinearTransform<S> {
+    #[must_use]
+    pub fn new(id: String, name: String, source: S, param: PiecewiseLinearParam) -> Self {
+        Self {
+            id,
+            name,
+            source,
+            param,
+        }
+    }
+}
+
+impl PiecewiseLinearParam {
+    /// Creates piecewise-linear parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the invariants listed on [`PiecewiseLinearParam`] do not hold.
+    pub fn new(
+        signal: FeatureSignal,
+        knots: Vec<f32>,
+        outputs: Vec<f32>,
+    ) -> Result<Self, KnotParamError> {
+        KnotParamError::check_knots(&knots)?;
+        KnotParamError::check_len("outputs", &knots, &outputs)?;
+        if !outputs.iter().all(|y| (0.0..=1.0).contains(y))
+            || !outputs.windows(2).all(|w| w[0] <= w[1])
+        {
+            return Err(KnotParamError::InvalidOutputs);
+        }
+        Ok(Self {
+            signal,
+            knots,
+            outputs,
+        })
+    }
+
+    /// Creates a curve from unconstrained parameters.
+    ///
+    /// Knot positions are sorted and deduplicated. Outputs start at `0.0` and increase by
+    /// `|incrementᵢ|` between consecutive knots, then are rescaled so that the last output
+    /// is `1.0` (unless all increments are zero).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `knots` is empty or not finite, or if
+    /// `increments.len() + 1 != knots.len()`.
+    pub fn from_increments(
+        signal: FeatureSignal,
+        mut knots: Vec<f32>,
+        increments: &[f32],
+    ) -> Result<Self, KnotParamError> {
+        if knots.is_empty() {
+            return Err(KnotParamError::NoKnots);
+        }
+        if increments.len() + 1 != knots.len() {
+            return Err(KnotParamError::LengthMismatch {
+                field: "increments",
+                expected: knots.len() - 1,
+                actual: increments.len(),
+            });
+        }
+        if !increments.iter().all(|inc| inc.is_finite()) {
+            return Err(KnotParamError::NonFinite {
+                field: "increments",
+            });
+        }
+        knots.sort_by(f32::total_cmp);
+
+        let mut outputs = Vec::with_capacity(knots.len());
+        let mut total = 0.0;
+        outputs.push(total);
+        for inc in increments {
+            total += inc.abs();
+            outputs.push(total);
+        }
+        if total > 0.0 {
+            for y in &mut outputs {
+                *y = (*y / total).min(1.0);
+            }
+        }
+
+        // Merge duplicated knots, keeping the larger output to stay monotone
+        let mut dedup_knots: Vec<f32> = Vec::with_capacity(knots.len());
+        let mut dedup_outputs: Vec<f32> = Vec::with_capacity(knots.len());
+        for (x, y) in knots.into_iter().zip(outputs) {
+            if dedup_knots.last() == Some(&x) {
+                *dedup_outputs.last_mut().unwrap() = y;
+            } else {
+                dedup_knots.push(x);
+                dedup_outputs.push(y);
+            }
+        }
+        Self::new(signal, dedup_knots, dedup_outputs)
+    }
+
+    #[must_use]
+    pub fn signal(&self) -> FeatureSignal {
+        self.signal
+    }
+
+    #[must_use]
+    pub fn knots(&self) -> &[f32] {
+        &self.knots
+    }
+
+    #[must_use]
+    pub fn outputs(&self) -> &[f32] {
+        &self.outputs
+    }
+
+    /// Evaluates the curve at `x` (linear extrapolation outside the knot range, which
+    /// [normalization](BoardFeature::normalize) clamps to \[0.0, 1.0\]).
+    #[must_use]
+    pub fn evaluate(&self, x: f32) -> f32 {
+        let knots = &self.knots;
+        let outputs = &self.outputs;
+        let n = knots.len();
+        if n == 1 {
+            return outputs[0];
+        }
+        let i = knots.partition_point(|&k| k <= x).clamp(1, n - 1) - 1;
+        let slope = (outputs[i + 1] - outputs[i]) / (knots[i + 1] - knots[i]);
+        outputs[i] + slope * (x - knots[i])
+    }
+}
+
+impl TryFrom<UncheckedPiecewiseLinearParam> for PiecewiseLinearParam {
+    type Error = KnotParamError;
+
+    fn try_from(param: UncheckedPiecewiseLinearParam) -> Result<Self, Self::Error> {
+        Self::new(param.signal, param.knots, param.outputs)
+    }
+}
+
+impl<S> BoardFeature for PiecewiseLinearTransform<S>
+where
+    S: BoardFeatureSource + Clone + fmt::Debug + Send + Sync + 'static,
+{
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn feature_source(&self) -> &dyn BoardFeatureSource {
+        &self.source
+    }
+
+    fn feature_processing(&self) -> FeatureProcessing {
+        FeatureProcessing::PiecewiseLinear(self.param.clone())
+    }
+
+    fn clone_boxed(&self) -> BoxedBoardFeature {
+        Box::new(self.clone())
+    }
+
+    fn shape_params(&self) -> Vec<f32> {
+        self.param.outputs.windows(2).map(|w| w[1] - w[0]).collect()
+    }
+
+    fn with_shape_params(&self, params: &[f32]) -> BoxedBoardFeature {
+        let param = PiecewiseLinearParam::from_increments(
+            self.param.signal,
+            self.param.knots.clone(),
+            params,
+        )
+        .expect("shape params should have one increment per knot segment");
+        Box::new(Self {
+            param,
+            ..self.clone()
+        })
+    }
+
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        self.source.extract_raw(analysis)
+    }
+
+    #[expect(clippy::cast_precision_loss)]
+    fn transform(&self, raw: u32) -> f32 {
+        self.param.evaluate(raw as f32)
+    }
+
+    fn normalize(&self, transformed: f32) -> f32 {
+        let norm = transformed.clamp(0.0, 1.0);
+        match self.param.signal {
+            FeatureSignal::Positive => norm,
+            FeatureSignal::Negative => 1.0 - norm,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board_feature::source::NumHoles;
+
+    #[test]
+    fn test_evaluate() {
+        let param = PiecewiseLinearParam::new(
+            FeatureSignal::Positive,
+            vec![0.0, 2.0, 6.0],
+            vec![0.0, 0.5, 0.7],
+        )
+        .unwrap();
+        assert!((param.evaluate(1.0) - 0.25).abs() < 1e-6);
+        assert!((param.evaluate(4.0) - 0.6).abs() < 1e-6);
+        // extrapolation keeps the slope of the outer segments
+        assert!((param.evaluate(10.0) - 0.9).abs() < 1e-6);
+        assert!((param.evaluate(-1.0) - -0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_from_increments() {
+        let param = PiecewiseLinearParam::from_increments(
+            FeatureSignal::Negative,
+            vec![4.0, 0.0, 2.0],
+            &[-1.0, 3.0],
+        )
+        .unwrap();
+        assert_eq!(param.knots(), &[0.0, 2.0, 4.0]);
+        assert_eq!(param.outputs(), &[0.0, 0.25, 1.0]);
+
+        let flat =
+            PiecewiseLinearParam::from_increments(FeatureSignal::Positive, vec![1.0, 1.0], &[0.0])
+                .unwrap();
+        assert_eq!(flat.knots(), &[1.0]);
+        assert_eq!(flat.outputs(), &[0.0]);
+    }
+
+    #[test]
+    fn test_shape_params() {
+        let param = PiecewiseLinearParam::new(
+            FeatureSignal::Negative,
+            vec![0.0, 2.0, 10.0],
+            vec![0.2, 0.6, 0.7],
+        )
+        .unwrap();
+        let feature =
+            PiecewiseLinearTransform::new("holes".to_owned(), "Holes".to_owned(), NumHoles, param);
+        let params = feature.shape_params();
+        assert_eq!(params.len(), 2);
+        assert!((params[0] - 0.4).abs() < 1e-6 && (params[1] - 0.1).abs() < 1e-6);
+
+        // Rebuilt curves keep the knot positions and span [0.0, 1.0]
+        let rebuilt = feature.with_shape_params(&[1.0, 3.0]);
+        let FeatureProcessing::PiecewiseLinear(param) = rebuilt.feature_processing() else {
+            panic!("unexpected processing");
+        };
+        assert_eq!(param.knots(), &[0.0, 2.0, 10.0]);
+        assert_eq!(param.outputs(), &[0.0, 0.25, 1.0]);
+        assert_eq!(rebuilt.shape_params(), vec![0.25, 0.75]);
+    }
+
+    #[test]
+    fn test_invalid_params() {
+        let new = |knots: Vec<f32>, outputs: Vec<f32>| {
+            PiecewiseLinearParam::new(FeatureSignal::Positive, knots, outputs)
+        };
+        assert_eq!(new(vec![], vec![]), Err(KnotParamError::NoKnots));
+        assert_eq!(
+            new(vec![2.0, 1.0], vec![0.0, 1.0]),
+            Err(KnotParamError::UnsortedKnots)
+        );
+        assert_eq!(
+            new(vec![0.0, f32::NAN], vec![0.0, 1.0]),
+            Err(KnotParamError::UnsortedKnots)
+        );
+        assert_eq!(
+            new(vec![0.0, 1.0], vec![1.0, 0.0]),
+            Err(KnotParamError::InvalidOutputs)
+        );
+        assert!(matches!(
+            new(vec![0.0, 1.0], vec![0.0]),
+            Err(KnotParamError::LengthMismatch { .. })
+        ));
+
+        // Model files are checked when loaded
+        let json = r#"{"signal":"positive","knots":[],"outputs":[]}"#;
+        assert!(serde_json::from_str::<PiecewiseLinearParam>(json).is_err());
+        let json = r#"{"signal":"positive","knots":[1.0,0.0],"outputs":[0.0,1.0]}"#;
+        assert!(serde_json::from_str::<PiecewiseLinearParam>(json).is_err());
+        let json = r#"{"signal":"positive","knots":[0.0,1.0],"outputs":[0.0,1.0]}"#;
+        assert!(serde_json::from_str::<PiecewiseLinearParam>(json).is_ok());
+    }
+}
@@ -0,0 +1,297 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::KnotParamError;
+use crate::{
+    board_feature::{BoardFeature, BoardFeatureSource, BoxedBoardFeature, FeatureProcessing},
+    placement_analysis::PlacementAnalysis,
+};
+
+/// Cubic spline feature transformation.
+///
+/// Maps raw values through a natural cubic spline, typically a smoothing spline fitted to
+/// KM median survival per feature value. Compared to [`TableTransform`](super::TableTransform),
+/// noisy estimates for rare values are smoothed out.
+///
+/// # Transform
+///
+/// ```text
+/// transformed = spline(raw)
+/// ```
+///
+/// The spline is stored as its values and second derivatives at each knot. Outside the
+/// knot range it is extended linearly.
+///
+/// # Normalization
+///
+/// Same as [`TableTransform`](super::TableTransform):
+///
+/// ```text
+/// normalized = (transformed - normalize_min) / (normalize_max - normalize_min)
+/// normalized = normalized.clamp(0.0, 1.0)
+/// ```
+///
+/// `normalize_min` / `normalize_max` are the extremes of the curve over the observed
+/// feature range, so values beyond that range saturate at 0.0 or 1.0 rather than
+/// extrapolating the trend.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use oxidris_evaluator::board_feature::{
+///     source::NumHoles,
+///     transform::{SplineTransform, SplineTransformParam},
+/// };
+///
+/// // Linear curve: 100 turns at 0 holes, decreasing by 20 per hole
+/// let param = SplineTransformParam::new(
+///     vec![0.0, 1.0, 2.0],
+///     vec![100.0, 80.0, 60.0],
+///     vec![0.0, 0.0, 0.0],
+///     60.0,
+///     100.0,
+/// )
+/// .unwrap();
+/// let feature = SplineTransform::new(
+///     "num_holes_spline_km".to_owned(),
+///     "Number of Holes (Spline KM)".to_owned(),
+///     NumHoles,
+///     param,
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct SplineTransform<S> {
+    id: String,
+    name: String,
+    source: S,
+    param: SplineTransformParam,
+}
+
+/// Parameters for [`SplineTransform`].
+///
+/// # Invariants
+///
+/// * At least one knot
+/// * `knots` are strictly increasing
+/// * `values` and `second_derivatives` have one finite entry per knot
+/// * `normalize_min` and `normalize_max` are finite
+///
+/// The invariants are also checked on deserialization.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "UncheckedSplineTransformParam")]
+pub struct SplineTransformParam {
+    knots: Vec<f32>,
+    values: Vec<f32>,
+    second_derivatives: Vec<f32>,
+    normalize_min: f32,
+    normalize_max: f32,
+}
+
+#[derive(Deserialize)]
+struct UncheckedSplineTransformParam {
+    knots: Vec<f32>,
+    values: Vec<f32>,
+    second_derivatives: Vec<f32>,
+    normalize_min: f32,
+    normalize_max: f32,
+}
+
+impl<S> SplineTransform<S> {
+    #[must_use]
+    pub fn new(id: String, name: String, source: S, param: SplineTransformParam) -> Self {
+        Self {
+            id,
+            name,
+            source,
+            param,
+        }
+    }
+}
+
+impl SplineTransformParam {
+    /// Creates spline parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the invariants listed on [`SplineTransformParam`] do not hold.
+    pub fn new(
+        knots: Vec<f32>,
+        values: Vec<f32>,
+        second_derivatives: Vec<f32>,
+        normalize_min: f32,
+        normalize_max: f32,
+    ) -> Result<Self, KnotParamError> {
+        KnotParamError::check_knots(&knots)?;
+        KnotParamError::check_len("values", &knots, &values)?;
+        KnotParamError::check_len("second_derivatives", &knots, &second_derivatives)?;
+        for (field, finite) in [
+            ("values", values.iter().all(|v| v.is_finite())),
+            (
+                "second_derivatives",
+                second_derivatives.iter().all(|v| v.is_finite()),
+            ),
+            (
+                "normalize_min/normalize_max",
+                normalize_min.is_finite() && normalize_max.is_finite(),
+            ),
+        ] {
+            if !finite {
+                return Err(KnotParamError::NonFinite { field });
+            }
+        }
+        Ok(Self {
+            knots,
+            values,
+            second_derivatives,
+            normalize_min,
+            normalize_max,
+        })
+    }
+
+    #[must_use]
+    pub fn knots(&self) -> &[f32] {
+        &self.knots
+    }
+
+    #[must_use]
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Evaluates the spline at `x` (linear extrapolation outside the knot range).
+    ///
+    /// [Normalization](BoardFeature::normalize) clamps the result to the observed range.
+    #[must_use]
+    pub fn evaluate(&self, x: f32) -> f32 {
+        let knots = &self.knots;
+        let values = &self.values;
+        let gamma = &self.second_derivatives;
+        let last = knots.len() - 1;
+        if last == 0 {
+            return values[0];
+        }
+        if x <= knots[0] {
+            let width = knots[1] - knots[0];
+            let slope = (values[1] - values[0]) / width - width * gamma[1] / 6.0;
+            return values[0] + slope * (x - knots[0]);
+        }
+        if x >= knots[last] {
+            let width = knots[last] - knots[last - 1];
+            let slope = (values[last] - values[last - 1]) / width + width * gamma[last - 1] / 6.0;
+            return values[last] + slope * (x - knots[last]);
+        }
+        let i = knots.partition_point(|&k| k <= x) - 1;
+        let width = knots[i + 1] - knots[i];
+        let left = x - knots[i];
+        let right = knots[i + 1] - x;
+        (left * values[i + 1] + right * values[i]) / width
+            - left * right / 6.0
+                * ((1.0 + left / width) * gamma[i + 1] + (1.0 + right / width) * gamma[i])
+    }
+}
+
+impl TryFrom<UncheckedSplineTransformParam> for SplineTransformParam {
+    type Error = KnotParamError;
+
+    fn try_from(param: UncheckedSplineTransformParam) -> Result<Self, Self::Error> {
+        Self::new(
+            param.knots,
+            param.values,
+            param.second_derivatives,
+            param.normalize_min,
+            param.normalize_max,
+        )
+    }
+}
+
+impl<S> BoardFeature for SplineTransform<S>
+where
+    S: BoardFeatureSource + Clone + fmt::Debug + Send + Sync + 'static,
+{
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn feature_source(&self) -> &dyn BoardFeatureSource {
+        &self.source
+    }
+
+    fn feature_processing(&self) -> FeatureProcessing {
+        FeatureProcessing::Spline(self.param.clone())
+    }
+
+    fn clone_boxed(&self) -> BoxedBoardFeature {
+        Box::new(self.clone())
+    }
+
+    fn extract_raw(&self, analysis: &PlacementAnalysis) -> u32 {
+        self.source.extract_raw(analysis)
+    }
+
+    #[expect(clippy::cast_precision_loss)]
+    fn transform(&self, raw: u32) -> f32 {
+        self.param.evaluate(raw as f32)
+    }
+
+    fn normalize(&self, transformed: f32) -> f32 {
+        let range = self.param.normalize_max - self.param.normalize_min;
+        if range.abs() < f32::EPSILON {
+            return 0.5;
+        }
+        ((transformed - self.param.normalize_min) / range).clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate() {
+        // Interpolating spline through (0, 0), (1, 1), (2, 0) has γ₁ = -3 (natural ends)
+        let param = SplineTransformParam::new(
+            vec![0.0, 1.0, 2.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, -3.0, 0.0],
+            0.0,
+            1.0,
+        )
+        .unwrap();
+        assert!((param.evaluate(1.0) - 1.0).abs() < 1e-6);
+        // symmetric around the middle knot
+        assert!((param.evaluate(0.5) - param.evaluate(1.5)).abs() < 1e-6);
+        assert!((param.evaluate(0.5) - 0.6875).abs() < 1e-6);
+        // linear extrapolation with end slopes ±1.5
+        assert!((param.evaluate(3.0) - -1.5).abs() < 1e-6);
+        assert!((param.evaluate(-1.0) - -1.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_invalid_params() {
+        assert_eq!(
+            SplineTransformParam::new(vec![], vec![], vec![], 0.0, 1.0),
+            Err(KnotParamError::NoKnots)
+        );
+        assert_eq!(
+            SplineTransformParam::new(vec![1.0, 0.0], vec![0.0; 2], vec![0.0; 2], 0.0, 1.0),
+            Err(KnotParamError::UnsortedKnots)
+        );
+        assert!(matches!(
+            SplineTransformParam::new(vec![0.0, 1.0], vec![0.0], vec![0.0; 2], 0.0, 1.0),
+            Err(KnotParamError::LengthMismatch { .. })
+        ));
+        assert!(matches!(
+            SplineTransformParam::new(vec![0.0, 1.0], vec![0.0, f32::NAN], vec![0.0; 2], 0.0, 1.0),
+            Err(KnotParamError::NonFinite { .. })
+        ));
+
+        // Model files are checked when loaded
+        let json = r#"{"knots":[],"values":[],"second_derivatives":[],"normalize_min":0.0,"normalize_max":1.0}"#;
+        assert!(serde_json::from_str::<SplineTransformParam>(json).is_err());
+    }
+}
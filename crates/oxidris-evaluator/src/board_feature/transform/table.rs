@@ -13,6 +13,16 @@
 //! 2. **Transform**: Look up pre-computed value in table (e.g., KM median survival)
 //! 3. **Normalize**: Scale to [0, 1] range using pre-computed min/max
 //!
+//! # Survival Metrics
+//!
+//! The KM median is undefined for feature values whose survival curve never drops
+//! below 0.5 (e.g., heavily censored good states). Tables can therefore hold other
+//! per-value summaries of the KM curve, recorded as [`SurvivalMetric`]:
+//!
+//! - [`SurvivalMetric::MedianSurvival`]: KM median survival time (default)
+//! - [`SurvivalMetric::SurvivalProbability`]: Probability of surviving up to a horizon
+//! - [`SurvivalMetric::RestrictedMeanSurvival`]: Mean survival time capped at a horizon
+//!
 //! # Example
 //!
 //! ```no_run
@@ -131,6 +141,32 @@ pub struct TableTransformParam {
     normalize_max: f32,
     /// Lookup table mapping feature values to transformed values
     table: Vec<f32>,
+    /// Survival metric stored in the table
+    #[serde(default, skip_serializing_if = "SurvivalMetric::is_median_survival")]
+    metric: SurvivalMetric,
+}
+
+/// Survival metric stored in a [`TableTransformParam`] table
+///
+/// The metric does not affect evaluation (the table is always a plain lookup), but
+/// records how the table was computed so that saved models are self-describing.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SurvivalMetric {
+    /// KM median survival time (turns)
+    #[default]
+    MedianSurvival,
+    /// KM survival probability `S(horizon)`
+    SurvivalProbability { horizon: usize },
+    /// KM restricted mean survival time up to `horizon` (turns)
+    RestrictedMeanSurvival { horizon: usize },
+}
+
+impl SurvivalMetric {
+    #[must_use]
+    pub fn is_median_survival(&self) -> bool {
+        matches!(self, Self::MedianSurvival)
+    }
 }
 
 impl<S> TableTransform<S> {
@@ -210,8 +246,23 @@ impl TableTransformParam {
             normalize_min,
             normalize_max,
             table,
+            metric: SurvivalMetric::default(),
         }
     }
+
+    /// Set the survival metric stored in the table
+    ///
+    /// Defaults to [`SurvivalMetric::MedianSurvival`].
+    #[must_use]
+    pub fn with_metric(mut self, metric: SurvivalMetric) -> Self {
+        self.metric = metric;
+        self
+    }
+
+    #[must_use]
+    pub fn metric(&self) -> SurvivalMetric {
+        self.metric
+    }
 }
 
 impl<S> BoardFeature for TableTransform<S>
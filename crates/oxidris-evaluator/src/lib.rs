@@ -48,7 +48,9 @@
 //! ## Linear Evaluation Model
 //!
 //! Placement scores are computed as weighted sums of normalized features. This is simple,
-//! interpretable, and fast, but cannot capture feature interactions (non-linear relationships).
+//! interpretable, and fast, but cannot capture feature interactions (non-linear relationships)
+//! on its own. Pairwise interactions can be added explicitly as composite features
+//! (see [`InteractionFeature`](board_feature::transform::InteractionFeature)).
 //!
 //! # Example: Using the Evaluator
 //!
@@ -77,7 +79,8 @@
 //!   doesn't capture non-linear relationships between feature values and survival time.
 //! - **Feature redundancy**: Some survival features are duplicated with different normalization
 //!   ranges to approximate non-linearity (ad-hoc solution).
-//! - **No feature interactions**: Linear weighted sum cannot capture interactions between features.
+//! - **Limited feature interactions**: Only explicitly defined pairwise interactions are
+//!   captured; the weighted sum cannot discover interactions between features by itself.
 //!
 //! See the project documentation for ongoing improvements and design discussions.
 
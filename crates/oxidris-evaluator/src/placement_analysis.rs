@@ -5,13 +5,20 @@
 //!
 //! # Design
 //!
-//! `PlacementAnalysis` combines two pieces of information:
+//! `PlacementAnalysis` combines the following pieces of information:
 //!
 //! 1. **Line clears** - How many lines were cleared by this placement
-//! 2. **Board state** - The resulting board metrics via [`BoardAnalysis`]
+//! 2. **Placed piece** - Where the piece landed and how many of its cells were cleared
+//!    (landing height and eroded piece cells, as used by Dellacherie's evaluator), and
+//!    whether it is a T-spin
+//! 3. **Board state** - The resulting board metrics via [`BoardAnalysis`]
+//! 4. **Stack height before placement** - Shared by all candidate placements of a turn,
+//!    used to switch strategies by game phase
+//! 5. **Piece queue** (optional) - The held piece and the upcoming pieces after the turn,
+//!    attached with [`PlacementAnalysis::with_queue()`] when the game state is known
 //!
 //! This unified analysis is used by board features to extract both placement-specific
-//! information (lines cleared) and board-state information (holes, height, etc.).
+//! information (lines cleared, landing height) and board-state information (holes, height, etc.).
 //!
 //! # Usage
 //!
@@ -26,8 +33,34 @@
 //!
 //! The `BoardAnalysis` inside provides lazy-evaluated metrics, making it efficient
 //! even when only a subset of metrics are needed.
+//!
+//! When many placements are analyzed on the same board (all candidates of a turn, or the
+//! nodes of a lookahead search), [`PlacementAnalysis::from_parent()`] derives the base
+//! metrics from the analysis of the board before placement instead of rescanning the board:
+//!
+//! ```rust,ignore
+//! let parent = BoardAnalysis::from_board(&board);
+//! for placement in candidates {
+//!     let analysis = PlacementAnalysis::from_parent(&parent, placement);
+//!     // The resulting board can be the parent of the next search depth
+//!     let child = analysis.board_analysis();
+//! }
+//! ```
+//!
+//! Hold- and queue-aware features need the [`PieceQueue`] after the turn. It is built from
+//! the [`GameField`] the turn is planned on:
+//!
+//! ```rust,ignore
+//! let queue = PieceQueue::after_turn(&field, turn.use_hold());
+//! let analysis = PlacementAnalysis::from_board(field.board(), placement).with_queue(queue);
+//! ```
+//!
+//! Analyses without a queue (e.g., boards recorded before queues were saved) report no held
+//! piece and no upcoming pieces.
 
-use oxidris_engine::{BitBoard, Piece};
+use arrayvec::ArrayVec;
+use oxidris_engine::{BitBoard, GameField, Piece, PieceKind};
+use serde::{Deserialize, Serialize};
 
 use crate::board_analysis::BoardAnalysis;
 
@@ -35,23 +68,81 @@ use crate::board_analysis::BoardAnalysis;
 pub struct PlacementAnalysis {
     placement: Piece,
     cleared_lines: usize,
+    landing_height: u8,
+    eroded_piece_cells: u8,
+    t_spin: bool,
+    max_height_before: u8,
+    queue: Option<PieceQueue>,
     board_analysis: BoardAnalysis,
 }
 
+/// Held piece and upcoming pieces after a turn.
+///
+/// This is the piece context the next turns are played with: `next[0]` is the piece that
+/// spawns after the placement, and `held` is the piece available by hold.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PieceQueue {
+    held: Option<PieceKind>,
+    next: ArrayVec<PieceKind, { PieceQueue::PREVIEW_LEN }>,
+}
+
 impl PlacementAnalysis {
     #[must_use]
     pub fn from_board(before_placement: &BitBoard, placement: Piece) -> Self {
+        let max_height_before = max_height(before_placement);
         let mut board = before_placement.clone();
         board.fill_piece(placement);
+        let landing_height = landing_height(placement);
+        let eroded_piece_cells = eroded_piece_cells(&board, placement);
+        let t_spin = is_t_spin(&board, placement);
         let cleared_lines = board.clear_lines();
 
         Self {
             placement,
             cleared_lines,
+            landing_height,
+            eroded_piece_cells,
+            t_spin,
+            max_height_before,
+            queue: None,
             board_analysis: BoardAnalysis::from_board(&board),
         }
     }
 
+    /// Analyzes a placement on the board of `parent`, updating its metrics incrementally.
+    ///
+    /// Produces the same analysis as [`from_board`](Self::from_board), but only the columns
+    /// and rows touched by the piece are rescanned for the base metrics. Metrics of
+    /// `parent` are computed (and cached) on first use, so reusing the same parent for
+    /// many placements pays off.
+    #[must_use]
+    pub fn from_parent(parent: &BoardAnalysis, placement: Piece) -> Self {
+        let mut board = parent.board().clone();
+        board.fill_piece(placement);
+        let landing_height = landing_height(placement);
+        let eroded_piece_cells = eroded_piece_cells(&board, placement);
+        let t_spin = is_t_spin(&board, placement);
+        let cleared_lines = board.clear_lines();
+
+        Self {
+            placement,
+            cleared_lines,
+            landing_height,
+            eroded_piece_cells,
+            t_spin,
+            max_height_before: parent.max_height(),
+            queue: None,
+            board_analysis: BoardAnalysis::from_parent(parent, board, placement, cleared_lines),
+        }
+    }
+
+    /// Attaches the piece queue after the turn.
+    #[must_use]
+    pub fn with_queue(mut self, queue: PieceQueue) -> Self {
+        self.queue = Some(queue);
+        self
+    }
+
     #[must_use]
     pub fn placement(&self) -> &Piece {
         &self.placement
@@ -62,8 +153,332 @@ impl PlacementAnalysis {
         self.cleared_lines
     }
 
+    /// Returns the height at which the piece landed.
+    ///
+    /// Measured as the middle of the rows occupied by the piece, counted from the floor
+    /// (the bottom row has height 1) and rounded down.
+    #[must_use]
+    pub fn landing_height(&self) -> u8 {
+        self.landing_height
+    }
+
+    /// Returns the number of cleared lines multiplied by the number of cells of the
+    /// placed piece that were removed by the clear.
+    #[must_use]
+    pub fn eroded_piece_cells(&self) -> u8 {
+        self.eroded_piece_cells
+    }
+
+    /// Returns whether the placement is a T-spin by the 3-corner rule.
+    ///
+    /// The placed piece is a T and at least three of the four diagonal corners around its
+    /// center are occupied (walls and floor count as occupied). Since placements are not
+    /// tracked back to the moves reaching them, the last move counts as a rotation only if a
+    /// straight drop cannot reach the position, i.e., the stack covers a cell of the piece.
+    #[must_use]
+    pub fn is_t_spin(&self) -> bool {
+        self.t_spin
+    }
+
+    /// Returns the maximum column height of the board before the placement.
+    ///
+    /// Unlike [`BoardAnalysis::max_height()`], this is the same for all candidate
+    /// placements of a turn.
+    #[must_use]
+    pub fn max_height_before(&self) -> u8 {
+        self.max_height_before
+    }
+
+    /// Returns the piece queue after the turn, if attached.
+    #[must_use]
+    pub fn queue(&self) -> Option<&PieceQueue> {
+        self.queue.as_ref()
+    }
+
     #[must_use]
     pub fn board_analysis(&self) -> &BoardAnalysis {
         &self.board_analysis
     }
 }
+
+impl PieceQueue {
+    /// Number of upcoming pieces kept in the queue.
+    pub const PREVIEW_LEN: usize = 5;
+
+    /// Creates a queue from the held piece and the upcoming pieces.
+    ///
+    /// Pieces beyond [`PREVIEW_LEN`](Self::PREVIEW_LEN) are dropped.
+    #[must_use]
+    pub fn new(held: Option<PieceKind>, next: impl IntoIterator<Item = PieceKind>) -> Self {
+        Self {
+            held,
+            next: next.into_iter().take(Self::PREVIEW_LEN).collect(),
+        }
+    }
+
+    /// Returns the queue after a turn is played on `field`.
+    ///
+    /// With `use_hold`, the falling piece goes to hold. If nothing was held before, the
+    /// first upcoming piece is consumed as the piece to place.
+    #[must_use]
+    pub fn after_turn(field: &GameField, use_hold: bool) -> Self {
+        if !use_hold {
+            return Self::new(field.held_piece(), field.next_pieces());
+        }
+        let consumed = usize::from(field.held_piece().is_none());
+        Self::new(
+            Some(field.falling_piece().kind()),
+            field.next_pieces().skip(consumed),
+        )
+    }
+
+    #[must_use]
+    pub fn held(&self) -> Option<PieceKind> {
+        self.held
+    }
+
+    #[must_use]
+    pub fn next(&self) -> &[PieceKind] {
+        &self.next
+    }
+}
+
+fn max_height(board: &BitBoard) -> u8 {
+    let top = board
+        .playable_rows()
+        .position(|row| row.playable_cells().any(|occupied| occupied))
+        .unwrap_or(BitBoard::PLAYABLE_HEIGHT);
+    u8::try_from(BitBoard::PLAYABLE_HEIGHT - top).unwrap()
+}
+
+/// Converts an internal row coordinate into a height counted from the floor (bottom row = 1).
+fn row_height(y: usize) -> usize {
+    BitBoard::PLAYABLE_Y_RANGE.end - y
+}
+
+fn landing_height(placement: Piece) -> u8 {
+    let (bottom, top) = placement
+        .occupied_positions()
+        .map(|(_x, y)| row_height(y))
+        .fold((usize::MAX, 0), |(bottom, top), h| {
+            (usize::min(bottom, h), usize::max(top, h))
+        });
+    u8::try_from(usize::midpoint(bottom, top)).unwrap()
+}
+
+/// Computes eroded piece cells on the board after the piece is filled but before lines are cleared.
+fn eroded_piece_cells(board: &BitBoard, placement: Piece) -> u8 {
+    let is_filled_row = |y: usize| {
+        BitBoard::PLAYABLE_Y_RANGE.contains(&y)
+            && board
+                .playable_row(y - BitBoard::PLAYABLE_Y_RANGE.start)
+                .is_playable_filled()
+    };
+
+    let piece_cells = placement
+        .occupied_positions()
+        .filter(|(_x, y)| is_filled_row(*y))
+        .count();
+    let cleared_lines = BitBoard::PLAYABLE_Y_RANGE
+        .filter(|y| is_filled_row(*y))
+        .count();
+    u8::try_from(cleared_lines * piece_cells).unwrap()
+}
+
+fn is_t_spin(board: &BitBoard, placement: Piece) -> bool {
+    if placement.kind() != PieceKind::T {
+        return false;
+    }
+    let cells = placement.occupied_positions().collect::<ArrayVec<_, 4>>();
+    // The center of a T is the cell adjacent to the three other cells
+    let Some(&(cx, cy)) = cells.iter().find(|(x, y)| {
+        cells
+            .iter()
+            .filter(|(ox, oy)| x.abs_diff(*ox) + y.abs_diff(*oy) == 1)
+            .count()
+            == 3
+    }) else {
+        return false;
+    };
+    let is_occupied = |x: usize, y: usize| {
+        if !BitBoard::PLAYABLE_X_RANGE.contains(&x) || y >= BitBoard::PLAYABLE_Y_RANGE.end {
+            return true;
+        }
+        BitBoard::PLAYABLE_Y_RANGE.contains(&y)
+            && board
+                .playable_row(y - BitBoard::PLAYABLE_Y_RANGE.start)
+                .is_cell_occupied(x)
+    };
+    // A straight drop reaches the position unless the stack covers the piece, and such a
+    // drop into a corner (e.g., against the wall) is not a spin
+    let covered = cells.iter().any(|&(x, y)| {
+        (BitBoard::PLAYABLE_Y_RANGE.start..y)
+            .any(|above| !cells.contains(&(x, above)) && is_occupied(x, above))
+    });
+    if !covered {
+        return false;
+    }
+    [
+        (cx - 1, cy - 1),
+        (cx + 1, cy - 1),
+        (cx - 1, cy + 1),
+        (cx + 1, cy + 1),
+    ]
+    .into_iter()
+    .filter(|(x, y)| is_occupied(*x, *y))
+    .count()
+        >= 3
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{Rng as _, SeedableRng as _, rngs::StdRng};
+
+    use super::*;
+
+    fn dropped(board: &BitBoard, piece: Piece) -> Piece {
+        piece.simulate_drop_position(board)
+    }
+
+    #[test]
+    fn test_landing_height_on_empty_board() {
+        let board = BitBoard::INITIAL;
+
+        // Horizontal I piece lies on the bottom row
+        let piece = dropped(&board, Piece::new(PieceKind::I));
+        let analysis = PlacementAnalysis::from_board(&board, piece);
+        assert_eq!(analysis.landing_height(), 1);
+        assert_eq!(analysis.max_height_before(), 0);
+
+        // Vertical I piece spans rows 1..=4
+        let piece = dropped(&board, Piece::new(PieceKind::I).rotated_right());
+        let analysis = PlacementAnalysis::from_board(&board, piece);
+        assert_eq!(analysis.landing_height(), 2);
+    }
+
+    #[test]
+    fn test_eroded_piece_cells() {
+        let board = BitBoard::from_ascii(
+            "
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ..........
+            ####.#####
+            ####.#####
+            ####.#####
+            ####.#####
+            ",
+        );
+        let piece = shift_to_column(Piece::new(PieceKind::I).rotated_right(), 4);
+        let piece = dropped(&board, piece);
+        let analysis = PlacementAnalysis::from_board(&board, piece);
+        assert_eq!(analysis.cleared_lines(), 4);
+        assert_eq!(analysis.eroded_piece_cells(), 16);
+        assert_eq!(analysis.landing_height(), 2);
+        // Height before placement is kept even though all lines were cleared
+        assert_eq!(analysis.max_height_before(), 4);
+        assert_eq!(analysis.board_analysis().max_height(), 0);
+
+        // No line clear means no eroded cells
+        let piece = dropped(&board, Piece::new(PieceKind::O));
+        let analysis = PlacementAnalysis::from_board(&board, piece);
+        assert_eq!(analysis.cleared_lines(), 0);
+        assert_eq!(analysis.eroded_piece_cells(), 0);
+        assert_eq!(analysis.landing_height(), 5);
+    }
+
+    #[test]
+    fn test_t_spin() {
+        let mut rows = vec![".........."; BitBoard::PLAYABLE_HEIGHT - 1];
+        rows.push(".#########");
+        let board = BitBoard::from_ascii(&rows.join("\n"));
+
+        // Flat T on the floor: two corners below, none above
+        let piece = dropped(&board, shift_to_column(Piece::new(PieceKind::T), 4));
+        assert!(!PlacementAnalysis::from_board(&board, piece).is_t_spin());
+
+        // Vertical T dropped against the wall with its stem over the filled cell: three
+        // corners are occupied, but a plain drop reaches it
+        let piece = dropped(
+            &board,
+            shift_to_column(Piece::new(PieceKind::T).rotated_right(), 0),
+        );
+        let analysis = PlacementAnalysis::from_board(&board, piece);
+        assert!(!analysis.is_t_spin());
+        assert_eq!(analysis.cleared_lines(), 1);
+
+        // Same placement under an overhang, which only a rotation can reach
+        let mut covered_rows = rows.clone();
+        covered_rows[BitBoard::PLAYABLE_HEIGHT - 4] = "##........";
+        let covered_board = BitBoard::from_ascii(&covered_rows.join("\n"));
+        let analysis = PlacementAnalysis::from_board(&covered_board, piece);
+        assert!(analysis.is_t_spin());
+        assert_eq!(analysis.cleared_lines(), 1);
+
+        // Same placement without the filled corner
+        let piece = dropped(
+            &BitBoard::INITIAL,
+            shift_to_column(Piece::new(PieceKind::T).rotated_right(), 0),
+        );
+        assert!(!PlacementAnalysis::from_board(&BitBoard::INITIAL, piece).is_t_spin());
+
+        // Other pieces are never T-spins
+        let piece = dropped(
+            &board,
+            shift_to_column(Piece::new(PieceKind::J).rotated_right(), 0),
+        );
+        assert!(!PlacementAnalysis::from_board(&board, piece).is_t_spin());
+    }
+
+    #[test]
+    fn test_piece_queue_after_turn() {
+        let mut field = GameField::with_seed(StdRng::seed_from_u64(0).random());
+        let current = field.falling_piece().kind();
+        let next = field.next_pieces().collect::<Vec<_>>();
+
+        let queue = PieceQueue::after_turn(&field, false);
+        assert_eq!(queue.held(), None);
+        assert_eq!(queue.next(), &next[..PieceQueue::PREVIEW_LEN]);
+
+        // Nothing held yet: the first upcoming piece is placed instead
+        let queue = PieceQueue::after_turn(&field, true);
+        assert_eq!(queue.held(), Some(current));
+        assert_eq!(queue.next(), &next[1..=PieceQueue::PREVIEW_LEN]);
+
+        // Swapping with the held piece keeps the upcoming pieces
+        field.try_hold().unwrap();
+        let current = field.falling_piece().kind();
+        let next = field.next_pieces().collect::<Vec<_>>();
+        let queue = PieceQueue::after_turn(&field, true);
+        assert_eq!(queue.held(), Some(current));
+        assert_eq!(queue.next(), &next[..PieceQueue::PREVIEW_LEN]);
+    }
+
+    /// Moves the piece horizontally so that its leftmost cell is in the given playable column.
+    fn shift_to_column(mut piece: Piece, column: usize) -> Piece {
+        let leftmost = |piece: Piece| {
+            piece.occupied_positions().map(|(x, _y)| x).min().unwrap()
+                - BitBoard::PLAYABLE_X_RANGE.start
+        };
+        while leftmost(piece) > column {
+            piece = piece.left().unwrap();
+        }
+        while leftmost(piece) < column {
+            piece = piece.right().unwrap();
+        }
+        piece
+    }
+}
@@ -0,0 +1,154 @@
+use std::iter;
+
+use crate::{board_feature::BoxedBoardFeature, placement_analysis::PlacementAnalysis};
+
+/// Normalized feature values of a batch of placements in struct-of-arrays layout.
+///
+/// Values are stored feature by feature: the values of one feature for all placements are
+/// contiguous. A weighted sum over features then becomes one multiply-add pass per feature
+/// over contiguous memory, which the compiler can vectorize:
+///
+/// ```text
+/// scores[i] = Σ_f weights[f] × values[f][i]
+/// ```
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use oxidris_evaluator::placement_evaluator::FeatureBatch;
+/// # let features: Vec<oxidris_evaluator::board_feature::BoxedBoardFeature> = todo!();
+/// # let analyses: Vec<oxidris_evaluator::placement_analysis::PlacementAnalysis> = todo!();
+/// # let weights: Vec<f32> = todo!();
+///
+/// let batch = FeatureBatch::from_analyses(&features, &analyses);
+/// let mut scores = vec![0.0; analyses.len()];
+/// batch.accumulate_weighted_sum(&weights, &mut scores);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FeatureBatch {
+    num_placements: usize,
+    values: Vec<f32>,
+}
+
+impl FeatureBatch {
+    /// Computes the normalized value of each feature for each placement.
+    #[must_use]
+    pub fn from_analyses(features: &[BoxedBoardFeature], analyses: &[PlacementAnalysis]) -> Self {
+        let values = features
+            .iter()
+            .flat_map(|f| {
+                analyses
+                    .iter()
+                    .map(|a| f.compute_feature_value(a).normalized)
+            })
+            .collect();
+        Self {
+            num_placements: analyses.len(),
+            values,
+        }
+    }
+
+    #[must_use]
+    pub fn num_placements(&self) -> usize {
+        self.num_placements
+    }
+
+    #[must_use]
+    pub fn num_features(&self) -> usize {
+        self.values
+            .len()
+            .checked_div(self.num_placements)
+            .unwrap_or(0)
+    }
+
+    /// Returns the values of the given feature for all placements.
+    #[must_use]
+    pub fn feature_values(&self, feature: usize) -> &[f32] {
+        let start = feature * self.num_placements;
+        &self.values[start..start + self.num_placements]
+    }
+
+    /// Adds `Σ_f weights[f] × values[f][i]` to `scores[i]` for each placement `i`.
+    ///
+    /// Features are accumulated in order, so the result is the same as summing the weighted
+    /// values of a single placement feature by feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scores.len()` differs from the number of placements, or if `weights`
+    /// has fewer entries than there are features.
+    pub fn accumulate_weighted_sum(&self, weights: &[f32], scores: &mut [f32]) {
+        assert_eq!(scores.len(), self.num_placements);
+        if self.num_placements == 0 {
+            return;
+        }
+        assert!(weights.len() >= self.num_features());
+        for (values, weight) in iter::zip(self.values.chunks_exact(self.num_placements), weights) {
+            for (score, value) in iter::zip(&mut *scores, values) {
+                *score += value * weight;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use oxidris_engine::{BitBoard, Piece, PieceKind};
+
+    use super::*;
+    use crate::{
+        board_feature::{
+            FeatureSignal,
+            source::{MaxHeight, NumHoles, RowTransitions},
+            transform::{RawTransform, RawTransformParam},
+        },
+        placement_evaluator::{FeatureBasedPlacementEvaluator, PlacementEvaluator},
+    };
+
+    fn raw_feature<S>(source: S, signal: FeatureSignal, max: f32) -> BoxedBoardFeature
+    where
+        S: crate::board_feature::BoardFeatureSource + Clone + 'static,
+    {
+        let param = RawTransformParam::new(signal, 0.0, max);
+        Box::new(RawTransform::new(
+            String::new(),
+            String::new(),
+            source,
+            param,
+        ))
+    }
+
+    #[test]
+    fn test_batched_scores_match_single_evaluation() {
+        let board = BitBoard::INITIAL;
+        let analyses = PieceKind::ALL
+            .into_iter()
+            .flat_map(|kind| {
+                iter::successors(Some(Piece::new(kind)), |p| {
+                    p.left().filter(|m| !board.is_colliding(*m))
+                })
+            })
+            .map(|p| PlacementAnalysis::from_board(&board, p.simulate_drop_position(&board)))
+            .collect::<Vec<_>>();
+        let features = vec![
+            raw_feature(NumHoles, FeatureSignal::Negative, 4.0),
+            raw_feature(MaxHeight, FeatureSignal::Negative, 20.0),
+            raw_feature(RowTransitions, FeatureSignal::Negative, 40.0),
+        ];
+
+        let batch = FeatureBatch::from_analyses(&features, &analyses);
+        assert_eq!(batch.num_placements(), analyses.len());
+        assert_eq!(batch.num_features(), 3);
+        for (i, analysis) in analyses.iter().enumerate() {
+            let expected = features[1].compute_feature_value(analysis).normalized;
+            assert!((batch.feature_values(1)[i] - expected).abs() < f32::EPSILON);
+        }
+
+        let evaluator = FeatureBasedPlacementEvaluator::new(features, vec![0.5, -1.5, 2.0]);
+        let mut scores = vec![f32::NAN; analyses.len()];
+        evaluator.evaluate_placements(&analyses, &mut scores);
+        for (analysis, score) in iter::zip(&analyses, &scores) {
+            assert!((evaluator.evaluate_placement(analysis) - score).abs() < f32::EPSILON);
+        }
+    }
+}
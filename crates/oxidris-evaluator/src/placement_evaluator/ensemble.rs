@@ -0,0 +1,383 @@
+use std::iter;
+
+use serde::{Deserialize, Serialize};
+
+use super::{HeightGate, PlacementEvaluator};
+use crate::placement_analysis::PlacementAnalysis;
+
+/// Placement evaluator combining the scores of several member evaluators.
+///
+/// Members are typically trained models with different play styles (e.g., `aggro-km` and
+/// `defensive-km`). Their raw scores are not comparable, so the [`EnsembleRule`] combines
+/// them on a common scale:
+///
+/// - [`Mean`](EnsembleRule::Mean): weighted average of the member scores, each min-max
+///   normalized over the candidates of the batch
+/// - [`RankVote`](EnsembleRule::RankVote): weighted Borda count, where each member votes
+///   with the normalized rank of each candidate
+/// - [`Phased`](EnsembleRule::Phased): a [`HeightGate`] picks one member per turn
+///
+/// The first two rules score a placement relative to the other placements of the batch
+/// (see [`PlacementEvaluator::is_batch_relative`]). Without other candidates,
+/// [`evaluate_placement`](PlacementEvaluator::evaluate_placement) falls back to the
+/// weighted average of the raw member scores.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use oxidris_evaluator::placement_evaluator::{EnsemblePlacementEvaluator, EnsembleRule};
+/// # let (aggro, defensive): (
+/// #     Box<dyn oxidris_evaluator::placement_evaluator::PlacementEvaluator>,
+/// #     Box<dyn oxidris_evaluator::placement_evaluator::PlacementEvaluator>,
+/// # ) = todo!();
+///
+/// let evaluator =
+///     EnsemblePlacementEvaluator::new(vec![aggro, defensive], vec![2.0, 1.0], EnsembleRule::Mean)
+///         .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct EnsemblePlacementEvaluator<'a> {
+    members: Vec<Box<dyn PlacementEvaluator + 'a>>,
+    weights: Vec<f32>,
+    rule: EnsembleRule,
+}
+
+/// How an [`EnsemblePlacementEvaluator`] combines the scores of its members.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EnsembleRule {
+    /// Weighted average of the min-max normalized member scores
+    #[default]
+    Mean,
+    /// Weighted average of the normalized member ranks (Borda count)
+    RankVote,
+    /// Member `i` scores the turns in phase `i` of the gate (member weights are unused)
+    Phased { gate: HeightGate },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+pub enum EnsembleError {
+    #[display("ensemble has no members")]
+    NoMembers,
+    #[display("ensemble has {expected} members, but {actual} weights are given")]
+    WeightCount { expected: usize, actual: usize },
+    #[display("gate has {expected} phases, but the ensemble has {actual} members")]
+    PhaseCount { expected: usize, actual: usize },
+}
+
+impl<'a> EnsemblePlacementEvaluator<'a> {
+    /// Creates a new ensemble evaluator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no members, if there is not one weight per member, or
+    /// if a [`Phased`](EnsembleRule::Phased) gate does not have one phase per member.
+    pub fn new(
+        members: Vec<Box<dyn PlacementEvaluator + 'a>>,
+        weights: Vec<f32>,
+        rule: EnsembleRule,
+    ) -> Result<Self, EnsembleError> {
+        if members.is_empty() {
+            return Err(EnsembleError::NoMembers);
+        }
+        if weights.len() != members.len() {
+            return Err(EnsembleError::WeightCount {
+                expected: members.len(),
+                actual: weights.len(),
+            });
+        }
+        if let EnsembleRule::Phased { gate } = &rule
+            && gate.num_phases() != members.len()
+        {
+            return Err(EnsembleError::PhaseCount {
+                expected: gate.num_phases(),
+                actual: members.len(),
+            });
+        }
+        Ok(Self {
+            members,
+            weights,
+            rule,
+        })
+    }
+
+    #[must_use]
+    pub fn rule(&self) -> &EnsembleRule {
+        &self.rule
+    }
+
+    #[must_use]
+    pub fn weights(&self) -> &[f32] {
+        &self.weights
+    }
+
+    /// Accumulates `weight × normalize(member scores)` of each member into `scores`.
+    fn combine_normalized(
+        &self,
+        analyses: &[PlacementAnalysis],
+        scores: &mut [f32],
+        normalize: fn(&mut [f32]),
+    ) {
+        let total_weight = self.weights.iter().sum::<f32>();
+        let mut member_scores = vec![0.0; analyses.len()];
+        scores.fill(0.0);
+        for (member, weight) in iter::zip(&self.members, &self.weights) {
+            member.evaluate_placements(analyses, &mut member_scores);
+            normalize(&mut member_scores);
+            for (score, member_score) in iter::zip(&mut *scores, &member_scores) {
+                *score += weight * member_score;
+            }
+        }
+        if total_weight != 0.0 {
+            for score in scores {
+                *score /= total_weight;
+            }
+        }
+    }
+}
+
+impl PlacementEvaluator for EnsemblePlacementEvaluator<'_> {
+    fn evaluate_placement(&self, analysis: &PlacementAnalysis) -> f32 {
+        match &self.rule {
+            EnsembleRule::Mean | EnsembleRule::RankVote => {
+                let total_weight = self.weights.iter().sum::<f32>();
+                let sum = iter::zip(&self.members, &self.weights)
+                    .map(|(member, weight)| weight * member.evaluate_placement(analysis))
+                    .sum::<f32>();
+                if total_weight == 0.0 {
+                    sum
+                } else {
+                    sum / total_weight
+                }
+            }
+            EnsembleRule::Phased { gate } => {
+                self.members[gate.phase(analysis.max_height_before())].evaluate_placement(analysis)
+            }
+        }
+    }
+
+    fn evaluate_placements(&self, analyses: &[PlacementAnalysis], scores: &mut [f32]) {
+        assert_eq!(analyses.len(), scores.len());
+        match &self.rule {
+            EnsembleRule::Mean => self.combine_normalized(analyses, scores, min_max_normalize),
+            EnsembleRule::RankVote => self.combine_normalized(analyses, scores, rank_normalize),
+            EnsembleRule::Phased { gate } => {
+                let Some(first) = analyses.first() else {
+                    return;
+                };
+                // Candidates of a turn share the board before placement, and hence the phase
+                let phase = gate.phase(first.max_height_before());
+                if analyses[1..]
+                    .iter()
+                    .all(|a| gate.phase(a.max_height_before()) == phase)
+                {
+                    self.members[phase].evaluate_placements(analyses, scores);
+                } else {
+                    for (analysis, score) in iter::zip(analyses, scores) {
+                        *score = self.evaluate_placement(analysis);
+                    }
+                }
+            }
+        }
+    }
+
+    fn is_batch_relative(&self) -> bool {
+        match &self.rule {
+            EnsembleRule::Mean | EnsembleRule::RankVote => true,
+            EnsembleRule::Phased { .. } => self.members.iter().any(|m| m.is_batch_relative()),
+        }
+    }
+}
+
+/// Maps scores linearly to \[0.0, 1.0\]; all-equal scores map to 0.0.
+fn min_max_normalize(scores: &mut [f32]) {
+    let min = scores.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = scores.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let range = max - min;
+    for score in scores {
+        *score = if range > 0.0 {
+            (*score - min) / range
+        } else {
+            0.0
+        };
+    }
+}
+
+/// Replaces scores by their rank scaled to \[0.0, 1.0\] (1.0 for the best); ties share
+/// their average rank.
+#[expect(clippy::cast_precision_loss)]
+fn rank_normalize(scores: &mut [f32]) {
+    let n = scores.len();
+    if n <= 1 {
+        scores.fill(0.0);
+        return;
+    }
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| scores[a].total_cmp(&scores[b]));
+    let mut ranks = vec![0.0; n];
+    let mut start = 0;
+    while start < n {
+        let mut end = start + 1;
+        while end < n && scores[order[end]].total_cmp(&scores[order[start]]).is_eq() {
+            end += 1;
+        }
+        let rank = (start + end - 1) as f32 / 2.0 / (n - 1) as f32;
+        for &i in &order[start..end] {
+            ranks[i] = rank;
+        }
+        start = end;
+    }
+    scores.copy_from_slice(&ranks);
+}
+
+#[cfg(test)]
+mod tests {
+    use oxidris_engine::{BitBoard, Piece, PieceKind};
+
+    use super::*;
+
+    /// Scores a placement by the column of the piece, scaled by `scale`.
+    #[derive(Debug)]
+    struct ColumnEvaluator {
+        scale: f32,
+    }
+
+    impl PlacementEvaluator for ColumnEvaluator {
+        fn evaluate_placement(&self, analysis: &PlacementAnalysis) -> f32 {
+            column(analysis) * self.scale
+        }
+    }
+
+    #[expect(clippy::cast_precision_loss)]
+    fn column(analysis: &PlacementAnalysis) -> f32 {
+        analysis.placement().position().x() as f32
+    }
+
+    fn analyses() -> Vec<PlacementAnalysis> {
+        let board = BitBoard::INITIAL;
+        iter::successors(Some(Piece::new(PieceKind::O)), |p| {
+            p.left().filter(|m| !board.is_colliding(*m))
+        })
+        .map(|p| PlacementAnalysis::from_board(&board, p.simulate_drop_position(&board)))
+        .collect()
+    }
+
+    fn column_evaluator(scale: f32) -> Box<dyn PlacementEvaluator> {
+        Box::new(ColumnEvaluator { scale })
+    }
+
+    fn assert_approx(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in iter::zip(actual, expected) {
+            assert!((a - e).abs() < 1e-6, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_normalization() {
+        let mut scores = [3.0, -1.0, 1.0, 1.0];
+        min_max_normalize(&mut scores);
+        assert_approx(&scores, &[1.0, 0.0, 0.5, 0.5]);
+
+        let mut scores = [3.0, -1.0, 1.0, 1.0];
+        rank_normalize(&mut scores);
+        assert_approx(&scores, &[1.0, 0.0, 0.5, 0.5]);
+
+        let mut scores = [2.0, 2.0];
+        min_max_normalize(&mut scores);
+        assert_approx(&scores, &[0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_scale_invariant_combination() {
+        let analyses = analyses();
+        assert!(analyses.len() > 2);
+        let min = analyses.iter().map(column).fold(f32::INFINITY, f32::min);
+        let max = analyses
+            .iter()
+            .map(column)
+            .fold(f32::NEG_INFINITY, f32::max);
+        for rule in [EnsembleRule::Mean, EnsembleRule::RankVote] {
+            // Members agree up to scale, so the ensemble ranks like either of them
+            let ensemble = EnsemblePlacementEvaluator::new(
+                vec![column_evaluator(1.0), column_evaluator(100.0)],
+                vec![1.0, 3.0],
+                rule,
+            )
+            .unwrap();
+            assert!(ensemble.is_batch_relative());
+            let mut scores = vec![0.0; analyses.len()];
+            ensemble.evaluate_placements(&analyses, &mut scores);
+            for (analysis, score) in iter::zip(&analyses, &scores) {
+                let expected = (column(analysis) - min) / (max - min);
+                assert!((score - expected).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_opposing_members_cancel_out() {
+        let analyses = analyses();
+        let ensemble = EnsemblePlacementEvaluator::new(
+            vec![column_evaluator(1.0), column_evaluator(-1.0)],
+            vec![1.0, 1.0],
+            EnsembleRule::RankVote,
+        )
+        .unwrap();
+        let mut scores = vec![0.0; analyses.len()];
+        ensemble.evaluate_placements(&analyses, &mut scores);
+        assert!(scores.iter().all(|s| (s - 0.5).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_phased_selects_member() {
+        let analyses = analyses();
+        let gate = HeightGate::new(vec![1]).unwrap();
+        let ensemble = EnsemblePlacementEvaluator::new(
+            vec![column_evaluator(1.0), column_evaluator(-1.0)],
+            vec![1.0, 1.0],
+            EnsembleRule::Phased { gate },
+        )
+        .unwrap();
+        assert!(!ensemble.is_batch_relative());
+        // Empty board: height 0 is in the first phase
+        let mut scores = vec![0.0; analyses.len()];
+        ensemble.evaluate_placements(&analyses, &mut scores);
+        for (analysis, score) in iter::zip(&analyses, &scores) {
+            assert!((column(analysis) - score).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_invalid_ensembles() {
+        assert_eq!(
+            EnsemblePlacementEvaluator::new(vec![], vec![], EnsembleRule::Mean).unwrap_err(),
+            EnsembleError::NoMembers
+        );
+        assert_eq!(
+            EnsemblePlacementEvaluator::new(
+                vec![column_evaluator(1.0)],
+                vec![],
+                EnsembleRule::Mean
+            )
+            .unwrap_err(),
+            EnsembleError::WeightCount {
+                expected: 1,
+                actual: 0
+            }
+        );
+        let gate = HeightGate::new(vec![8, 14]).unwrap();
+        assert_eq!(
+            EnsemblePlacementEvaluator::new(
+                vec![column_evaluator(1.0)],
+                vec![1.0],
+                EnsembleRule::Phased { gate }
+            )
+            .unwrap_err(),
+            EnsembleError::PhaseCount {
+                expected: 3,
+                actual: 1
+            }
+        );
+    }
+}
@@ -0,0 +1,444 @@
+use oxidris_engine::BitBoard;
+use serde::{Deserialize, Serialize};
+
+use super::PlacementEvaluator;
+use crate::{board_feature::BoxedBoardFeature, placement_analysis::PlacementAnalysis};
+
+/// Placement evaluator running a small multi-layer perceptron over the feature vector.
+///
+/// The network input is the normalized value of each feature (∈ \[0.0, 1.0\]), optionally
+/// followed by the column heights scaled by the board height. The network must have a
+/// single output, which is used as the placement score.
+///
+/// Unlike [`FeatureBasedPlacementEvaluator`](super::FeatureBasedPlacementEvaluator),
+/// hidden layers let the evaluator learn interactions between features without
+/// defining them explicitly.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use oxidris_evaluator::placement_evaluator::{Activation, MlpNetwork, MlpPlacementEvaluator};
+/// # let features: Vec<oxidris_evaluator::board_feature::BoxedBoardFeature> = todo!();
+/// # let flat_weights: Vec<f32> = todo!(); // e.g., from genetic algorithm training
+///
+/// let shape = [features.len(), 16, 1];
+/// let network = MlpNetwork::from_flat(&shape, Activation::Relu, &flat_weights).unwrap();
+/// let evaluator = MlpPlacementEvaluator::new(features, false, network).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct MlpPlacementEvaluator {
+    features: Vec<BoxedBoardFeature>,
+    column_heights: bool,
+    network: MlpNetwork,
+}
+
+/// Feed-forward neural network with dense layers.
+///
+/// Serialized as a list of layers, so trained networks can be stored in JSON model files.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "Vec<DenseLayer>", into = "Vec<DenseLayer>")]
+pub struct MlpNetwork {
+    layers: Vec<DenseLayer>,
+}
+
+/// Fully connected layer: `output = activation(weights · input + biases)`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DenseLayer {
+    /// Weight matrix in row-major order (`outputs` rows × `inputs` columns)
+    weights: Vec<f32>,
+    biases: Vec<f32>,
+    activation: Activation,
+}
+
+/// Activation function applied to the output of a [`DenseLayer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Activation {
+    Linear,
+    Relu,
+    Tanh,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+pub enum MlpError {
+    #[display("network has no layers")]
+    NoLayers,
+    #[display(
+        "layer {layer} has {weights} weights, which is not a non-zero multiple of {biases} biases"
+    )]
+    LayerShape {
+        layer: usize,
+        weights: usize,
+        biases: usize,
+    },
+    #[display("layer {layer} expects {expected} inputs, but previous layer has {actual} outputs")]
+    LayerMismatch {
+        layer: usize,
+        expected: usize,
+        actual: usize,
+    },
+    #[display("network expects {expected} inputs, but evaluator provides {actual}")]
+    InputMismatch { expected: usize, actual: usize },
+    #[display("network must have a single output, but has {outputs}")]
+    OutputMismatch { outputs: usize },
+    #[display("expected {expected} parameters, but got {actual}")]
+    ParamCount { expected: usize, actual: usize },
+}
+
+impl MlpPlacementEvaluator {
+    /// Creates a new MLP placement evaluator.
+    ///
+    /// # Arguments
+    ///
+    /// * `features` - Features whose normalized values are fed to the network
+    /// * `column_heights` - Also feed the column heights (scaled to \[0.0, 1.0\])
+    /// * `network` - Network with a single output
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the network input size does not match the number of inputs,
+    /// or if the network does not have exactly one output.
+    pub fn new(
+        features: Vec<BoxedBoardFeature>,
+        column_heights: bool,
+        network: MlpNetwork,
+    ) -> Result<Self, MlpError> {
+        let inputs = Self::input_len(features.len(), column_heights);
+        if network.inputs() != inputs {
+            return Err(MlpError::InputMismatch {
+                expected: network.inputs(),
+                actual: inputs,
+            });
+        }
+        if network.outputs() != 1 {
+            return Err(MlpError::OutputMismatch {
+                outputs: network.outputs(),
+            });
+        }
+        Ok(Self {
+            features,
+            column_heights,
+            network,
+        })
+    }
+
+    /// Returns the number of network inputs for the given configuration.
+    #[must_use]
+    pub fn input_len(num_features: usize, column_heights: bool) -> usize {
+        if column_heights {
+            num_features + BitBoard::PLAYABLE_WIDTH
+        } else {
+            num_features
+        }
+    }
+
+    #[must_use]
+    pub fn features(&self) -> &[BoxedBoardFeature] {
+        &self.features
+    }
+
+    #[must_use]
+    pub fn column_heights(&self) -> bool {
+        self.column_heights
+    }
+
+    #[must_use]
+    pub fn network(&self) -> &MlpNetwork {
+        &self.network
+    }
+}
+
+impl PlacementEvaluator for MlpPlacementEvaluator {
+    #[expect(clippy::cast_precision_loss)]
+    fn evaluate_placement(&self, analysis: &PlacementAnalysis) -> f32 {
+        let mut input = Vec::with_capacity(self.network.inputs());
+        input.extend(
+            self.features
+                .iter()
+                .map(|f| f.compute_feature_value(analysis).normalized),
+        );
+        if self.column_heights {
+            let heights = analysis.board_analysis().column_heights();
+            input.extend(
+                heights
+                    .iter()
+                    .map(|&h| f32::from(h) / BitBoard::PLAYABLE_HEIGHT as f32),
+            );
+        }
+        self.network.forward(&input)[0]
+    }
+}
+
+impl MlpNetwork {
+    /// Creates a network from its layers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no layers, if a layer has no inputs or outputs, or if layer
+    /// sizes are inconsistent.
+    pub fn new(layers: Vec<DenseLayer>) -> Result<Self, MlpError> {
+        let Some(first) = layers.first() else {
+            return Err(MlpError::NoLayers);
+        };
+        let mut prev_outputs = first.inputs();
+        for (i, layer) in layers.iter().enumerate() {
+            if layer.biases.is_empty()
+                || layer.weights.is_empty()
+                || layer.weights.len() % layer.biases.len() != 0
+            {
+                return Err(MlpError::LayerShape {
+                    layer: i,
+                    weights: layer.weights.len(),
+                    biases: layer.biases.len(),
+                });
+            }
+            if layer.inputs() != prev_outputs {
+                return Err(MlpError::LayerMismatch {
+                    layer: i,
+                    expected: layer.inputs(),
+                    actual: prev_outputs,
+                });
+            }
+            prev_outputs = layer.outputs();
+        }
+        Ok(Self { layers })
+    }
+
+    /// Creates a network from a flattened parameter vector.
+    ///
+    /// `shape` lists the layer sizes from input to output (e.g., `[20, 16, 1]`). Hidden
+    /// layers use `hidden_activation` and the output layer is linear. For each layer,
+    /// `params` holds the row-major weight matrix followed by the biases.
+    ///
+    /// This is the representation used by genetic algorithm training, where each
+    /// individual is a flat weight vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `shape` has fewer than two entries, or if `params` does not have
+    /// exactly [`num_params(shape)`](Self::num_params) elements.
+    pub fn from_flat(
+        shape: &[usize],
+        hidden_activation: Activation,
+        params: &[f32],
+    ) -> Result<Self, MlpError> {
+        if shape.len() < 2 {
+            return Err(MlpError::NoLayers);
+        }
+        let expected = Self::num_params(shape);
+        if params.len() != expected {
+            return Err(MlpError::ParamCount {
+                expected,
+                actual: params.len(),
+            });
+        }
+        let num_layers = shape.len() - 1;
+        let mut rest = params;
+        let layers = shape
+            .windows(2)
+            .enumerate()
+            .map(|(i, io)| {
+                let (inputs, outputs) = (io[0], io[1]);
+                let (weights, tail) = rest.split_at(inputs * outputs);
+                let (biases, tail) = tail.split_at(outputs);
+                rest = tail;
+                let activation = if i + 1 == num_layers {
+                    Activation::Linear
+                } else {
+                    hidden_activation
+                };
+                DenseLayer::new(weights.to_vec(), biases.to_vec(), activation)
+            })
+            .collect();
+        Self::new(layers)
+    }
+
+    /// Returns the number of parameters of a network with the given `shape`.
+    #[must_use]
+    pub fn num_params(shape: &[usize]) -> usize {
+        shape.windows(2).map(|io| io[0] * io[1] + io[1]).sum()
+    }
+
+    /// Flattens the network parameters in the order used by [`from_flat()`](Self::from_flat).
+    #[must_use]
+    pub fn to_flat(&self) -> Vec<f32> {
+        self.layers
+            .iter()
+            .flat_map(|layer| layer.weights.iter().chain(&layer.biases).copied())
+            .collect()
+    }
+
+    #[must_use]
+    pub fn layers(&self) -> &[DenseLayer] {
+        &self.layers
+    }
+
+    #[must_use]
+    pub fn inputs(&self) -> usize {
+        self.layers[0].inputs()
+    }
+
+    #[must_use]
+    pub fn outputs(&self) -> usize {
+        self.layers[self.layers.len() - 1].outputs()
+    }
+
+    /// Runs the network on `input` and returns the output layer values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input.len()` does not match [`inputs()`](Self::inputs).
+    #[must_use]
+    pub fn forward(&self, input: &[f32]) -> Vec<f32> {
+        assert_eq!(input.len(), self.inputs());
+        let mut values = input.to_vec();
+        let mut next = vec![];
+        for layer in &self.layers {
+            layer.forward(&values, &mut next);
+            std::mem::swap(&mut values, &mut next);
+        }
+        values
+    }
+}
+
+impl TryFrom<Vec<DenseLayer>> for MlpNetwork {
+    type Error = MlpError;
+
+    fn try_from(layers: Vec<DenseLayer>) -> Result<Self, Self::Error> {
+        Self::new(layers)
+    }
+}
+
+impl From<MlpNetwork> for Vec<DenseLayer> {
+    fn from(network: MlpNetwork) -> Self {
+        network.layers
+    }
+}
+
+impl DenseLayer {
+    /// Creates a dense layer.
+    ///
+    /// `weights` is the row-major weight matrix with one row per output (`biases.len()` rows).
+    #[must_use]
+    pub fn new(weights: Vec<f32>, biases: Vec<f32>, activation: Activation) -> Self {
+        Self {
+            weights,
+            biases,
+            activation,
+        }
+    }
+
+    #[must_use]
+    pub fn inputs(&self) -> usize {
+        self.weights
+            .len()
+            .checked_div(self.biases.len())
+            .unwrap_or(0)
+    }
+
+    #[must_use]
+    pub fn outputs(&self) -> usize {
+        self.biases.len()
+    }
+
+    #[must_use]
+    pub fn activation(&self) -> Activation {
+        self.activation
+    }
+
+    fn forward(&self, input: &[f32], output: &mut Vec<f32>) {
+        output.clear();
+        output.extend(
+            self.weights
+                .chunks_exact(input.len())
+                .zip(&self.biases)
+                .map(|(row, bias)| {
+                    let sum = row.iter().zip(input).map(|(w, x)| w * x).sum::<f32>() + bias;
+                    self.activation.apply(sum)
+                }),
+        );
+    }
+}
+
+impl Activation {
+    #[must_use]
+    pub fn apply(self, x: f32) -> f32 {
+        match self {
+            Self::Linear => x,
+            Self::Relu => x.max(0.0),
+            Self::Tanh => x.tanh(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forward() {
+        // hidden: h0 = relu(x0 - x1), h1 = relu(x1 - x0)
+        // output: y = h0 + 2 * h1 + 0.5
+        let network = MlpNetwork::new(vec![
+            DenseLayer::new(vec![1.0, -1.0, -1.0, 1.0], vec![0.0, 0.0], Activation::Relu),
+            DenseLayer::new(vec![1.0, 2.0], vec![0.5], Activation::Linear),
+        ])
+        .unwrap();
+        assert_eq!(network.inputs(), 2);
+        assert_eq!(network.outputs(), 1);
+        assert_eq!(network.forward(&[0.75, 0.25]), vec![1.0]);
+        assert_eq!(network.forward(&[0.25, 0.75]), vec![1.5]);
+    }
+
+    #[test]
+    fn test_flat_roundtrip() {
+        let shape = [3, 4, 1];
+        let num_params = MlpNetwork::num_params(&shape);
+        assert_eq!(num_params, 3 * 4 + 4 + 4 + 1);
+
+        #[expect(clippy::cast_precision_loss)]
+        let params = (0..num_params).map(|i| i as f32 * 0.1).collect::<Vec<_>>();
+        let network = MlpNetwork::from_flat(&shape, Activation::Tanh, &params).unwrap();
+        assert_eq!(network.layers()[0].activation(), Activation::Tanh);
+        assert_eq!(network.layers()[1].activation(), Activation::Linear);
+        assert_eq!(network.to_flat(), params);
+
+        let json = serde_json::to_string(&network).unwrap();
+        let restored: MlpNetwork = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, network);
+
+        assert_eq!(
+            MlpNetwork::from_flat(&shape, Activation::Tanh, &params[1..]),
+            Err(MlpError::ParamCount {
+                expected: num_params,
+                actual: num_params - 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_layers() {
+        assert_eq!(MlpNetwork::new(vec![]), Err(MlpError::NoLayers));
+        assert_eq!(
+            MlpNetwork::new(vec![
+                DenseLayer::new(vec![1.0; 6], vec![0.0; 2], Activation::Relu),
+                DenseLayer::new(vec![1.0; 3], vec![0.0], Activation::Linear),
+            ]),
+            Err(MlpError::LayerMismatch {
+                layer: 1,
+                expected: 3,
+                actual: 2,
+            })
+        );
+        assert_eq!(
+            MlpNetwork::new(vec![DenseLayer::new(vec![], vec![0.0], Activation::Linear)]),
+            Err(MlpError::LayerShape {
+                layer: 0,
+                weights: 0,
+                biases: 1,
+            })
+        );
+        let json = r#"[{"weights":[1.0,2.0,3.0],"biases":[0.0,0.0],"activation":"relu"}]"#;
+        assert!(serde_json::from_str::<MlpNetwork>(json).is_err());
+    }
+}
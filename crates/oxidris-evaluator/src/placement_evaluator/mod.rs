@@ -38,6 +38,34 @@
 //! - Cannot capture feature interactions (e.g., "holes are bad, but worse when height is high")
 //! - Assumes features contribute independently to the score
 //!
+//! # Batched Evaluation
+//!
+//! [`PlacementEvaluator::evaluate_placements`] scores many placements at once (e.g., all
+//! candidates of a turn). Linear evaluators extract the features of the whole batch into a
+//! [`FeatureBatch`] (struct-of-arrays) and compute the weighted sums one feature at a time.
+//!
+//! # Phase-dependent Weights
+//!
+//! The [`PhasedPlacementEvaluator`] keeps one weight vector per game phase, and a
+//! [`HeightGate`] selects the phase from the stack height before placement. This allows
+//! aggressive play while the stack is low and defensive play once it gets high.
+//!
+//! # Non-linear Evaluation
+//!
+//! The [`MlpPlacementEvaluator`] feeds the same normalized features (optionally with the
+//! column heights) to a small multi-layer perceptron ([`MlpNetwork`]). Hidden layers can
+//! learn interactions between features, at the cost of interpretability. Networks are
+//! serializable, and can be built from flattened parameter vectors for GA training.
+//!
+//! The [`TreePlacementEvaluator`] uses a regression tree ensemble ([`GradientBoostedTrees`])
+//! instead, typically fitted offline to predict the survival time of a board.
+//!
+//! # Ensembles
+//!
+//! The [`EnsemblePlacementEvaluator`] combines several evaluators (e.g., trained models with
+//! different play styles) by an [`EnsembleRule`]: weighted average of normalized scores,
+//! rank voting, or switching members by game phase.
+//!
 //! # Usage
 //!
 //! ```rust,no_run
@@ -60,8 +88,15 @@
 
 use std::{fmt, iter};
 
+pub use self::{batch::*, ensemble::*, mlp::*, phased::*, tree::*};
 use crate::{board_feature::BoxedBoardFeature, placement_analysis::PlacementAnalysis};
 
+mod batch;
+mod ensemble;
+mod mlp;
+mod phased;
+mod tree;
+
 /// Evaluates piece placements by assigning scores.
 ///
 /// Implementations define how to score a placement given its analysis.
@@ -76,6 +111,32 @@ pub trait PlacementEvaluator: fmt::Debug + Send + Sync {
     /// # Returns
     /// Placement score (unbounded, typically in range \[-10.0, 10.0\])
     fn evaluate_placement(&self, analysis: &PlacementAnalysis) -> f32;
+
+    /// Evaluates a batch of placements, writing the score of `analyses[i]` to `scores[i]`.
+    ///
+    /// Scores are the same as with [`evaluate_placement`](Self::evaluate_placement). The
+    /// default implementation evaluates each placement in turn; implementations can
+    /// override it to share work across the batch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `analyses` and `scores` have different lengths.
+    fn evaluate_placements(&self, analyses: &[PlacementAnalysis], scores: &mut [f32]) {
+        assert_eq!(analyses.len(), scores.len());
+        for (analysis, score) in iter::zip(analyses, scores) {
+            *score = self.evaluate_placement(analysis);
+        }
+    }
+
+    /// Returns `true` if the scores of [`evaluate_placements`](Self::evaluate_placements)
+    /// depend on the other placements of the batch (e.g., scores normalized over all
+    /// candidates).
+    ///
+    /// Callers must then pass all candidates of a turn as a single batch, instead of
+    /// splitting them into chunks. Defaults to `false`.
+    fn is_batch_relative(&self) -> bool {
+        false
+    }
 }
 
 /// Feature-based placement evaluator using weighted sum of normalized features.
@@ -128,4 +189,10 @@ impl PlacementEvaluator for FeatureBasedPlacementEvaluator {
             .map(|(f, w)| f.compute_feature_value(analysis).normalized * w)
             .sum()
     }
+
+    fn evaluate_placements(&self, analyses: &[PlacementAnalysis], scores: &mut [f32]) {
+        scores.fill(0.0);
+        FeatureBatch::from_analyses(&self.features, analyses)
+            .accumulate_weighted_sum(&self.weights, scores);
+    }
 }
@@ -0,0 +1,227 @@
+use std::iter;
+
+use serde::{Deserialize, Serialize};
+
+use super::{FeatureBatch, PlacementEvaluator};
+use crate::{board_feature::BoxedBoardFeature, placement_analysis::PlacementAnalysis};
+
+/// Placement evaluator switching between weight sets by game phase.
+///
+/// A [`HeightGate`] selects one weight set per turn from the stack height before the
+/// placement, and the placement is scored as a weighted sum like
+/// [`FeatureBasedPlacementEvaluator`](super::FeatureBasedPlacementEvaluator):
+///
+/// ```text
+/// phase = gate.phase(max_height_before)
+/// score = Σ(weights[phase][i] × featureᵢ)
+/// ```
+///
+/// This lets a model play aggressively (e.g., build for tetrises) while the stack is low
+/// and defensively once it gets high. Since the gate only depends on the board before the
+/// placement, all candidate placements of a turn are scored with the same weight set.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use oxidris_evaluator::placement_evaluator::{HeightGate, PhasedPlacementEvaluator};
+/// # let features: Vec<oxidris_evaluator::board_feature::BoxedBoardFeature> = todo!();
+/// # let (low, middle, high): (Vec<f32>, Vec<f32>, Vec<f32>) = todo!();
+///
+/// // Phases: height < 8, 8 <= height < 14, height >= 14
+/// let gate = HeightGate::new(vec![8, 14]).unwrap();
+/// let evaluator = PhasedPlacementEvaluator::new(features, gate, vec![low, middle, high]).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct PhasedPlacementEvaluator {
+    features: Vec<BoxedBoardFeature>,
+    gate: HeightGate,
+    weights: Vec<Vec<f32>>,
+}
+
+/// Gating rule selecting a game phase by the stack height before placement.
+///
+/// `thresholds` split the height range into `thresholds.len() + 1` bands. Phase `i`
+/// covers heights `thresholds[i - 1] <= height < thresholds[i]`. A gate without
+/// thresholds has a single phase.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "Vec<u8>", into = "Vec<u8>")]
+pub struct HeightGate {
+    thresholds: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+pub enum PhasedError {
+    #[display("height thresholds must be strictly increasing")]
+    UnsortedThresholds,
+    #[display("gate has {expected} phases, but {actual} weight sets are given")]
+    WeightSetCount { expected: usize, actual: usize },
+    #[display("weight set {phase} has {actual} weights, but {expected} features are given")]
+    WeightCount {
+        phase: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl PhasedPlacementEvaluator {
+    /// Creates a new phased placement evaluator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of weight sets does not match the number of phases,
+    /// or if a weight set does not have one weight per feature.
+    pub fn new(
+        features: Vec<BoxedBoardFeature>,
+        gate: HeightGate,
+        weights: Vec<Vec<f32>>,
+    ) -> Result<Self, PhasedError> {
+        if weights.len() != gate.num_phases() {
+            return Err(PhasedError::WeightSetCount {
+                expected: gate.num_phases(),
+                actual: weights.len(),
+            });
+        }
+        for (phase, w) in weights.iter().enumerate() {
+            if w.len() != features.len() {
+                return Err(PhasedError::WeightCount {
+                    phase,
+                    expected: features.len(),
+                    actual: w.len(),
+                });
+            }
+        }
+        Ok(Self {
+            features,
+            gate,
+            weights,
+        })
+    }
+
+    #[must_use]
+    pub fn gate(&self) -> &HeightGate {
+        &self.gate
+    }
+
+    #[must_use]
+    pub fn weights(&self) -> &[Vec<f32>] {
+        &self.weights
+    }
+}
+
+impl PlacementEvaluator for PhasedPlacementEvaluator {
+    fn evaluate_placement(&self, analysis: &PlacementAnalysis) -> f32 {
+        let weights = &self.weights[self.gate.phase(analysis.max_height_before())];
+        iter::zip(&self.features, weights)
+            .map(|(f, w)| f.compute_feature_value(analysis).normalized * w)
+            .sum()
+    }
+
+    fn evaluate_placements(&self, analyses: &[PlacementAnalysis], scores: &mut [f32]) {
+        assert_eq!(analyses.len(), scores.len());
+        let Some(first) = analyses.first() else {
+            return;
+        };
+        // Candidates of a turn share the board before placement, and hence the phase
+        let phase = self.gate.phase(first.max_height_before());
+        if analyses[1..]
+            .iter()
+            .any(|a| self.gate.phase(a.max_height_before()) != phase)
+        {
+            for (analysis, score) in iter::zip(analyses, scores) {
+                *score = self.evaluate_placement(analysis);
+            }
+            return;
+        }
+        scores.fill(0.0);
+        FeatureBatch::from_analyses(&self.features, analyses)
+            .accumulate_weighted_sum(&self.weights[phase], scores);
+    }
+}
+
+impl HeightGate {
+    /// Creates a gate from band thresholds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `thresholds` are not strictly increasing.
+    pub fn new(thresholds: Vec<u8>) -> Result<Self, PhasedError> {
+        if !thresholds.is_sorted_by(|a, b| a < b) {
+            return Err(PhasedError::UnsortedThresholds);
+        }
+        Ok(Self { thresholds })
+    }
+
+    #[must_use]
+    pub fn thresholds(&self) -> &[u8] {
+        &self.thresholds
+    }
+
+    #[must_use]
+    pub fn num_phases(&self) -> usize {
+        self.thresholds.len() + 1
+    }
+
+    /// Returns the phase index for the given stack height.
+    #[must_use]
+    pub fn phase(&self, height: u8) -> usize {
+        self.thresholds.partition_point(|&t| t <= height)
+    }
+}
+
+impl TryFrom<Vec<u8>> for HeightGate {
+    type Error = PhasedError;
+
+    fn try_from(thresholds: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::new(thresholds)
+    }
+}
+
+impl From<HeightGate> for Vec<u8> {
+    fn from(gate: HeightGate) -> Self {
+        gate.thresholds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_height_gate() {
+        let gate = HeightGate::new(vec![8, 14]).unwrap();
+        assert_eq!(gate.num_phases(), 3);
+        assert_eq!(gate.phase(0), 0);
+        assert_eq!(gate.phase(7), 0);
+        assert_eq!(gate.phase(8), 1);
+        assert_eq!(gate.phase(13), 1);
+        assert_eq!(gate.phase(20), 2);
+
+        assert_eq!(HeightGate::default().num_phases(), 1);
+        assert_eq!(HeightGate::default().phase(20), 0);
+        assert_eq!(
+            HeightGate::new(vec![8, 8]),
+            Err(PhasedError::UnsortedThresholds)
+        );
+        assert!(serde_json::from_str::<HeightGate>("[14, 8]").is_err());
+    }
+
+    #[test]
+    fn test_weight_set_mismatch() {
+        let gate = HeightGate::new(vec![10]).unwrap();
+        assert_eq!(
+            PhasedPlacementEvaluator::new(vec![], gate.clone(), vec![vec![]]).unwrap_err(),
+            PhasedError::WeightSetCount {
+                expected: 2,
+                actual: 1
+            }
+        );
+        assert_eq!(
+            PhasedPlacementEvaluator::new(vec![], gate, vec![vec![], vec![1.0]]).unwrap_err(),
+            PhasedError::WeightCount {
+                phase: 1,
+                expected: 0,
+                actual: 1
+            }
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use serde::{Deserialize, Serialize};
+
+use super::PlacementEvaluator;
+use crate::{board_feature::BoxedBoardFeature, placement_analysis::PlacementAnalysis};
+
+/// Placement evaluator using an ensemble of regression trees over the feature vector.
+///
+/// The model input is the normalized value of each feature (∈ \[0.0, 1.0\]). The model
+/// output is used as the placement score. Models are typically trained to predict the
+/// remaining survival turns of a board, so higher is better.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use oxidris_evaluator::placement_evaluator::{GradientBoostedTrees, TreePlacementEvaluator};
+/// # let features: Vec<oxidris_evaluator::board_feature::BoxedBoardFeature> = todo!();
+/// # let model: GradientBoostedTrees = todo!(); // e.g., trained by `oxidris-training`
+///
+/// let evaluator = TreePlacementEvaluator::new(features, model).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct TreePlacementEvaluator {
+    features: Vec<BoxedBoardFeature>,
+    model: GradientBoostedTrees,
+}
+
+/// Gradient-boosted regression tree ensemble.
+///
+/// ```text
+/// prediction = base_score + learning_rate × Σ treeᵢ(input)
+/// ```
+///
+/// A single decision tree is an ensemble with one tree, `base_score = 0.0`
+/// and `learning_rate = 1.0`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GradientBoostedTrees {
+    base_score: f32,
+    learning_rate: f32,
+    trees: Vec<RegressionTree>,
+}
+
+/// Binary regression tree stored as a flat list of nodes.
+///
+/// The root is the first node, and children always come after their parent.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "Vec<TreeNode>", into = "Vec<TreeNode>")]
+pub struct RegressionTree {
+    nodes: Vec<TreeNode>,
+}
+
+/// Node of a [`RegressionTree`].
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TreeNode {
+    /// Goes to `left` if `input[feature] <= threshold`, otherwise to `right`
+    Split {
+        feature: usize,
+        threshold: f32,
+        left: usize,
+        right: usize,
+    },
+    Leaf {
+        value: f32,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+pub enum TreeError {
+    #[display("tree has no nodes")]
+    EmptyTree,
+    #[display("node {node} has invalid child {child}")]
+    InvalidChild { node: usize, child: usize },
+    #[display("model uses feature {feature}, but evaluator provides {num_features} features")]
+    FeatureOutOfRange { feature: usize, num_features: usize },
+}
+
+impl TreePlacementEvaluator {
+    /// Creates a new tree-based placement evaluator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the model refers to a feature index outside `features`.
+    pub fn new(
+        features: Vec<BoxedBoardFeature>,
+        model: GradientBoostedTrees,
+    ) -> Result<Self, TreeError> {
+        if let Some(feature) = model.max_feature_index()
+            && feature >= features.len()
+        {
+            return Err(TreeError::FeatureOutOfRange {
+                feature,
+                num_features: features.len(),
+            });
+        }
+        Ok(Self { features, model })
+    }
+
+    #[must_use]
+    pub fn features(&self) -> &[BoxedBoardFeature] {
+        &self.features
+    }
+
+    #[must_use]
+    pub fn model(&self) -> &GradientBoostedTrees {
+        &self.model
+    }
+}
+
+impl PlacementEvaluator for TreePlacementEvaluator {
+    fn evaluate_placement(&self, analysis: &PlacementAnalysis) -> f32 {
+        let input = self
+            .features
+            .iter()
+            .map(|f| f.compute_feature_value(analysis).normalized)
+            .collect::<Vec<_>>();
+        self.model.predict(&input)
+    }
+}
+
+impl GradientBoostedTrees {
+    #[must_use]
+    pub fn new(base_score: f32, learning_rate: f32, trees: Vec<RegressionTree>) -> Self {
+        Self {
+            base_score,
+            learning_rate,
+            trees,
+        }
+    }
+
+    #[must_use]
+    pub fn base_score(&self) -> f32 {
+        self.base_score
+    }
+
+    #[must_use]
+    pub fn learning_rate(&self) -> f32 {
+        self.learning_rate
+    }
+
+    #[must_use]
+    pub fn trees(&self) -> &[RegressionTree] {
+        &self.trees
+    }
+
+    /// Returns the largest feature index used by any split, or `None` if there are no splits.
+    #[must_use]
+    pub fn max_feature_index(&self) -> Option<usize> {
+        self.trees
+            .iter()
+            .filter_map(RegressionTree::max_feature_index)
+            .max()
+    }
+
+    /// Predicts the output for `input`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is shorter than [`max_feature_index()`](Self::max_feature_index) + 1.
+    #[must_use]
+    pub fn predict(&self, input: &[f32]) -> f32 {
+        let sum = self.trees.iter().map(|t| t.predict(input)).sum::<f32>();
+        self.base_score + self.learning_rate * sum
+    }
+}
+
+impl RegressionTree {
+    /// Creates a tree from its nodes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no nodes, or if a split refers to a child that does
+    /// not come after it.
+    pub fn new(nodes: Vec<TreeNode>) -> Result<Self, TreeError> {
+        if nodes.is_empty() {
+            return Err(TreeError::EmptyTree);
+        }
+        for (i, node) in nodes.iter().enumerate() {
+            if let TreeNode::Split { left, right, .. } = *node {
+                for child in [left, right] {
+                    if child <= i || child >= nodes.len() {
+                        return Err(TreeError::InvalidChild { node: i, child });
+                    }
+                }
+            }
+        }
+        Ok(Self { nodes })
+    }
+
+    #[must_use]
+    pub fn nodes(&self) -> &[TreeNode] {
+        &self.nodes
+    }
+
+    /// Returns the largest feature index used by any split, or `None` if there are no splits.
+    #[must_use]
+    pub fn max_feature_index(&self) -> Option<usize> {
+        self.nodes
+            .iter()
+            .filter_map(|node| match node {
+                TreeNode::Split { feature, .. } => Some(*feature),
+                TreeNode::Leaf { .. } => None,
+            })
+            .max()
+    }
+
+    /// Predicts the output for `input`.
+    #[must_use]
+    pub fn predict(&self, input: &[f32]) -> f32 {
+        let mut index = 0;
+        loop {
+            match self.nodes[index] {
+                TreeNode::Split {
+                    feature,
+                    threshold,
+                    left,
+                    right,
+                } => {
+                    index = if input[feature] <= threshold {
+                        left
+                    } else {
+                        right
+                    }
+                }
+                TreeNode::Leaf { value } => return value,
+            }
+        }
+    }
+}
+
+impl TryFrom<Vec<TreeNode>> for RegressionTree {
+    type Error = TreeError;
+
+    fn try_from(nodes: Vec<TreeNode>) -> Result<Self, Self::Error> {
+        Self::new(nodes)
+    }
+}
+
+impl From<RegressionTree> for Vec<TreeNode> {
+    fn from(tree: RegressionTree) -> Self {
+        tree.nodes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stump(feature: usize, threshold: f32, left: f32, right: f32) -> RegressionTree {
+        RegressionTree::new(vec![
+            TreeNode::Split {
+                feature,
+                threshold,
+                left: 1,
+                right: 2,
+            },
+            TreeNode::Leaf { value: left },
+            TreeNode::Leaf { value: right },
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_predict_and_roundtrip() {
+        let model = GradientBoostedTrees::new(
+            10.0,
+            0.5,
+            vec![stump(0, 0.5, -2.0, 2.0), stump(1, 0.25, 4.0, 0.0)],
+        );
+        assert_eq!(model.max_feature_index(), Some(1));
+        assert!((model.predict(&[0.2, 0.2]) - 11.0).abs() < 1e-6);
+        assert!((model.predict(&[0.8, 0.2]) - 13.0).abs() < 1e-6);
+        assert!((model.predict(&[0.8, 0.8]) - 11.0).abs() < 1e-6);
+
+        let json = serde_json::to_string(&model).unwrap();
+        let restored: GradientBoostedTrees = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, model);
+    }
+
+    #[test]
+    fn test_invalid_tree() {
+        assert_eq!(RegressionTree::new(vec![]), Err(TreeError::EmptyTree));
+        assert_eq!(
+            RegressionTree::new(vec![TreeNode::Split {
+                feature: 0,
+                threshold: 0.0,
+                left: 0,
+                right: 1,
+            }]),
+            Err(TreeError::InvalidChild { node: 0, child: 0 })
+        );
+    }
+}
@@ -0,0 +1,601 @@
+use oxidris_engine::GameField;
+use serde::{Deserialize, Serialize};
+
+use super::{DefaultSessionStats, EvaluateSessionStats, SessionTimeline, TimelineSessionStats};
+
+/// Highest column height of the playable area, assumed for the turns after a game over.
+const MAX_HEIGHT: f32 = 20.0;
+
+/// Fitness function defined as a weighted sum of terms.
+///
+/// **Formula:**
+/// ```text
+/// fitness = bias + Σ(weightᵢ × termᵢ)
+/// ```
+///
+/// Terms are computed from the session statistics and normalized to about \[0.0, 1.0\].
+/// Penalty terms grow with bad play, so they take negative weights. Definitions are
+/// serializable, so fitness experiments can be run from a config file without recompiling:
+///
+/// ```toml
+/// bias = 0.6666667
+///
+/// [[terms]]
+/// type = "line_clears"
+/// weight = 0.33333334
+/// line_weights = [0, 1, 3, 5, 8]
+///
+/// [[terms]]
+/// type = "height_penalty"
+/// weight = -0.33333334
+/// cutoff = 4
+/// exponent = 2
+///
+/// [[terms]]
+/// type = "peak_height_penalty"
+/// weight = -0.33333334
+/// cutoff = 4
+/// ```
+///
+/// [`aggro`](Self::aggro) and [`defensive`](Self::defensive) reproduce the built-in
+/// fitness functions and are a starting point for new definitions.
+///
+/// A definition is evaluated by [`FitnessSessionEvaluator`] on [`DefaultSessionStats`], or by
+/// [`TimelineFitnessSessionEvaluator`] on [`TimelineSessionStats`] if it has terms that need
+/// the per-turn timeline (see [`requires_timeline`](Self::requires_timeline)). Both check
+/// the definition with [`validate`](Self::validate) when created.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FitnessDefinition {
+    /// Constant added to the fitness
+    #[serde(default)]
+    pub bias: f32,
+    pub terms: Vec<WeightedFitnessTerm>,
+}
+
+/// A fitness term with its weight.
+///
+/// Unknown fields are rejected by [`FitnessTerm`], which receives every field but `weight`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeightedFitnessTerm {
+    pub weight: f32,
+    #[serde(flatten)]
+    pub term: FitnessTerm,
+}
+
+/// A quantity computed from the statistics of a session.
+///
+/// Turns after a game over count as played at the maximum height ([`MAX_HEIGHT`]), so
+/// height terms also penalize short games.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum FitnessTerm {
+    /// `(survived_turns / turn_limit)^exponent`
+    SurvivalRatio {
+        #[serde(default = "FitnessTerm::default_survival_exponent")]
+        exponent: f32,
+    },
+    /// Weighted line clears relative to the best possible rate:
+    /// `Σ(line_weights[i] × clears[i]) / max_weighted_lines`
+    ///
+    /// `max_weighted_lines` assumes every line is cleared by the line clear count with the
+    /// highest weight per line.
+    LineClears {
+        /// Weight of 0 to 4 line clears
+        #[serde(default = "FitnessTerm::default_line_weights")]
+        line_weights: [f32; 5],
+    },
+    /// Score per piece relative to clearing only tetrises
+    // A struct variant, since `deny_unknown_fields` is ignored by unit variants
+    Score {},
+    /// Mean of `max(max_height - cutoff, 0)^exponent` over the turn limit, divided by its
+    /// worst case `(20 - cutoff)^exponent`
+    HeightPenalty {
+        #[serde(default)]
+        cutoff: f32,
+        #[serde(default = "FitnessTerm::default_height_exponent")]
+        exponent: f32,
+    },
+    /// `max(peak_max_height - cutoff, 0)^exponent / 20^exponent`
+    PeakHeightPenalty {
+        #[serde(default)]
+        cutoff: f32,
+        #[serde(default = "FitnessTerm::default_height_exponent")]
+        exponent: f32,
+    },
+    /// Share of the turn limit with the max height at or above `height`
+    TimeAbove { height: u8 },
+    /// Mean turns to recover from height spikes, relative to the turn limit
+    ///
+    /// A spike starts when the max height reaches `spike_height` and ends when it drops to
+    /// `recovered_height` or below (see [`SessionTimeline::recovery_turns`]). Spikes not
+    /// recovered by the end of the game count as the whole turn limit; 0.0 without spikes.
+    ///
+    /// Needs the per-turn timeline ([`TimelineFitnessSessionEvaluator`]).
+    SpikeRecovery {
+        spike_height: u8,
+        recovered_height: u8,
+    },
+}
+
+/// Invalid [`FitnessDefinition`] for a fitness evaluator.
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+pub enum FitnessDefinitionError {
+    #[display("term {term}: {field} must be finite and non-negative")]
+    InvalidParam { term: usize, field: &'static str },
+    #[display("term {term}: spike_height must be greater than recovered_height")]
+    SpikeHeight { term: usize },
+    #[display("term {term} requires the timeline, which is not recorded by this evaluator")]
+    RequiresTimeline { term: usize },
+}
+
+/// Evaluates a [`FitnessDefinition`] without timeline terms on [`DefaultSessionStats`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FitnessSessionEvaluator {
+    definition: FitnessDefinition,
+}
+
+/// Evaluates a [`FitnessDefinition`] on [`TimelineSessionStats`].
+///
+/// Needed for definitions with timeline terms; gives the same fitness as
+/// [`FitnessSessionEvaluator`] for the other terms.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineFitnessSessionEvaluator {
+    definition: FitnessDefinition,
+}
+
+impl FitnessDefinition {
+    /// Checks that the parameters of every term are in range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an exponent or cutoff is negative or NaN, or if a spike height is
+    /// not above its recovered height.
+    pub fn validate(&self) -> Result<(), FitnessDefinitionError> {
+        for (i, term) in self.terms.iter().enumerate() {
+            let check = |field, value: f32| {
+                if value.is_finite() && value >= 0.0 {
+                    Ok(())
+                } else {
+                    Err(FitnessDefinitionError::InvalidParam { term: i, field })
+                }
+            };
+            match &term.term {
+                FitnessTerm::SurvivalRatio { exponent } => check("exponent", *exponent)?,
+                FitnessTerm::HeightPenalty { cutoff, exponent }
+                | FitnessTerm::PeakHeightPenalty { cutoff, exponent } => {
+                    check("cutoff", *cutoff)?;
+                    check("exponent", *exponent)?;
+                }
+                FitnessTerm::SpikeRecovery {
+                    spike_height,
+                    recovered_height,
+                } => {
+                    if spike_height <= recovered_height {
+                        return Err(FitnessDefinitionError::SpikeHeight { term: i });
+                    }
+                }
+                FitnessTerm::LineClears { .. }
+                | FitnessTerm::Score {}
+                | FitnessTerm::TimeAbove { .. } => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if any term needs the per-turn timeline.
+    #[must_use]
+    pub fn requires_timeline(&self) -> bool {
+        self.terms.iter().any(|t| t.term.requires_timeline())
+    }
+
+    fn evaluate(
+        &self,
+        stats: &DefaultSessionStats,
+        timeline: Option<&SessionTimeline>,
+        turn_limit: usize,
+    ) -> f32 {
+        self.bias
+            + self
+                .terms
+                .iter()
+                .map(|t| t.weight * t.term.compute(stats, timeline, turn_limit))
+                .sum::<f32>()
+    }
+
+    /// Same fitness as [`AggroSessionEvaluator`](super::AggroSessionEvaluator).
+    #[must_use]
+    pub fn aggro() -> Self {
+        let third = 1.0 / 3.0;
+        Self {
+            bias: 2.0 * third,
+            terms: vec![
+                WeightedFitnessTerm {
+                    weight: third,
+                    term: FitnessTerm::LineClears {
+                        line_weights: FitnessTerm::default_line_weights(),
+                    },
+                },
+                WeightedFitnessTerm {
+                    weight: -third,
+                    term: FitnessTerm::HeightPenalty {
+                        cutoff: 4.0,
+                        exponent: 2.0,
+                    },
+                },
+                WeightedFitnessTerm {
+                    weight: -third,
+                    term: FitnessTerm::PeakHeightPenalty {
+                        cutoff: 4.0,
+                        exponent: 2.0,
+                    },
+                },
+            ],
+        }
+    }
+
+    /// Same fitness as [`DefensiveSessionEvaluator`](super::DefensiveSessionEvaluator).
+    #[must_use]
+    pub fn defensive() -> Self {
+        Self {
+            bias: 1.0,
+            terms: vec![
+                WeightedFitnessTerm {
+                    weight: -0.5,
+                    term: FitnessTerm::HeightPenalty {
+                        cutoff: 0.0,
+                        exponent: 2.0,
+                    },
+                },
+                WeightedFitnessTerm {
+                    weight: -0.5,
+                    term: FitnessTerm::PeakHeightPenalty {
+                        cutoff: 0.0,
+                        exponent: 2.0,
+                    },
+                },
+            ],
+        }
+    }
+}
+
+impl FitnessSessionEvaluator {
+    /// Creates a fitness evaluator from a definition.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the definition is invalid or has a term that requires the timeline.
+    pub fn new(definition: FitnessDefinition) -> Result<Self, FitnessDefinitionError> {
+        definition.validate()?;
+        if let Some(term) = definition
+            .terms
+            .iter()
+            .position(|t| t.term.requires_timeline())
+        {
+            return Err(FitnessDefinitionError::RequiresTimeline { term });
+        }
+        Ok(Self { definition })
+    }
+}
+
+impl EvaluateSessionStats for FitnessSessionEvaluator {
+    type Stats = DefaultSessionStats;
+
+    fn evaluate_session_stats(
+        &self,
+        _field: &GameField,
+        stats: &Self::Stats,
+        turn_limit: usize,
+    ) -> f32 {
+        self.definition.evaluate(stats, None, turn_limit)
+    }
+}
+
+impl TimelineFitnessSessionEvaluator {
+    /// Creates a fitness evaluator from a definition.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the definition is invalid.
+    pub fn new(definition: FitnessDefinition) -> Result<Self, FitnessDefinitionError> {
+        definition.validate()?;
+        Ok(Self { definition })
+    }
+}
+
+impl EvaluateSessionStats for TimelineFitnessSessionEvaluator {
+    type Stats = TimelineSessionStats;
+
+    fn evaluate_session_stats(
+        &self,
+        _field: &GameField,
+        stats: &Self::Stats,
+        turn_limit: usize,
+    ) -> f32 {
+        self.definition
+            .evaluate(stats.default_stats(), Some(stats.timeline()), turn_limit)
+    }
+}
+
+impl FitnessTerm {
+    fn default_survival_exponent() -> f32 {
+        1.0
+    }
+
+    fn default_line_weights() -> [f32; 5] {
+        [0.0, 1.0, 3.0, 5.0, 8.0]
+    }
+
+    fn default_height_exponent() -> f32 {
+        2.0
+    }
+
+    /// Returns `true` if the term is computed from the per-turn timeline.
+    #[must_use]
+    pub fn requires_timeline(&self) -> bool {
+        matches!(self, Self::SpikeRecovery { .. })
+    }
+
+    /// Computes the value of the term (before weighting).
+    ///
+    /// `timeline` is `Some` for timeline terms, which only [`TimelineFitnessSessionEvaluator`]
+    /// accepts.
+    #[expect(clippy::cast_precision_loss)]
+    fn compute(
+        &self,
+        stats: &DefaultSessionStats,
+        timeline: Option<&SessionTimeline>,
+        turn_limit: usize,
+    ) -> f32 {
+        let survived_turns = stats.game_stats.turn() as f32;
+        let turn_limit = turn_limit as f32;
+        let remaining_turns = (turn_limit - survived_turns).max(0.0);
+        let excess = |height: f32, cutoff: f32| (height - cutoff).max(0.0);
+
+        match self {
+            Self::SurvivalRatio { exponent } => (survived_turns / turn_limit).powf(*exponent),
+            Self::LineClears { line_weights } => {
+                let weighted_lines = line_weights
+                    .iter()
+                    .zip(stats.game_stats.line_cleared_counter())
+                    .map(|(w, c)| w * (*c as f32))
+                    .sum::<f32>();
+                // A `n`-line clear takes `2.5 × n` pieces
+                let best_weight_per_piece = (1..line_weights.len())
+                    .map(|n| line_weights[n] / (2.5 * n as f32))
+                    .fold(0.0, f32::max);
+                let max_weighted_lines = best_weight_per_piece * turn_limit;
+                if max_weighted_lines > 0.0 {
+                    weighted_lines / max_weighted_lines
+                } else {
+                    0.0
+                }
+            }
+            Self::Score {} => {
+                let max_score_per_piece = 800.0 / (4.0 * 2.5);
+                stats.game_stats.score() as f32 / (max_score_per_piece * turn_limit)
+            }
+            Self::HeightPenalty { cutoff, exponent } => {
+                let worst = excess(MAX_HEIGHT, *cutoff).powf(*exponent);
+                let sum = stats
+                    .max_height_map
+                    .iter()
+                    .map(|(h, c)| excess(f32::from(*h), *cutoff).powf(*exponent) * (*c as f32))
+                    .sum::<f32>()
+                    + worst * remaining_turns;
+                if worst > 0.0 {
+                    sum / (worst * turn_limit)
+                } else {
+                    0.0
+                }
+            }
+            Self::PeakHeightPenalty { cutoff, exponent } => {
+                let peak = stats
+                    .max_height_map
+                    .last_key_value()
+                    .map_or(0.0, |(h, _c)| excess(f32::from(*h), *cutoff));
+                peak.powf(*exponent) / MAX_HEIGHT.powf(*exponent)
+            }
+            Self::TimeAbove { height } => {
+                let turns_above = stats
+                    .max_height_map
+                    .range(height..)
+                    .map(|(_h, c)| *c as f32)
+                    .sum::<f32>();
+                (turns_above + remaining_turns) / turn_limit
+            }
+            Self::SpikeRecovery {
+                spike_height,
+                recovered_height,
+            } => {
+                let timeline = timeline.expect("timeline terms are evaluated with the timeline");
+                let recoveries = timeline.recovery_turns(*spike_height, *recovered_height);
+                if recoveries.is_empty() {
+                    return 0.0;
+                }
+                let sum = recoveries
+                    .iter()
+                    .map(|turns| turns.map_or(turn_limit, |t| t as f32))
+                    .sum::<f32>();
+                sum / (recoveries.len() as f32 * turn_limit)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        session_evaluator::{AggroSessionEvaluator, DefensiveSessionEvaluator},
+        turn_evaluator::{
+            SessionStats as _, TurnEvaluator,
+            tests::{NegativeHeightEvaluator, test_field},
+        },
+    };
+
+    fn evaluator(definition: FitnessDefinition) -> FitnessSessionEvaluator {
+        FitnessSessionEvaluator::new(definition).unwrap()
+    }
+
+    #[test]
+    fn test_presets_match_builtin_evaluators() {
+        let turn_evaluator = TurnEvaluator::new(Box::new(NegativeHeightEvaluator));
+        for seed in 0..3 {
+            let field = test_field(seed);
+            let stats: DefaultSessionStats = turn_evaluator.play_session(&mut field.clone(), 200);
+            // Turn limits beyond the survived turns exercise the game over handling
+            for turn_limit in [200, 1000] {
+                let expected =
+                    AggroSessionEvaluator::new().evaluate_session_stats(&field, &stats, turn_limit);
+                let actual = evaluator(FitnessDefinition::aggro())
+                    .evaluate_session_stats(&field, &stats, turn_limit);
+                assert!((expected - actual).abs() < 1e-5, "{expected} != {actual}");
+
+                let expected = DefensiveSessionEvaluator::new()
+                    .evaluate_session_stats(&field, &stats, turn_limit);
+                let actual = evaluator(FitnessDefinition::defensive())
+                    .evaluate_session_stats(&field, &stats, turn_limit);
+                assert!((expected - actual).abs() < 1e-5, "{expected} != {actual}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_timeline_evaluator_matches_plain() {
+        let turn_evaluator = TurnEvaluator::new(Box::new(NegativeHeightEvaluator));
+        let field = test_field(0);
+        let stats: TimelineSessionStats = turn_evaluator.play_session(&mut field.clone(), 200);
+        let expected = evaluator(FitnessDefinition::aggro()).evaluate_session_stats(
+            &field,
+            stats.default_stats(),
+            200,
+        );
+        let actual = TimelineFitnessSessionEvaluator::new(FitnessDefinition::aggro())
+            .unwrap()
+            .evaluate_session_stats(&field, &stats, 200);
+        assert!((expected - actual).abs() < 1e-6, "{expected} != {actual}");
+    }
+
+    #[test]
+    fn test_spike_recovery_term() {
+        let term = FitnessTerm::SpikeRecovery {
+            spike_height: 8,
+            recovered_height: 4,
+        };
+        let stats = DefaultSessionStats::new();
+        let timeline = SessionTimeline {
+            max_heights: vec![2, 6, 9, 7, 4, 3, 8, 10, 9],
+            ..SessionTimeline::default()
+        };
+        // One spike recovers in 2 turns, the other never: (2 + 10) / (2 × 10)
+        let value = term.compute(&stats, Some(&timeline), 10);
+        assert!((value - 0.6).abs() < 1e-6, "{value}");
+
+        let timeline = SessionTimeline {
+            max_heights: vec![2, 6, 4],
+            ..SessionTimeline::default()
+        };
+        assert!(term.compute(&stats, Some(&timeline), 10).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_invalid_definitions_are_rejected() {
+        let with_term = |term| FitnessDefinition {
+            bias: 0.0,
+            terms: vec![WeightedFitnessTerm { weight: 1.0, term }],
+        };
+        let invalid = [
+            FitnessTerm::SurvivalRatio { exponent: f32::NAN },
+            FitnessTerm::HeightPenalty {
+                cutoff: -1.0,
+                exponent: 2.0,
+            },
+            FitnessTerm::PeakHeightPenalty {
+                cutoff: 4.0,
+                exponent: -2.0,
+            },
+            FitnessTerm::SpikeRecovery {
+                spike_height: 8,
+                recovered_height: 8,
+            },
+        ];
+        for term in invalid {
+            let definition = with_term(term);
+            assert!(definition.validate().is_err(), "{definition:?}");
+            assert!(FitnessSessionEvaluator::new(definition.clone()).is_err());
+            assert!(TimelineFitnessSessionEvaluator::new(definition).is_err());
+        }
+
+        let definition = with_term(FitnessTerm::SpikeRecovery {
+            spike_height: 14,
+            recovered_height: 8,
+        });
+        assert_eq!(
+            FitnessSessionEvaluator::new(definition.clone()),
+            Err(FitnessDefinitionError::RequiresTimeline { term: 0 })
+        );
+        assert!(TimelineFitnessSessionEvaluator::new(definition).is_ok());
+    }
+
+    #[test]
+    fn test_unknown_fields_are_rejected() {
+        for json in [
+            r#"{ "terms": [], "bais": 1.0 }"#,
+            r#"{ "terms": [{ "type": "score", "weight": 1.0, "cutoff": 4 }] }"#,
+            r#"{ "terms": [{ "type": "height_penalty", "weight": 1.0, "cutof": 4 }] }"#,
+        ] {
+            assert!(
+                serde_json::from_str::<FitnessDefinition>(json).is_err(),
+                "{json}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_deserialize_definition() {
+        let json = r#"{
+            "terms": [
+                { "type": "survival_ratio", "weight": 1.0 },
+                { "type": "time_above", "weight": -0.5, "height": 10 },
+                { "type": "height_penalty", "weight": -1.0, "cutoff": 4 },
+                {
+                    "type": "spike_recovery", "weight": -0.5,
+                    "spike_height": 14, "recovered_height": 8
+                }
+            ]
+        }"#;
+        let definition: FitnessDefinition = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            definition,
+            FitnessDefinition {
+                bias: 0.0,
+                terms: vec![
+                    WeightedFitnessTerm {
+                        weight: 1.0,
+                        term: FitnessTerm::SurvivalRatio { exponent: 1.0 },
+                    },
+                    WeightedFitnessTerm {
+                        weight: -0.5,
+                        term: FitnessTerm::TimeAbove { height: 10 },
+                    },
+                    WeightedFitnessTerm {
+                        weight: -1.0,
+                        term: FitnessTerm::HeightPenalty {
+                            cutoff: 4.0,
+                            exponent: 2.0,
+                        },
+                    },
+                    WeightedFitnessTerm {
+                        weight: -0.5,
+                        term: FitnessTerm::SpikeRecovery {
+                            spike_height: 14,
+                            recovered_height: 8,
+                        },
+                    },
+                ],
+            }
+        );
+        assert!(definition.requires_timeline());
+    }
+}
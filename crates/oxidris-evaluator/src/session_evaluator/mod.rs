@@ -0,0 +1,994 @@
+//! Session evaluation: fitness functions for training AI models.
+//!
+//! This module implements the third level of the evaluator architecture: evaluating entire
+//! game sessions to compute fitness scores for genetic algorithm training. Different fitness
+//! functions produce models with different play styles.
+//!
+//! # How It Works
+//!
+//! Session evaluation involves:
+//!
+//! 1. **Play Session** - AI plays a complete game (up to turn limit)
+//! 2. **Collect Statistics** - Track survival time, lines cleared, max height, etc.
+//! 3. **Compute Fitness** - Apply fitness function to statistics
+//!
+//! The fitness score represents how "good" the AI played according to the fitness function's
+//! objectives. During training, the genetic algorithm evolves feature weights to maximize
+//! this fitness score.
+//!
+//! # Fitness Functions
+//!
+//! ## Aggro Session Evaluator
+//!
+//! Balances line clearing efficiency with height management:
+//!
+//! ```text
+//! fitness = (efficiency + (1 - max_height_penalty) + (1 - peak_max_height_penalty)) / 3
+//!
+//! where:
+//!   efficiency = weighted_lines / max_possible_weighted_lines
+//!   weighted_lines = Σ(line_clear_weight[i] × cleared_count[i])
+//!   line_clear_weight = [0, 1, 3, 5, 8] for 0-4 line clears
+//!   max_height_penalty = avg_squared_height_excess / worst_case_squared_height
+//!   peak_max_height_penalty = peak_height_excess² / max_height²
+//!   height_excess = max(height - cutoff, 0) where cutoff = 4.0
+//! ```
+//!
+//! **Characteristics:**
+//!
+//! - Rewards efficient line clearing with exponential weights (4-line Tetris gets 8× value)
+//! - Penalizes both average and peak height above cutoff (4.0)
+//! - Survival time indirectly penalized: early termination assumes worst-case height for remaining turns
+//! - Equal weight given to efficiency, average height quality, and peak height quality
+//!
+//! ## Defensive Session Evaluator
+//!
+//! Prioritizes height minimization above all else:
+//!
+//! ```text
+//! fitness = ((1 - max_height_penalty) + (1 - peak_max_height_penalty)) / 2
+//!
+//! where:
+//!   max_height_penalty = avg_squared_height / worst_case_squared_height
+//!   peak_max_height_penalty = peak_height² / max_height²
+//!   (no cutoff: all height is penalized)
+//! ```
+//!
+//! **Characteristics:**
+//!
+//! - No efficiency component (line clearing not directly rewarded)
+//! - Penalizes all height (cutoff = 0.0) for both average and peak
+//! - Survival time indirectly penalized (same mechanism as Aggro)
+//! - Focus: minimize height, clear lines only as means to reduce height
+//!
+//! ## Score and Time Session Evaluators
+//!
+//! These evaluators use [`LineClearSessionStats`], which additionally tracks when each line
+//! was cleared, T-spin clears, and back-to-back chains:
+//!
+//! - [`ScoreSessionEvaluator`] - Points per piece, relative to clearing only tetrises
+//! - [`SprintSessionEvaluator`] - Pieces needed to clear a target number of lines (40 by default)
+//! - [`TetrisRateSessionEvaluator`] - Lines cleared by tetrises per possible line
+//! - [`BackToBackSessionEvaluator`] - Tetrises and T-spin clears, with a bonus for
+//!   back-to-back chains
+//!
+//! All of them count early game overs against the turn limit (or the line target), so
+//! survival is rewarded only as far as it enables the objective.
+//!
+//! ## Configurable Fitness
+//!
+//! [`FitnessDefinition`] is a fitness function declared as a weighted sum of terms (survival
+//! ratio, weighted line clears, height penalties with cutoff and exponent, ...). Definitions
+//! are serializable, so fitness experiments can be loaded from config files instead of
+//! editing the constants of the built-in evaluators.
+//! [`FitnessSessionEvaluator`] evaluates a definition; definitions with terms computed from the
+//! per-turn timeline ([`FitnessTerm::SpikeRecovery`]) need [`TimelineFitnessSessionEvaluator`].
+//!
+//! # Timeline Statistics
+//!
+//! [`TimelineSessionStats`] records the board after every turn (max height, holes, cleared
+//! lines, and all feature source values) as a [`SessionTimeline`]. It is opt-in: evaluators
+//! that only need aggregates keep using the cheaper [`DefaultSessionStats`].
+//!
+//! # Design: Defining "Good Play"
+//!
+//! Different session evaluators define different objectives:
+//!
+//! - **Aggro**: "Good play means surviving while clearing lines efficiently"
+//! - **Defensive**: "Good play means surviving as long as possible"
+//! - **Score / Sprint / Tetris rate / Back-to-back**: "Good play means scoring (or clearing
+//!   lines) in a particular way"
+//!
+//! These objectives drive the genetic algorithm to learn different feature weights,
+//! resulting in models with different play styles despite using the same features.
+//!
+//! ## Design Rationale and Limitations
+//!
+//! **Current Approach:** The fitness formulas and coefficients (line clear weights `[0,1,3,5,8]`,
+//! height cutoffs, quadratic penalties) were chosen manually based on intuition about what "good
+//! play" means. The line clear weights encourage multi-line clears (especially 4-line Tetrises),
+//! and quadratic height penalties emphasize avoiding dangerous stacks.
+//!
+//! **Survival Time Consideration:** Survival time is indirectly penalized through a worst-case
+//! mechanism: if a game ends before `turn_limit`, remaining turns are counted as having maximum
+//! height (20.0), which increases the height penalty. This approach penalizes early termination
+//! without requiring explicit survival tracking.
+//!
+//! **Limitations:**
+//!
+//! - **No formal justification**: Coefficients and formulas lack theoretical or empirical validation
+//! - **Unknown optimality**: It's unclear if these formulas effectively capture desired play styles
+//! - **Indirect survival penalty**: Worst-case height assumption may not optimally balance survival vs other objectives
+//! - **Ad-hoc design**: Different evaluators use different formulas without consistent design principles
+//!
+//! The fitness function defines what the AI learns, so improvements to fitness design could
+//! significantly impact play quality. See the project documentation (`docs/future-projects.md`)
+//! for potential improvements, including systematic fitness function design and multi-objective
+//! optimization.
+//!
+//! # Usage
+//!
+//! ```rust,no_run
+//! use oxidris_evaluator::{
+//!     session_evaluator::{AggroSessionEvaluator, DefaultSessionEvaluator},
+//!     turn_evaluator::TurnEvaluator,
+//! };
+//!
+//! // Create session evaluator
+//! let fitness_fn = AggroSessionEvaluator::new();
+//! let session_evaluator = DefaultSessionEvaluator::new(1000, fitness_fn);
+//!
+//! // Evaluate multiple game sessions
+//! // (requires GameField vector - see oxidris_engine::GameField)
+//! // let fitness = session_evaluator.play_and_evaluate_sessions(&fields, &turn_evaluator);
+//! ```
+//!
+//! Session evaluators are used by the genetic algorithm (see `oxidris-training` crate)
+//! to evolve feature weights that maximize fitness.
+
+use std::{collections::BTreeMap, fmt, iter};
+
+use oxidris_engine::{GameField, GameStats};
+use serde::{Deserialize, Serialize};
+
+pub use self::fitness_definition::*;
+use crate::{
+    board_feature::source::{self, BoxedBoardFeatureSource},
+    placement_analysis::PlacementAnalysis,
+    turn_evaluator::{SessionStats, TurnEvaluator},
+};
+
+mod fitness_definition;
+
+/// Evaluates session statistics to compute fitness scores.
+///
+/// Implementations define what "good play" means by assigning fitness scores
+/// to game session statistics.
+pub trait EvaluateSessionStats {
+    /// Type of statistics tracked during the session.
+    type Stats: SessionStats;
+
+    /// Computes fitness score from session statistics.
+    ///
+    /// # Arguments
+    /// * `field` - Initial game field (for context)
+    /// * `stats` - Collected session statistics
+    /// * `turn_limit` - Maximum number of turns allowed
+    ///
+    /// # Returns
+    /// Fitness score (higher is better)
+    fn evaluate_session_stats(
+        &self,
+        field: &GameField,
+        stats: &Self::Stats,
+        turn_limit: usize,
+    ) -> f32;
+}
+
+/// Evaluates complete game sessions for training.
+///
+/// Used by the genetic algorithm to compute fitness scores for individuals.
+pub trait SessionEvaluator: fmt::Debug + Send + Sync {
+    /// Plays and evaluates a single game session.
+    fn play_and_evaluate_session(&self, field: &GameField, turn_evaluator: &TurnEvaluator) -> f32;
+
+    /// Plays and evaluates multiple game sessions, returning average fitness.
+    fn play_and_evaluate_sessions(
+        &self,
+        fields: &[GameField],
+        turn_evaluator: &TurnEvaluator,
+    ) -> f32;
+}
+
+/// Default session evaluator implementation.
+///
+/// Plays game sessions up to a turn limit and evaluates them using a fitness function.
+#[derive(Debug)]
+pub struct DefaultSessionEvaluator<E> {
+    turn_limit: usize,
+    evaluator: E,
+}
+
+impl<E> DefaultSessionEvaluator<E> {
+    /// Creates a new session evaluator.
+    ///
+    /// # Arguments
+    /// * `turn_limit` - Maximum number of turns per game session
+    /// * `evaluator` - Fitness function to evaluate session statistics
+    pub fn new(turn_limit: usize, evaluator: E) -> Self {
+        Self {
+            turn_limit,
+            evaluator,
+        }
+    }
+}
+
+impl<S, E> SessionEvaluator for DefaultSessionEvaluator<E>
+where
+    E: EvaluateSessionStats<Stats = S> + fmt::Debug + Send + Sync,
+    S: SessionStats,
+{
+    fn play_and_evaluate_session(&self, field: &GameField, turn_evaluator: &TurnEvaluator) -> f32 {
+        let stats = turn_evaluator.play_session(&mut field.clone(), self.turn_limit);
+        self.evaluator
+            .evaluate_session_stats(field, &stats, self.turn_limit)
+    }
+
+    #[expect(clippy::cast_precision_loss)]
+    fn play_and_evaluate_sessions(
+        &self,
+        fields: &[GameField],
+        turn_evaluator: &TurnEvaluator,
+    ) -> f32 {
+        let mut total_fitness = 0.0;
+        for field in fields {
+            total_fitness += self.play_and_evaluate_session(field, turn_evaluator);
+        }
+        total_fitness / (fields.len() as f32)
+    }
+}
+
+/// Evaluates complete game sessions by several objectives at once.
+///
+/// Used by multi-objective training (e.g., NSGA-II) to compute one score per objective
+/// without playing the sessions once per objective.
+pub trait MultiObjectiveSessionEvaluator: fmt::Debug + Send + Sync {
+    /// Returns the number of objectives.
+    fn num_objectives(&self) -> usize;
+
+    /// Plays and evaluates multiple game sessions, returning the average of each objective.
+    fn play_and_evaluate_sessions(
+        &self,
+        fields: &[GameField],
+        turn_evaluator: &TurnEvaluator,
+    ) -> Vec<f32>;
+}
+
+/// Default multi-objective session evaluator implementation.
+///
+/// Plays game sessions up to a turn limit and evaluates each of them with every objective
+/// function. All objectives share the same session statistics type.
+#[derive(Debug)]
+pub struct DefaultMultiObjectiveSessionEvaluator<E> {
+    turn_limit: usize,
+    objectives: Vec<E>,
+}
+
+impl<E> DefaultMultiObjectiveSessionEvaluator<E> {
+    /// Creates a new multi-objective session evaluator.
+    ///
+    /// # Arguments
+    /// * `turn_limit` - Maximum number of turns per game session
+    /// * `objectives` - Fitness functions, one per objective
+    #[must_use]
+    pub fn new(turn_limit: usize, objectives: Vec<E>) -> Self {
+        Self {
+            turn_limit,
+            objectives,
+        }
+    }
+}
+
+impl<S, E> MultiObjectiveSessionEvaluator for DefaultMultiObjectiveSessionEvaluator<E>
+where
+    E: EvaluateSessionStats<Stats = S> + fmt::Debug + Send + Sync,
+    S: SessionStats,
+{
+    fn num_objectives(&self) -> usize {
+        self.objectives.len()
+    }
+
+    #[expect(clippy::cast_precision_loss)]
+    fn play_and_evaluate_sessions(
+        &self,
+        fields: &[GameField],
+        turn_evaluator: &TurnEvaluator,
+    ) -> Vec<f32> {
+        let mut totals = vec![0.0; self.objectives.len()];
+        for field in fields {
+            let stats: S = turn_evaluator.play_session(&mut field.clone(), self.turn_limit);
+            for (total, objective) in iter::zip(&mut totals, &self.objectives) {
+                *total += objective.evaluate_session_stats(field, &stats, self.turn_limit);
+            }
+        }
+        for total in &mut totals {
+            *total /= fields.len() as f32;
+        }
+        totals
+    }
+}
+
+/// Default session statistics tracker.
+///
+/// Tracks game statistics (pieces placed, lines cleared) and worst max height
+/// during the session.
+#[derive(Debug)]
+pub struct DefaultSessionStats {
+    game_stats: GameStats,
+    max_height_map: BTreeMap<u8, usize>,
+    total_height_map: BTreeMap<u8, usize>,
+}
+
+impl SessionStats for DefaultSessionStats {
+    fn new() -> Self {
+        Self {
+            game_stats: GameStats::new(),
+            max_height_map: BTreeMap::new(),
+            total_height_map: BTreeMap::new(),
+        }
+    }
+
+    fn complete_piece_drop(&mut self, analysis: &PlacementAnalysis) {
+        self.game_stats
+            .complete_piece_drop(analysis.cleared_lines());
+        *self
+            .max_height_map
+            .entry(analysis.board_analysis().max_height())
+            .or_default() += 1;
+        *self
+            .total_height_map
+            .entry(analysis.board_analysis().total_height())
+            .or_default() += 1;
+    }
+}
+
+/// Aggressive fitness function: balances survival with line clearing efficiency.
+///
+/// **Formula:**
+/// ```text
+/// fitness = survival_bonus + efficiency × survival_ratio - height_penalty
+/// ```
+///
+/// This evaluator encourages the AI to:
+/// - Survive as long as possible (quadratic bonus)
+/// - Clear lines efficiently, especially multi-line clears
+/// - Avoid building risky high stacks
+/// - Maintain sustained performance (efficiency scaled by survival)
+#[derive(Default, Debug)]
+pub struct AggroSessionEvaluator {}
+
+impl AggroSessionEvaluator {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl EvaluateSessionStats for AggroSessionEvaluator {
+    type Stats = DefaultSessionStats;
+
+    #[expect(clippy::cast_precision_loss)]
+    fn evaluate_session_stats(
+        &self,
+        _field: &GameField,
+        stats: &Self::Stats,
+        turn_limit: usize,
+    ) -> f32 {
+        const LINE_CLEAR_WEIGHT: [u16; 5] = [0, 1, 3, 5, 8];
+        let survived_turns = stats.game_stats.turn() as f32;
+        let turn_limit = turn_limit as f32;
+
+        let block_count = turn_limit * 4.0;
+        let weighted_line_count =
+            iter::zip(LINE_CLEAR_WEIGHT, stats.game_stats.line_cleared_counter())
+                .map(|(w, c)| f32::from(w) * (*c as f32))
+                .sum::<f32>();
+        let max_line_score = 8.0 * block_count / 40.0;
+        let efficiency = weighted_line_count / max_line_score;
+
+        let max_height_max: f32 = 20.0;
+        let max_height_cutoff = 4.0;
+        let mut max_height_square_sum = stats
+            .max_height_map
+            .iter()
+            .map(|(h, c)| {
+                let h = f32::from(*h) - max_height_cutoff;
+                if h < 0.0 {
+                    0.0
+                } else {
+                    h.powi(2) * (*c as f32)
+                }
+            })
+            .sum::<f32>();
+        if survived_turns < turn_limit {
+            // Penalize unfinished games by assuming max height for remaining turns
+            let remaining_turns = turn_limit - survived_turns;
+            max_height_square_sum += (max_height_max - max_height_cutoff).powi(2) * remaining_turns;
+        }
+
+        let worst_max_height = (max_height_max - max_height_cutoff).powi(2) * turn_limit;
+        let max_height_penalty = max_height_square_sum / worst_max_height;
+
+        let peak_max_height = stats
+            .max_height_map
+            .last_key_value()
+            .map_or(0.0, |(h, _c)| {
+                let h = f32::from(*h) - max_height_cutoff;
+                if h < 0.0 { 0.0 } else { h }
+            });
+        let peak_max_height_penalty = peak_max_height.powi(2) / max_height_max.powi(2);
+
+        (efficiency + (1.0 - max_height_penalty) + (1.0 - peak_max_height_penalty)) / 3.0
+    }
+}
+
+/// Defensive fitness function: prioritizes survival time above all else.
+///
+/// **Formula:**
+/// ```text
+/// fitness = survival_bonus + efficiency / 10.0 - height_penalty
+/// ```
+///
+/// This evaluator encourages the AI to:
+/// - Maximize survival time (quadratic bonus, same as Aggro)
+/// - Clear lines as a secondary objective (10× lower weight)
+/// - Tolerate higher stacks (smaller penalty)
+/// - Play conservatively to extend survival
+#[derive(Default, Debug)]
+pub struct DefensiveSessionEvaluator {}
+
+impl DefensiveSessionEvaluator {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl EvaluateSessionStats for DefensiveSessionEvaluator {
+    type Stats = DefaultSessionStats;
+
+    #[expect(clippy::cast_precision_loss, clippy::manual_midpoint)]
+    fn evaluate_session_stats(
+        &self,
+        _field: &GameField,
+        stats: &Self::Stats,
+        turn_limit: usize,
+    ) -> f32 {
+        let survived_turns = stats.game_stats.turn() as f32;
+        let turn_limit = turn_limit as f32;
+
+        let max_height_max: f32 = 20.0;
+        let max_height_cutoff = 0.0;
+        let mut max_height_square_sum = stats
+            .max_height_map
+            .iter()
+            .map(|(h, c)| {
+                let h = f32::from(*h) - max_height_cutoff;
+                if h < 0.0 {
+                    0.0
+                } else {
+                    h.powi(2) * (*c as f32)
+                }
+            })
+            .sum::<f32>();
+        if survived_turns < turn_limit {
+            // Penalize unfinished games by assuming max height for remaining turns
+            let remaining_turns = turn_limit - survived_turns;
+            max_height_square_sum += (max_height_max - max_height_cutoff).powi(2) * remaining_turns;
+        }
+
+        let worst_max_height = (max_height_max - max_height_cutoff).powi(2) * turn_limit;
+        let max_height_penalty = max_height_square_sum / worst_max_height;
+
+        let peak_max_height = stats
+            .max_height_map
+            .last_key_value()
+            .map_or(0.0, |(h, _c)| {
+                let h = f32::from(*h) - max_height_cutoff;
+                if h < 0.0 { 0.0 } else { h }
+            });
+        let peak_max_height_penalty = peak_max_height.powi(2) / max_height_max.powi(2);
+
+        ((1.0 - max_height_penalty) + (1.0 - peak_max_height_penalty)) / 2.0
+    }
+}
+
+/// Line clear statistics tracker.
+///
+/// Tracks game statistics together with the turn at which each line was cleared, T-spin
+/// clears, and back-to-back chains of difficult clears (tetrises and T-spin clears, as in
+/// the guideline scoring). Used by the score- and time-oriented fitness functions.
+#[derive(Debug)]
+pub struct LineClearSessionStats {
+    game_stats: GameStats,
+    line_clear_turns: Vec<usize>,
+    t_spin_clears: usize,
+    t_spin_lines: usize,
+    difficult_clears: usize,
+    back_to_back_clears: usize,
+    in_back_to_back: bool,
+}
+
+impl LineClearSessionStats {
+    /// Returns the game statistics (turns, score, line clear counts).
+    #[must_use]
+    pub fn game_stats(&self) -> &GameStats {
+        &self.game_stats
+    }
+
+    /// Returns the number of pieces placed until `lines` lines were cleared in total.
+    ///
+    /// Returns `None` if fewer lines were cleared.
+    #[must_use]
+    pub fn turns_to_clear(&self, lines: usize) -> Option<usize> {
+        lines
+            .checked_sub(1)
+            .and_then(|i| self.line_clear_turns.get(i))
+            .copied()
+    }
+
+    /// Returns the number of T-spin placements that cleared lines.
+    #[must_use]
+    pub fn t_spin_clears(&self) -> usize {
+        self.t_spin_clears
+    }
+
+    /// Returns the number of lines cleared by T-spins.
+    #[must_use]
+    pub fn t_spin_lines(&self) -> usize {
+        self.t_spin_lines
+    }
+
+    /// Returns the number of difficult clears (tetrises and T-spin clears).
+    #[must_use]
+    pub fn difficult_clears(&self) -> usize {
+        self.difficult_clears
+    }
+
+    /// Returns the number of difficult clears directly following another difficult clear.
+    #[must_use]
+    pub fn back_to_back_clears(&self) -> usize {
+        self.back_to_back_clears
+    }
+
+    fn record_line_clear(&mut self, cleared_lines: usize, t_spin: bool) {
+        let turn = self.game_stats.turn();
+        self.line_clear_turns
+            .extend(iter::repeat_n(turn, cleared_lines));
+        if cleared_lines == 0 {
+            return;
+        }
+        if t_spin {
+            self.t_spin_clears += 1;
+            self.t_spin_lines += cleared_lines;
+        }
+        let difficult = t_spin || cleared_lines == 4;
+        if difficult {
+            self.difficult_clears += 1;
+            if self.in_back_to_back {
+                self.back_to_back_clears += 1;
+            }
+        }
+        self.in_back_to_back = difficult;
+    }
+}
+
+impl SessionStats for LineClearSessionStats {
+    fn new() -> Self {
+        Self {
+            game_stats: GameStats::new(),
+            line_clear_turns: vec![],
+            t_spin_clears: 0,
+            t_spin_lines: 0,
+            difficult_clears: 0,
+            back_to_back_clears: 0,
+            in_back_to_back: false,
+        }
+    }
+
+    fn complete_piece_drop(&mut self, analysis: &PlacementAnalysis) {
+        self.game_stats
+            .complete_piece_drop(analysis.cleared_lines());
+        self.record_line_clear(analysis.cleared_lines(), analysis.is_t_spin());
+    }
+}
+
+/// Per-turn timeline statistics tracker.
+///
+/// Records the board after every turn as a [`SessionTimeline`]: max height, holes, cleared
+/// lines, and the raw value of every board feature source. Session evaluators can derive
+/// trajectory-based objectives from it (e.g., time above a height threshold, recovery after
+/// height spikes), and analysis tools can plot the course of a game.
+///
+/// Extracting all feature sources each turn costs more than the histograms of
+/// [`DefaultSessionStats`], so only use this type when the timeline is needed.
+#[derive(Debug)]
+pub struct TimelineSessionStats {
+    stats: DefaultSessionStats,
+    sources: Vec<BoxedBoardFeatureSource>,
+    timeline: SessionTimeline,
+}
+
+/// Board trajectory of a game session, one entry per turn.
+///
+/// Entry `i` of every series describes the board after the `i + 1`-th placement.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionTimeline {
+    pub max_heights: Vec<u8>,
+    pub num_holes: Vec<u8>,
+    pub cleared_lines: Vec<u8>,
+    /// Raw values of each board feature source
+    pub features: Vec<FeatureTimeline>,
+}
+
+/// Raw values of a board feature source over a game session.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureTimeline {
+    /// Feature source ID (e.g., `num_holes`)
+    pub id: String,
+    pub values: Vec<u32>,
+}
+
+impl TimelineSessionStats {
+    /// Returns the game statistics (turns, score, line clear counts).
+    #[must_use]
+    pub fn game_stats(&self) -> &GameStats {
+        &self.stats.game_stats
+    }
+
+    /// Returns the aggregate statistics tracked alongside the timeline.
+    #[must_use]
+    pub fn default_stats(&self) -> &DefaultSessionStats {
+        &self.stats
+    }
+
+    #[must_use]
+    pub fn timeline(&self) -> &SessionTimeline {
+        &self.timeline
+    }
+
+    #[must_use]
+    pub fn into_timeline(self) -> SessionTimeline {
+        self.timeline
+    }
+}
+
+impl SessionStats for TimelineSessionStats {
+    fn new() -> Self {
+        let sources = source::all_board_feature_sources();
+        let features = sources
+            .iter()
+            .map(|s| FeatureTimeline {
+                id: s.id().to_owned(),
+                values: vec![],
+            })
+            .collect();
+        Self {
+            stats: DefaultSessionStats::new(),
+            sources,
+            timeline: SessionTimeline {
+                features,
+                ..SessionTimeline::default()
+            },
+        }
+    }
+
+    fn complete_piece_drop(&mut self, analysis: &PlacementAnalysis) {
+        self.stats.complete_piece_drop(analysis);
+        let board = analysis.board_analysis();
+        let timeline = &mut self.timeline;
+        timeline.max_heights.push(board.max_height());
+        timeline.num_holes.push(board.num_holes());
+        timeline
+            .cleared_lines
+            .push(u8::try_from(analysis.cleared_lines()).unwrap());
+        for (source, feature) in iter::zip(&self.sources, &mut timeline.features) {
+            feature.values.push(source.extract_raw(analysis));
+        }
+    }
+}
+
+impl SessionTimeline {
+    /// Returns the number of recorded turns.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.max_heights.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.max_heights.is_empty()
+    }
+
+    /// Returns the values of the feature source with the given ID.
+    #[must_use]
+    pub fn feature(&self, id: &str) -> Option<&[u32]> {
+        self.features
+            .iter()
+            .find(|f| f.id == id)
+            .map(|f| f.values.as_slice())
+    }
+
+    /// Returns the number of turns that ended with the max height at or above `height`.
+    #[must_use]
+    pub fn turns_at_or_above(&self, height: u8) -> usize {
+        self.max_heights.iter().filter(|h| **h >= height).count()
+    }
+
+    /// Returns how many turns each height spike took to recover.
+    ///
+    /// A spike starts when the max height reaches `spike_height` and ends at the first
+    /// turn whose max height is at or below `recovered_height`. The result has one entry per
+    /// spike, `None` if the session ended before the stack recovered.
+    #[must_use]
+    pub fn recovery_turns(&self, spike_height: u8, recovered_height: u8) -> Vec<Option<usize>> {
+        let mut result = vec![];
+        let mut spike_start = None;
+        for (turn, height) in self.max_heights.iter().enumerate() {
+            match spike_start {
+                None if *height >= spike_height => spike_start = Some(turn),
+                Some(start) if *height <= recovered_height => {
+                    result.push(Some(turn - start));
+                    spike_start = None;
+                }
+                _ => {}
+            }
+        }
+        if spike_start.is_some() {
+            result.push(None);
+        }
+        result
+    }
+}
+
+/// Number of pieces needed to clear one line (10 cells per line, 4 cells per piece).
+const PIECES_PER_LINE: f32 = 2.5;
+
+/// Score fitness function: points earned per piece.
+///
+/// **Formula:**
+/// ```text
+/// fitness = score / (turn_limit × max_score_per_piece)
+/// max_score_per_piece = 800 / (4 × 2.5)   (tetrises only)
+/// ```
+///
+/// Normalizing by the turn limit rather than the survived turns makes early game overs
+/// cost the points that could have been earned in the remaining turns.
+#[derive(Default, Debug)]
+pub struct ScoreSessionEvaluator {}
+
+impl ScoreSessionEvaluator {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl EvaluateSessionStats for ScoreSessionEvaluator {
+    type Stats = LineClearSessionStats;
+
+    #[expect(clippy::cast_precision_loss)]
+    fn evaluate_session_stats(
+        &self,
+        _field: &GameField,
+        stats: &Self::Stats,
+        turn_limit: usize,
+    ) -> f32 {
+        let max_score_per_piece = 800.0 / (4.0 * PIECES_PER_LINE);
+        stats.game_stats.score() as f32 / (turn_limit as f32 * max_score_per_piece)
+    }
+}
+
+/// Sprint fitness function: pieces needed to clear a target number of lines.
+///
+/// **Formula:**
+/// ```text
+/// fitness = 0.5 + 0.5 × min_pieces / pieces_used     (target reached)
+/// fitness = 0.5 × cleared_lines / target_lines        (otherwise)
+/// min_pieces = target_lines × 2.5
+/// ```
+///
+/// Any session reaching the target ranks above all sessions that do not, so the turn limit
+/// should leave room for reaching the target (e.g., 40 lines need at least 100 pieces).
+#[derive(Debug)]
+pub struct SprintSessionEvaluator {
+    target_lines: usize,
+}
+
+impl Default for SprintSessionEvaluator {
+    fn default() -> Self {
+        Self::new(40)
+    }
+}
+
+impl SprintSessionEvaluator {
+    #[must_use]
+    pub const fn new(target_lines: usize) -> Self {
+        Self { target_lines }
+    }
+}
+
+impl EvaluateSessionStats for SprintSessionEvaluator {
+    type Stats = LineClearSessionStats;
+
+    #[expect(clippy::cast_precision_loss)]
+    fn evaluate_session_stats(
+        &self,
+        _field: &GameField,
+        stats: &Self::Stats,
+        _turn_limit: usize,
+    ) -> f32 {
+        let target_lines = self.target_lines as f32;
+        match stats.turns_to_clear(self.target_lines) {
+            Some(pieces_used) => {
+                let min_pieces = target_lines * PIECES_PER_LINE;
+                0.5 + 0.5 * (min_pieces / pieces_used as f32).min(1.0)
+            }
+            None => 0.5 * stats.game_stats.cleared_lines() as f32 / target_lines,
+        }
+    }
+}
+
+/// Tetris rate fitness function: share of the possible lines cleared by tetrises.
+///
+/// **Formula:**
+/// ```text
+/// fitness = tetris_rate × cleared_lines / max_lines = 4 × tetrises / max_lines
+/// tetris_rate = 4 × tetrises / cleared_lines
+/// max_lines = turn_limit / 2.5
+/// ```
+///
+/// Weighting the tetris rate by the cleared lines keeps a single tetris followed by a game
+/// over from reaching a perfect rate.
+#[derive(Default, Debug)]
+pub struct TetrisRateSessionEvaluator {}
+
+impl TetrisRateSessionEvaluator {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl EvaluateSessionStats for TetrisRateSessionEvaluator {
+    type Stats = LineClearSessionStats;
+
+    #[expect(clippy::cast_precision_loss)]
+    fn evaluate_session_stats(
+        &self,
+        _field: &GameField,
+        stats: &Self::Stats,
+        turn_limit: usize,
+    ) -> f32 {
+        let tetris_lines = 4.0 * stats.game_stats.line_cleared_counter()[4] as f32;
+        let max_lines = turn_limit as f32 / PIECES_PER_LINE;
+        tetris_lines / max_lines
+    }
+}
+
+/// Back-to-back fitness function: rewards chains of tetrises and T-spin clears.
+///
+/// **Formula:**
+/// ```text
+/// fitness = (Σ difficult_clear_points + back_to_back_clears) / max_points
+/// difficult_clear_points = 4 (tetris), 2 × cleared_lines (T-spin clear)
+/// max_points = 5 × turn_limit / 10   (back-to-back tetrises only)
+/// ```
+///
+/// T-spin clears earn twice their lines like in the guideline scoring. Placements are only
+/// dropped straight down, so T-spins are limited to slots reachable by a drop (e.g., against
+/// walls), and tetrises make up most of the difficult clears.
+#[derive(Default, Debug)]
+pub struct BackToBackSessionEvaluator {}
+
+impl BackToBackSessionEvaluator {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl EvaluateSessionStats for BackToBackSessionEvaluator {
+    type Stats = LineClearSessionStats;
+
+    #[expect(clippy::cast_precision_loss)]
+    fn evaluate_session_stats(
+        &self,
+        _field: &GameField,
+        stats: &Self::Stats,
+        turn_limit: usize,
+    ) -> f32 {
+        let tetrises = stats.game_stats.line_cleared_counter()[4];
+        let points = 4.0 * tetrises as f32
+            + 2.0 * stats.t_spin_lines as f32
+            + stats.back_to_back_clears as f32;
+        let max_points = 5.0 * turn_limit as f32 / (4.0 * PIECES_PER_LINE);
+        points / max_points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::turn_evaluator::tests::{NegativeHeightEvaluator, test_field};
+
+    #[test]
+    fn test_timeline_stats() {
+        let turn_evaluator = TurnEvaluator::new(Box::new(NegativeHeightEvaluator));
+        let mut field = test_field(0);
+        let stats: TimelineSessionStats = turn_evaluator.play_session(&mut field, 50);
+
+        let timeline = stats.timeline();
+        assert_eq!(timeline.len(), stats.game_stats().turn());
+        assert_eq!(timeline.num_holes.len(), timeline.len());
+        assert_eq!(
+            timeline
+                .cleared_lines
+                .iter()
+                .map(|c| usize::from(*c))
+                .sum::<usize>(),
+            stats.game_stats().cleared_lines()
+        );
+        assert_eq!(
+            timeline.features.len(),
+            source::all_board_feature_sources().len()
+        );
+        let max_heights = timeline.feature("max_height").unwrap();
+        assert!(iter::zip(max_heights, &timeline.max_heights).all(|(a, b)| *a == u32::from(*b)));
+        assert_eq!(timeline.feature("no_such_feature"), None);
+    }
+
+    #[test]
+    fn test_timeline_height_metrics() {
+        let timeline = SessionTimeline {
+            max_heights: vec![2, 6, 9, 7, 4, 3, 8, 10, 9],
+            ..SessionTimeline::default()
+        };
+        assert_eq!(timeline.turns_at_or_above(8), 4);
+        assert_eq!(timeline.turns_at_or_above(0), timeline.len());
+        // Spike at turn 2 recovers at turn 4; spike at turn 6 never recovers
+        assert_eq!(timeline.recovery_turns(8, 4), [Some(2), None]);
+        assert_eq!(timeline.recovery_turns(11, 4), []);
+    }
+
+    #[test]
+    fn test_line_clear_stats() {
+        let mut stats = LineClearSessionStats::new();
+        for (cleared_lines, t_spin) in [
+            (1, false),
+            (4, false),
+            (0, false),
+            (2, true),
+            (4, false),
+            (2, false),
+            (4, false),
+            (1, true),
+        ] {
+            stats.game_stats.complete_piece_drop(cleared_lines);
+            stats.record_line_clear(cleared_lines, t_spin);
+        }
+
+        assert_eq!(stats.t_spin_clears(), 2);
+        assert_eq!(stats.t_spin_lines(), 3);
+        assert_eq!(stats.difficult_clears(), 5);
+        // Non-clearing placements keep the chain, other clears break it
+        assert_eq!(stats.back_to_back_clears(), 3);
+
+        assert_eq!(stats.turns_to_clear(0), None);
+        assert_eq!(stats.turns_to_clear(1), Some(1));
+        assert_eq!(stats.turns_to_clear(5), Some(2));
+        assert_eq!(stats.turns_to_clear(6), Some(4));
+        assert_eq!(stats.turns_to_clear(18), Some(8));
+        assert_eq!(stats.turns_to_clear(19), None);
+    }
+}
@@ -10,8 +10,24 @@
 //!
 //! 1. **Enumerate Placements** - Generate all valid placements for current and hold pieces
 //! 2. **Score Each Placement** - Use placement evaluator to score each option
+//!    (board metrics of the current board are computed once and updated incrementally
+//!    for each placement)
 //! 3. **Select Best** - Choose the placement with the highest score
 //!
+//! # Candidate Ranking and Stochastic Selection
+//!
+//! [`TurnEvaluator::evaluate_turns`] returns every scored candidate sorted by descending
+//! score, which is useful for inspecting near-ties between placements.
+//!
+//! [`TurnEvaluator::select_turn`] chooses among those candidates according to a
+//! [`TurnSelection`] strategy:
+//!
+//! - [`TurnSelection::Greedy`] - Always the highest score (same as `select_best_turn`)
+//! - [`TurnSelection::Softmax`] - Sample with probability `∝ exp(score / temperature)`
+//! - [`TurnSelection::EpsilonGreedy`] - Uniformly random candidate with probability `epsilon`
+//!
+//! Stochastic selection is used to generate more diverse boards for training data.
+//!
 //! # Turn Plan
 //!
 //! A [`TurnPlan`] specifies the complete action for a turn:
@@ -61,12 +77,18 @@
 //! // }
 //! ```
 
-use std::iter;
+use std::{cmp::Ordering, iter};
 
 use arrayvec::ArrayVec;
 use oxidris_engine::{BitBoard, CompletePieceDropError, GameField, GameStats, Piece};
+use rand::Rng;
+use rayon::prelude::*;
 
-use crate::{placement_analysis::PlacementAnalysis, placement_evaluator::PlacementEvaluator};
+use crate::{
+    board_analysis::BoardAnalysis,
+    placement_analysis::{PieceQueue, PlacementAnalysis},
+    placement_evaluator::PlacementEvaluator,
+};
 
 /// Statistics tracking for game sessions.
 ///
@@ -112,6 +134,22 @@ impl TurnPlan {
         self.placement
     }
 
+    /// Analyzes the placement of this turn plan on the game field.
+    ///
+    /// The returned analysis includes the [`PieceQueue`] after the turn.
+    #[must_use]
+    pub fn analyze(&self, field: &GameField) -> PlacementAnalysis {
+        PlacementAnalysis::from_board(field.board(), self.placement)
+            .with_queue(PieceQueue::after_turn(field, self.use_hold))
+    }
+
+    /// Like [`analyze`](Self::analyze), but derives the board metrics from `parent`, the
+    /// analysis of the current board shared by all candidates of the turn.
+    fn analyze_from(self, parent: &BoardAnalysis, field: &GameField) -> PlacementAnalysis {
+        PlacementAnalysis::from_parent(parent, self.placement)
+            .with_queue(PieceQueue::after_turn(field, self.use_hold))
+    }
+
     /// Applies this turn plan to the game field.
     ///
     /// # Arguments
@@ -143,24 +181,132 @@ impl TurnPlan {
     }
 }
 
+/// A turn candidate together with its placement analysis and score.
+#[derive(Debug)]
+pub struct ScoredTurn {
+    /// The turn plan of this candidate.
+    pub plan: TurnPlan,
+    /// Placement analysis of the resulting board.
+    pub analysis: PlacementAnalysis,
+    /// Score assigned by the placement evaluator.
+    pub score: f32,
+}
+
+/// Strategy for choosing a turn among the scored candidates.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TurnSelection {
+    /// Always choose the candidate with the highest score.
+    #[default]
+    Greedy,
+    /// Sample a candidate with probability proportional to `exp(score / temperature)`.
+    ///
+    /// Lower temperatures approach greedy selection; a non-positive temperature is
+    /// treated as greedy.
+    Softmax { temperature: f32 },
+    /// Choose a uniformly random candidate with probability `epsilon`, otherwise the best.
+    EpsilonGreedy { epsilon: f32 },
+}
+
+impl TurnSelection {
+    /// Chooses one candidate from a list sorted by descending score.
+    ///
+    /// Returns `None` if `candidates` is empty.
+    pub fn choose<R>(self, mut candidates: Vec<ScoredTurn>, rng: &mut R) -> Option<ScoredTurn>
+    where
+        R: Rng + ?Sized,
+    {
+        if candidates.is_empty() {
+            return None;
+        }
+        let index = match self {
+            TurnSelection::Greedy => 0,
+            TurnSelection::Softmax { temperature } => {
+                if temperature > 0.0 && temperature.is_finite() {
+                    softmax_sample(&candidates, temperature, rng)
+                } else {
+                    0
+                }
+            }
+            TurnSelection::EpsilonGreedy { epsilon } => {
+                if rng.random_bool(f64::from(epsilon.clamp(0.0, 1.0))) {
+                    rng.random_range(0..candidates.len())
+                } else {
+                    0
+                }
+            }
+        };
+        Some(candidates.swap_remove(index))
+    }
+}
+
+fn softmax_sample<R>(candidates: &[ScoredTurn], temperature: f32, rng: &mut R) -> usize
+where
+    R: Rng + ?Sized,
+{
+    // Candidates are sorted, so the first score is the maximum; subtracting it keeps
+    // `exp` from overflowing.
+    let max_score = candidates[0].score;
+    let weights = candidates
+        .iter()
+        .map(|c| ((c.score - max_score) / temperature).exp())
+        .map(|w| if w.is_finite() { w } else { 0.0 })
+        .collect::<Vec<_>>();
+    let total: f32 = weights.iter().sum();
+    if total <= 0.0 || !total.is_finite() {
+        return 0;
+    }
+
+    let mut threshold = rng.random_range(0.0..total);
+    for (i, weight) in weights.iter().enumerate() {
+        if threshold < *weight {
+            return i;
+        }
+        threshold -= weight;
+    }
+    0
+}
+
 /// Evaluates and selects the best placement for the current turn.
 ///
 /// Uses a placement evaluator to score all possible placements and selects
 /// the one with the highest score.
+///
+/// Candidates are scored as a batch (see
+/// [`PlacementEvaluator::evaluate_placements`]). With
+/// [`with_parallel`](Self::with_parallel), chunks of candidates are analyzed and scored on
+/// the rayon thread pool; the results are the same as with sequential evaluation.
+/// Evaluators whose scores depend on the whole batch
+/// ([`PlacementEvaluator::is_batch_relative`]) are always scored sequentially.
 #[derive(Debug)]
 pub struct TurnEvaluator<'a> {
     placement_evaluator: Box<dyn PlacementEvaluator + 'a>,
+    parallel: bool,
 }
 
+/// Number of candidates analyzed and scored together by one parallel task.
+const PARALLEL_CHUNK_LEN: usize = 16;
+
 impl<'a> TurnEvaluator<'a> {
     /// Creates a new turn evaluator with the given placement evaluator.
     #[must_use]
     pub fn new(placement_evaluator: Box<dyn PlacementEvaluator + 'a>) -> Self {
         Self {
             placement_evaluator,
+            parallel: false,
         }
     }
 
+    /// Enables or disables parallel evaluation of the candidates of a turn.
+    ///
+    /// Parallelism pays off when a single game is played as fast as possible (e.g., auto
+    /// play). When many games are played at once (e.g., training), parallelize over games
+    /// instead.
+    #[must_use]
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
     /// Selects the best turn for the current game state.
     ///
     /// Evaluates all possible placements (with and without hold) and returns
@@ -178,19 +324,122 @@ impl<'a> TurnEvaluator<'a> {
         field: &GameField,
         hold_available: bool,
     ) -> Option<(TurnPlan, PlacementAnalysis)> {
-        let mut best_score = f32::MIN;
-        let mut best_result = None;
-
-        for turn in available_turns(field, hold_available).into_iter().flatten() {
-            let analysis = PlacementAnalysis::from_board(field.board(), turn.placement());
-            let score = self.placement_evaluator.evaluate_placement(&analysis);
-            if score > best_score {
-                best_score = score;
-                best_result = Some((turn, analysis));
+        let mut best: Option<ScoredTurn> = None;
+        for turn in self.score_turns(field, hold_available) {
+            if best
+                .as_ref()
+                .is_none_or(|b| compare_scores(turn.score, b.score).is_gt())
+            {
+                best = Some(turn);
             }
         }
+        best.map(|turn| (turn.plan, turn.analysis))
+    }
+
+    /// Evaluates all possible turns for the current game state.
+    ///
+    /// # Arguments
+    /// * `field` - Current game field state
+    /// * `hold_available` - Whether hold is available for this turn
+    ///
+    /// # Returns
+    /// All candidates sorted by descending score, with NaN scores last. Candidates with
+    /// equal scores keep their enumeration order, so the first element is the turn that
+    /// [`select_best_turn`](Self::select_best_turn) would return.
+    #[must_use]
+    pub fn evaluate_turns(&self, field: &GameField, hold_available: bool) -> Vec<ScoredTurn> {
+        let mut candidates = self.score_turns(field, hold_available);
+        candidates.sort_by(|a, b| compare_scores(b.score, a.score));
+        candidates
+    }
+
+    /// Scores all possible turns, in enumeration order.
+    fn score_turns(&self, field: &GameField, hold_available: bool) -> Vec<ScoredTurn> {
+        let plans = available_turns(field, hold_available)
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        if !self.parallel || self.placement_evaluator.is_batch_relative() {
+            let parent = BoardAnalysis::from_board(field.board());
+            return self.score_plans(&parent, field, &plans);
+        }
+        plans
+            .par_chunks(PARALLEL_CHUNK_LEN)
+            .map_init(
+                || BoardAnalysis::from_board(field.board()),
+                |parent, chunk| self.score_plans(parent, field, chunk),
+            )
+            .collect::<Vec<_>>()
+            .into_iter()
+            .flatten()
+            .collect()
+    }
 
-        best_result
+    fn score_plans(
+        &self,
+        parent: &BoardAnalysis,
+        field: &GameField,
+        plans: &[TurnPlan],
+    ) -> Vec<ScoredTurn> {
+        let analyses = plans
+            .iter()
+            .map(|plan| plan.analyze_from(parent, field))
+            .collect::<Vec<_>>();
+        let mut scores = vec![0.0; analyses.len()];
+        self.placement_evaluator
+            .evaluate_placements(&analyses, &mut scores);
+        iter::zip(plans, analyses)
+            .zip(scores)
+            .map(|((plan, analysis), score)| ScoredTurn {
+                plan: *plan,
+                analysis,
+                score,
+            })
+            .collect()
+    }
+
+    /// Returns the `k` highest-scoring turns for the current game state.
+    ///
+    /// See [`evaluate_turns`](Self::evaluate_turns) for the ordering.
+    #[must_use]
+    pub fn top_k_turns(
+        &self,
+        field: &GameField,
+        hold_available: bool,
+        k: usize,
+    ) -> Vec<ScoredTurn> {
+        let mut candidates = self.evaluate_turns(field, hold_available);
+        candidates.truncate(k);
+        candidates
+    }
+
+    /// Selects a turn for the current game state using the given selection strategy.
+    ///
+    /// # Arguments
+    /// * `field` - Current game field state
+    /// * `hold_available` - Whether hold is available for this turn
+    /// * `selection` - Strategy for choosing among the scored candidates
+    /// * `rng` - Random number generator used by stochastic strategies
+    ///
+    /// # Returns
+    /// `Some((turn_plan, analysis))` if a valid placement exists, `None` if game over
+    pub fn select_turn<R>(
+        &self,
+        field: &GameField,
+        hold_available: bool,
+        selection: TurnSelection,
+        rng: &mut R,
+    ) -> Option<(TurnPlan, PlacementAnalysis)>
+    where
+        R: Rng + ?Sized,
+    {
+        if selection == TurnSelection::Greedy {
+            return self.select_best_turn(field, hold_available);
+        }
+        let candidates = self.evaluate_turns(field, hold_available);
+        selection
+            .choose(candidates, rng)
+            .map(|turn| (turn.plan, turn.analysis))
     }
 
     #[must_use]
@@ -212,6 +461,19 @@ impl<'a> TurnEvaluator<'a> {
     }
 }
 
+/// Orders placement scores, ranking NaN below every other score.
+///
+/// Greedy, ranked and softmax selection all order candidates by this, so a NaN score never
+/// wins a turn.
+fn compare_scores(a: f32, b: f32) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => a.total_cmp(&b),
+    }
+}
+
 fn available_turns(
     field: &GameField,
     hold_available: bool,
@@ -258,3 +520,209 @@ fn left(piece: Piece, board: &BitBoard) -> Option<Piece> {
 fn right(piece: Piece, board: &BitBoard) -> Option<Piece> {
     piece.right().filter(|moved| !board.is_colliding(*moved))
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    /// Prefers placements leaving the lowest stack
+    #[derive(Debug)]
+    pub(crate) struct NegativeHeightEvaluator;
+
+    impl PlacementEvaluator for NegativeHeightEvaluator {
+        fn evaluate_placement(&self, analysis: &PlacementAnalysis) -> f32 {
+            -f32::from(analysis.board_analysis().total_height())
+        }
+    }
+
+    pub(crate) fn test_field(seed: u64) -> GameField {
+        let mut rng = StdRng::seed_from_u64(seed);
+        GameField::with_seed(rng.random())
+    }
+
+    #[test]
+    fn test_evaluate_turns_sorted_descending() {
+        let evaluator = TurnEvaluator::new(Box::new(NegativeHeightEvaluator));
+        let field = test_field(0);
+        let candidates = evaluator.evaluate_turns(&field, true);
+        assert!(!candidates.is_empty());
+        assert!(candidates.windows(2).all(|w| w[0].score >= w[1].score));
+
+        let (best, _) = evaluator.select_best_turn(&field, true).unwrap();
+        assert_eq!(candidates[0].plan.placement(), best.placement());
+        assert_eq!(candidates[0].plan.use_hold(), best.use_hold());
+
+        let top = evaluator.top_k_turns(&field, true, 3);
+        assert_eq!(top.len(), 3);
+    }
+
+    /// Scores boards with holes as NaN
+    #[derive(Debug)]
+    struct NanForHolesEvaluator;
+
+    impl PlacementEvaluator for NanForHolesEvaluator {
+        fn evaluate_placement(&self, analysis: &PlacementAnalysis) -> f32 {
+            let board = analysis.board_analysis();
+            if board.num_holes() > 0 {
+                f32::NAN
+            } else {
+                -f32::from(board.total_height())
+            }
+        }
+    }
+
+    #[test]
+    fn test_nan_scores_rank_last() {
+        let evaluator = TurnEvaluator::new(Box::new(NanForHolesEvaluator));
+        let field = test_field(0);
+        let candidates = evaluator.evaluate_turns(&field, true);
+        let first_nan = candidates.iter().position(|c| c.score.is_nan()).unwrap();
+        assert!(first_nan > 0);
+        assert!(candidates[first_nan..].iter().all(|c| c.score.is_nan()));
+
+        let (best, analysis) = evaluator.select_best_turn(&field, true).unwrap();
+        assert_eq!(candidates[0].plan.placement(), best.placement());
+        assert!(!NanForHolesEvaluator.evaluate_placement(&analysis).is_nan());
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let (_, analysis) = evaluator
+                .select_turn(
+                    &field,
+                    true,
+                    TurnSelection::Softmax { temperature: 1.0 },
+                    &mut rng,
+                )
+                .unwrap();
+            assert!(!NanForHolesEvaluator.evaluate_placement(&analysis).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_select_turn_degenerate_parameters_are_greedy() {
+        let evaluator = TurnEvaluator::new(Box::new(NegativeHeightEvaluator));
+        let field = test_field(1);
+        let mut rng = StdRng::seed_from_u64(1);
+        let (_, best) = evaluator.select_best_turn(&field, true).unwrap();
+        let best_score = NegativeHeightEvaluator.evaluate_placement(&best);
+
+        for selection in [
+            TurnSelection::Greedy,
+            TurnSelection::Softmax { temperature: 0.0 },
+            TurnSelection::Softmax { temperature: 1e-6 },
+            TurnSelection::EpsilonGreedy { epsilon: 0.0 },
+        ] {
+            let (_, analysis) = evaluator
+                .select_turn(&field, true, selection, &mut rng)
+                .unwrap();
+            let score = NegativeHeightEvaluator.evaluate_placement(&analysis);
+            assert!((score - best_score).abs() < f32::EPSILON, "{selection:?}");
+        }
+    }
+
+    #[test]
+    fn test_select_turn_stochastic_explores() {
+        let evaluator = TurnEvaluator::new(Box::new(NegativeHeightEvaluator));
+        let field = test_field(2);
+        let mut rng = StdRng::seed_from_u64(2);
+        let (best, _) = evaluator.select_best_turn(&field, true).unwrap();
+
+        for selection in [
+            TurnSelection::Softmax { temperature: 100.0 },
+            TurnSelection::EpsilonGreedy { epsilon: 1.0 },
+        ] {
+            let explored = (0..100).any(|_| {
+                let (plan, _) = evaluator
+                    .select_turn(&field, true, selection, &mut rng)
+                    .unwrap();
+                plan.placement() != best.placement()
+            });
+            assert!(explored, "{selection:?}");
+        }
+    }
+
+    fn all_metrics(analysis: &PlacementAnalysis) -> Vec<u32> {
+        let board = analysis.board_analysis();
+        let mut metrics = vec![
+            u32::try_from(analysis.cleared_lines()).unwrap(),
+            analysis.landing_height().into(),
+            analysis.eroded_piece_cells().into(),
+            analysis.max_height_before().into(),
+            board.max_height().into(),
+            board.center_column_max_height().into(),
+            board.total_height().into(),
+            board.num_holes().into(),
+            board.sum_of_hole_depth(),
+            board.rows_with_holes().into(),
+            board.cells_above_holes(),
+            board.reachable_overhangs().into(),
+            board.row_transitions(),
+            board.column_transitions(),
+            board.surface_bumpiness(),
+            board.surface_roughness(),
+            board.sum_of_deep_well_depth(),
+            board.edge_i_well_depth().into(),
+            board.open_well_depth().into(),
+            board.open_well_clean_rows().into(),
+            board.i_dependencies().into(),
+            board.checkerboard_parity_imbalance().into(),
+            board.column_parity_imbalance().into(),
+            board.t_slot_count().into(),
+            board.t_slot_depth().into(),
+            board.num_unfittable_piece_kinds().into(),
+        ];
+        metrics.extend(board.column_heights().iter().map(|h| u32::from(*h)));
+        metrics.extend(board.column_occupied_cells().iter().map(|o| u32::from(*o)));
+        metrics
+    }
+
+    #[test]
+    fn test_incremental_analysis_matches_full() {
+        let evaluator = TurnEvaluator::new(Box::new(NegativeHeightEvaluator));
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut checked_line_clears = 0;
+        for seed in 0..3 {
+            let mut field = test_field(seed);
+            let mut stats = GameStats::new();
+            for _ in 0..100 {
+                let parent = BoardAnalysis::from_board(field.board());
+                for turn in available_turns(&field, true).into_iter().flatten() {
+                    let full = PlacementAnalysis::from_board(field.board(), turn.placement());
+                    let incremental = PlacementAnalysis::from_parent(&parent, turn.placement());
+                    assert_eq!(all_metrics(&incremental), all_metrics(&full));
+                    if full.cleared_lines() > 0 {
+                        checked_line_clears += 1;
+                    }
+                }
+                let selection = TurnSelection::EpsilonGreedy { epsilon: 0.3 };
+                let Some((turn, analysis)) =
+                    evaluator.select_turn(&field, true, selection, &mut rng)
+                else {
+                    break;
+                };
+                if turn.apply(&analysis, &mut field, &mut stats).1.is_err() {
+                    break;
+                }
+            }
+        }
+        assert!(checked_line_clears > 0);
+    }
+
+    #[test]
+    fn test_parallel_evaluation_matches_sequential() {
+        let sequential = TurnEvaluator::new(Box::new(NegativeHeightEvaluator));
+        let parallel = TurnEvaluator::new(Box::new(NegativeHeightEvaluator)).with_parallel(true);
+        let field = test_field(4);
+        let expected = sequential.evaluate_turns(&field, true);
+        let actual = parallel.evaluate_turns(&field, true);
+        assert!(expected.len() > PARALLEL_CHUNK_LEN);
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in iter::zip(&actual, &expected) {
+            assert_eq!(a.plan.placement(), e.plan.placement());
+            assert_eq!(a.plan.use_hold(), e.plan.use_hold());
+            assert!((a.score - e.score).abs() < f32::EPSILON);
+        }
+    }
+}
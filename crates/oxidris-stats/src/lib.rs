@@ -8,6 +8,7 @@
 //! - **Comprehensive statistics**: Combined descriptive statistics, percentiles, and histograms
 //! - **Survival analysis**: Kaplan-Meier estimator for time-to-event data with censoring
 //! - **Adaptive binning**: Data-driven binning for handling skewed distributions
+//! - **Smoothing**: Isotonic regression and smoothing splines for noisy curves
 //!
 //! # Modules
 //!
@@ -17,6 +18,7 @@
 //! - [`comprehensive`]: Comprehensive statistical analysis combining multiple measures
 //! - [`survival`]: Kaplan-Meier survival curves for analyzing time-to-event data
 //! - [`binning`]: Adaptive binning algorithms for skewed distributions
+//! - [`smoothing`]: Monotone and smoothing-spline fits for noisy per-value statistics
 //!
 //! # Examples
 //!
@@ -78,4 +80,5 @@ pub mod comprehensive;
 pub mod descriptive;
 pub mod histogram;
 pub mod percentiles;
+pub mod smoothing;
 pub mod survival;
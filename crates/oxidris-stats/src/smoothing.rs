@@ -0,0 +1,299 @@
+//! Curve smoothing for noisy per-value statistics
+//!
+//! This module provides algorithms that turn noisy `(x, y)` observations (e.g., KM median
+//! survival per feature value) into smooth or monotone curves:
+//!
+//! - [`isotonic_regression`]: Best non-decreasing fit (pool adjacent violators)
+//! - [`SmoothingSpline`]: Cubic smoothing spline (Reinsch algorithm)
+//!
+//! # Examples
+//!
+//! ```
+//! use oxidris_stats::smoothing::{SmoothingSpline, isotonic_regression};
+//!
+//! // Monotone fit: the violating pair (3.0, 2.0) is pooled into its mean
+//! let fitted = isotonic_regression(&[1.0, 3.0, 2.0, 4.0], &[1.0, 1.0, 1.0, 1.0]);
+//! assert_eq!(fitted, vec![1.0, 2.5, 2.5, 4.0]);
+//!
+//! // Smoothing spline through noisy points
+//! let x = [0.0, 1.0, 2.0, 3.0, 4.0];
+//! let y = [0.0, 1.2, 1.8, 3.1, 4.0];
+//! let spline = SmoothingSpline::fit(&x, &y, &[1.0; 5], 0.5).unwrap();
+//! assert!((spline.evaluate(2.0) - 2.0).abs() < 0.3);
+//! ```
+
+/// Fit a non-decreasing sequence to `values` minimizing weighted squared error
+///
+/// Uses the pool adjacent violators algorithm (PAVA): adjacent values that violate
+/// the ordering are repeatedly merged into their weighted mean.
+///
+/// # Arguments
+///
+/// * `values` - Observed values in order
+/// * `weights` - Positive weight for each value
+///
+/// # Panics
+///
+/// Panics if `values` and `weights` have different lengths.
+#[must_use]
+pub fn isotonic_regression(values: &[f64], weights: &[f64]) -> Vec<f64> {
+    assert_eq!(values.len(), weights.len());
+
+    // Each block: (mean, total weight, number of values)
+    let mut blocks: Vec<(f64, f64, usize)> = Vec::with_capacity(values.len());
+    for (&value, &weight) in values.iter().zip(weights) {
+        let mut block = (value, weight, 1);
+        while let Some(&(prev_mean, prev_weight, prev_len)) = blocks.last() {
+            if prev_mean <= block.0 {
+                break;
+            }
+            blocks.pop();
+            let total = prev_weight + block.1;
+            block = (
+                (prev_mean * prev_weight + block.0 * block.1) / total,
+                total,
+                prev_len + block.2,
+            );
+        }
+        blocks.push(block);
+    }
+
+    blocks
+        .into_iter()
+        .flat_map(|(mean, _, len)| std::iter::repeat_n(mean, len))
+        .collect()
+}
+
+/// Natural cubic smoothing spline
+///
+/// Minimizes `Σ wᵢ (yᵢ - g(xᵢ))² + α ∫ g''(x)² dx` over twice-differentiable functions `g`.
+/// The solution is a natural cubic spline with knots at the observation points, stored as
+/// its values and second derivatives at each knot.
+///
+/// - `α = 0` interpolates the observations
+/// - `α → ∞` approaches the weighted least-squares line
+///
+/// Outside the knot range the spline is extended linearly, so it stays defined (and
+/// continuous with its end slopes) for values not covered by the observations. Feature
+/// transforms built from the spline clamp their normalized output, so such values
+/// saturate at the extremes of the observed range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmoothingSpline {
+    /// Knot positions (strictly increasing)
+    pub knots: Vec<f64>,
+    /// Fitted value at each knot
+    pub values: Vec<f64>,
+    /// Second derivative at each knot (zero at both ends)
+    pub second_derivatives: Vec<f64>,
+}
+
+impl SmoothingSpline {
+    /// Fit a smoothing spline to weighted observations
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Observation positions (strictly increasing)
+    /// * `y` - Observed values
+    /// * `weights` - Positive weight for each observation
+    /// * `smoothing` - Roughness penalty `α` (non-negative)
+    ///
+    /// # Returns
+    ///
+    /// The fitted spline, or `None` if `x` is empty or not strictly increasing
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x`, `y` and `weights` have different lengths.
+    #[must_use]
+    #[expect(
+        clippy::many_single_char_names,
+        reason = "follows the notation of the Reinsch algorithm"
+    )]
+    pub fn fit(x: &[f64], y: &[f64], weights: &[f64], smoothing: f64) -> Option<Self> {
+        assert_eq!(x.len(), y.len());
+        assert_eq!(x.len(), weights.len());
+        if x.is_empty() || x.windows(2).any(|w| w[0] >= w[1]) {
+            return None;
+        }
+
+        let n = x.len();
+        if n < 3 {
+            // No interior knots: the fit is linear (the penalty has no effect)
+            return Some(Self {
+                knots: x.to_vec(),
+                values: y.to_vec(),
+                second_derivatives: vec![0.0; n],
+            });
+        }
+
+        // Reinsch algorithm (Green & Silverman, "Nonparametric Regression and GLMs", 2.3)
+        // Q: n × (n-2) band matrix, R: (n-2) × (n-2) tridiagonal matrix
+        let h = x.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+        let m = n - 2;
+        let q = |i: usize, j: usize| -> f64 {
+            // column j corresponds to interior knot j + 1
+            if i == j {
+                1.0 / h[j]
+            } else if i == j + 1 {
+                -1.0 / h[j] - 1.0 / h[j + 1]
+            } else if i == j + 2 {
+                1.0 / h[j + 1]
+            } else {
+                0.0
+            }
+        };
+
+        // (R + α Qᵀ W⁻¹ Q) γ = Qᵀ y
+        let mut matrix = vec![vec![0.0; m]; m];
+        let mut rhs = vec![0.0; m];
+        for j in 0..m {
+            matrix[j][j] += (h[j] + h[j + 1]) / 3.0;
+            if j + 1 < m {
+                matrix[j][j + 1] += h[j + 1] / 6.0;
+                matrix[j + 1][j] += h[j + 1] / 6.0;
+            }
+            let row = matrix[j].iter_mut().enumerate();
+            for (k, entry) in row.take((j + 3).min(m)).skip(j.saturating_sub(2)) {
+                let sum = (j..j + 3)
+                    .map(|i| q(i, j) * q(i, k) / weights[i])
+                    .sum::<f64>();
+                *entry += smoothing * sum;
+            }
+            rhs[j] = (j..j + 3).map(|i| q(i, j) * y[i]).sum();
+        }
+        let gamma = solve_linear_system(matrix, rhs);
+
+        // g = y - α W⁻¹ Q γ
+        let values = (0..n)
+            .map(|i| {
+                let q_gamma = (i.saturating_sub(2)..=i.min(m - 1))
+                    .map(|j| q(i, j) * gamma[j])
+                    .sum::<f64>();
+                y[i] - smoothing * q_gamma / weights[i]
+            })
+            .collect();
+
+        let mut second_derivatives = vec![0.0; n];
+        second_derivatives[1..=m].copy_from_slice(&gamma);
+        Some(Self {
+            knots: x.to_vec(),
+            values,
+            second_derivatives,
+        })
+    }
+
+    /// Evaluate the spline at `x` (linear extrapolation outside the knot range)
+    #[must_use]
+    pub fn evaluate(&self, x: f64) -> f64 {
+        let knots = &self.knots;
+        let values = &self.values;
+        let gamma = &self.second_derivatives;
+        let last = knots.len() - 1;
+        if last == 0 {
+            return values[0];
+        }
+        if x <= knots[0] {
+            let width = knots[1] - knots[0];
+            let slope = (values[1] - values[0]) / width - width * gamma[1] / 6.0;
+            return values[0] + slope * (x - knots[0]);
+        }
+        if x >= knots[last] {
+            let width = knots[last] - knots[last - 1];
+            let slope = (values[last] - values[last - 1]) / width + width * gamma[last - 1] / 6.0;
+            return values[last] + slope * (x - knots[last]);
+        }
+        let i = knots.partition_point(|&k| k <= x) - 1;
+        let width = knots[i + 1] - knots[i];
+        let left = x - knots[i];
+        let right = knots[i + 1] - x;
+        (left * values[i + 1] + right * values[i]) / width
+            - left * right / 6.0
+                * ((1.0 + left / width) * gamma[i + 1] + (1.0 + right / width) * gamma[i])
+    }
+}
+
+/// Solve `matrix · x = rhs` by Gaussian elimination with partial pivoting
+fn solve_linear_system(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Vec<f64> {
+    let n = rhs.len();
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&a, &b| matrix[a][col].abs().total_cmp(&matrix[b][col].abs()))
+            .unwrap();
+        matrix.swap(col, pivot);
+        rhs.swap(col, pivot);
+        for row in col + 1..n {
+            let factor = matrix[row][col] / matrix[col][col];
+            if factor == 0.0 {
+                continue;
+            }
+            let (upper, lower) = matrix.split_at_mut(row);
+            for (target, &value) in lower[0][col..].iter_mut().zip(&upper[col][col..]) {
+                *target -= factor * value;
+            }
+            rhs[row] -= factor * rhs[col];
+        }
+    }
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum = (row + 1..n).map(|k| matrix[row][k] * x[k]).sum::<f64>();
+        x[row] = (rhs[row] - sum) / matrix[row][row];
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isotonic_regression() {
+        assert_eq!(isotonic_regression(&[], &[]), Vec::<f64>::new());
+        assert_eq!(
+            isotonic_regression(&[1.0, 2.0, 3.0], &[1.0, 1.0, 1.0]),
+            vec![1.0, 2.0, 3.0]
+        );
+        // weighted pooling: (4.0 × 3 + 1.0 × 1) / 4 = 3.25
+        assert_eq!(
+            isotonic_regression(&[0.0, 4.0, 1.0, 5.0], &[1.0, 3.0, 1.0, 1.0]),
+            vec![0.0, 3.25, 3.25, 5.0]
+        );
+        assert_eq!(
+            isotonic_regression(&[3.0, 2.0, 1.0], &[1.0, 1.0, 1.0]),
+            vec![2.0, 2.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn test_spline_interpolation() {
+        // Without smoothing, the spline interpolates the observations
+        let x = [0.0, 1.0, 3.0, 4.0, 6.0];
+        let y = [1.0, 3.0, 2.0, 5.0, 4.0];
+        let spline = SmoothingSpline::fit(&x, &y, &[1.0; 5], 0.0).unwrap();
+        for (xi, yi) in x.iter().zip(&y) {
+            assert!((spline.evaluate(*xi) - yi).abs() < 1e-9);
+        }
+        assert!(spline.second_derivatives[0].abs() < f64::EPSILON);
+        assert!(spline.second_derivatives[4].abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_spline_smoothing_and_extrapolation() {
+        // Linear data is reproduced exactly for any smoothing (zero roughness)
+        let x = [0.0, 1.0, 2.0, 5.0];
+        let y = [1.0, 3.0, 5.0, 11.0];
+        let spline = SmoothingSpline::fit(&x, &y, &[1.0, 2.0, 1.0, 0.5], 100.0).unwrap();
+        assert!((spline.evaluate(3.0) - 7.0).abs() < 1e-9);
+        assert!((spline.evaluate(-1.0) - -1.0).abs() < 1e-9);
+        assert!((spline.evaluate(10.0) - 21.0).abs() < 1e-9);
+
+        // Heavy smoothing flattens a zigzag towards its least-squares line
+        let x = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let y = [0.0, 2.0, 0.0, 2.0, 0.0];
+        let rough = SmoothingSpline::fit(&x, &y, &[1.0; 5], 0.0).unwrap();
+        let smooth = SmoothingSpline::fit(&x, &y, &[1.0; 5], 1000.0).unwrap();
+        assert!((smooth.evaluate(1.0) - 0.8).abs() < 0.05);
+        assert!((rough.evaluate(1.0) - 2.0).abs() < 1e-9);
+
+        assert!(SmoothingSpline::fit(&[0.0, 0.0], &[1.0, 2.0], &[1.0, 1.0], 1.0).is_none());
+    }
+}
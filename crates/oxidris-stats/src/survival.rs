@@ -203,4 +203,43 @@ impl KaplanMeierCurve {
         // Before first event, survival is 1.0
         1.0
     }
+
+    /// Returns the restricted mean survival time (RMST) up to `horizon`.
+    ///
+    /// The RMST is the area under the survival curve between time 0 and `horizon`,
+    /// i.e., the expected survival time when survival is capped at `horizon`.
+    /// Unlike the median, it is always defined, even when the survival probability
+    /// never drops to 50% due to heavy censoring.
+    ///
+    /// # Arguments
+    ///
+    /// * `horizon` - Upper bound of the integration range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oxidris_stats::survival::KaplanMeierCurve;
+    /// let data = vec![(10, false), (20, false)];
+    /// let curve = KaplanMeierCurve::from_data(data);
+    ///
+    /// // S(t) = 1.0 for t < 10, 0.5 for 10 <= t < 20, 0.0 afterwards
+    /// assert_eq!(curve.restricted_mean_survival(10), 10.0);
+    /// assert_eq!(curve.restricted_mean_survival(30), 15.0);
+    /// ```
+    #[expect(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn restricted_mean_survival(&self, horizon: usize) -> f64 {
+        let mut area = 0.0;
+        let mut prev_time = 0;
+        let mut prev_survival = 1.0;
+        for (&time, &survival) in self.times.iter().zip(&self.survival_prob) {
+            if time >= horizon {
+                break;
+            }
+            area += (time - prev_time) as f64 * prev_survival;
+            prev_time = time;
+            prev_survival = survival;
+        }
+        area + (horizon - prev_time) as f64 * prev_survival
+    }
 }
@@ -0,0 +1,650 @@
+//! CMA-ES optimization of feature weights.
+//!
+//! The Covariance Matrix Adaptation Evolution Strategy (Hansen & Ostermeier, 2001) samples
+//! candidate weights from a multivariate normal distribution and adapts its mean, step size
+//! and covariance matrix from the ranking of the samples. Learning the covariance lets it
+//! follow correlated weights, where the per-weight operators of the
+//! [`genetic`](crate::genetic) module converge slowly.
+//!
+//! # Algorithm Overview
+//!
+//! Each generation:
+//!
+//! 1. **Sample** - Draw `λ` candidates `x = m + σ·N(0, C)`
+//! 2. **Evaluate Fitness** - Play game sessions with each candidate (same as the GA)
+//! 3. **Recombine** - Move the mean `m` to a weighted average of the best `μ = λ/2` candidates
+//! 4. **Adapt** - Update the evolution paths, the covariance matrix `C` (rank-one and rank-μ
+//!    updates) and the step size `σ` (cumulative step-size adaptation)
+//!
+//! ## IPOP Restarts
+//!
+//! A run stops when the distribution has collapsed (step size below a tolerance), when `C`
+//! becomes ill-conditioned, or when the best fitness of the run has stagnated. The
+//! optimizer then restarts from a new random mean with a doubled population size
+//! (Auger & Hansen, 2005). Larger populations search more globally, so repeated restarts
+//! escape local optima that the first runs converged to.
+//!
+//! # Weight Handling
+//!
+//! Weights are clamped to non-negative values before evaluation, and those repaired weights
+//! are used in the update. Placement rankings do not depend on the scale of the weights, so
+//! the candidates are not L1-normalized like the GA's (normalizing every candidate would
+//! collapse the covariance along the scale direction). Instead, the mean and step size are
+//! rescaled together after each update, so the weights of the mean sum to about the number
+//! of phases without changing the search distribution. A uniform factor keeps the relative
+//! scale of the phases, so single weight sets are not normalized: normalize each weight set
+//! of the final individual when saving a model.
+//!
+//! # Choosing the Final Individual
+//!
+//! Fitness is evaluated on a few random games, so the best fitness seen over a whole run is
+//! biased towards lucky evaluations. [`CmaEs::final_candidates()`] returns the best
+//! individual and the final mean of every run; evaluate them on fresh fields and keep the
+//! best one.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use oxidris_training::cma_es::{CmaEs, CmaEsParams};
+//!
+//! let mut optimizer = CmaEs::new(features, gate, CmaEsParams::default(), &mut rng);
+//! for generation in 0..200 {
+//!     let mut population = optimizer.ask(&mut rng);
+//!     population.evaluate_fitness(&fields, &session_evaluator);
+//!     optimizer.tell(&population, &mut rng);
+//! }
+//! let mut candidates = optimizer.final_candidates();
+//! candidates.evaluate_fitness(&fresh_fields, &session_evaluator);
+//! let best = &candidates.individuals()[0];
+//! ```
+
+use std::iter;
+
+use oxidris_evaluator::{board_feature::BoxedBoardFeature, placement_evaluator::HeightGate};
+use rand::Rng;
+use rand_distr::{Distribution as _, StandardNormal};
+
+use crate::genetic::{self, Individual, Population};
+
+/// CMA-ES parameters.
+#[derive(Debug, Clone)]
+pub struct CmaEsParams {
+    /// Initial step size (standard deviation of the search distribution)
+    pub initial_sigma: f32,
+    /// Population size `λ` of the first run (doubled by each restart)
+    pub population_size: usize,
+    /// Restart when the largest standard deviation of the search distribution falls below
+    /// `tol_x × initial_sigma`
+    pub tol_x: f32,
+    /// Restart when the condition number of the covariance matrix exceeds this value
+    pub max_condition: f32,
+    /// Restart after this many generations without improving the best fitness of the run
+    /// (`None` for the default `10 + ⌈30n / λ⌉`)
+    pub stagnation_generations: Option<usize>,
+}
+
+impl Default for CmaEsParams {
+    fn default() -> Self {
+        Self {
+            initial_sigma: 0.02,
+            population_size: 30,
+            tol_x: 1e-3,
+            max_condition: 1e14,
+            stagnation_generations: None,
+        }
+    }
+}
+
+/// CMA-ES optimizer with IPOP restarts.
+///
+/// Follows an ask-and-tell interface: [`ask()`](Self::ask) samples a [`Population`] whose
+/// fitness is evaluated with [`Population::evaluate_fitness()`], and
+/// [`tell()`](Self::tell) updates the search distribution from the result.
+#[derive(Debug, Clone)]
+pub struct CmaEs {
+    board_features: Vec<BoxedBoardFeature>,
+    gate: HeightGate,
+    params: CmaEsParams,
+    state: CmaEsState,
+    restarts: usize,
+    best: Option<Individual>,
+    /// Best individual of the current run
+    run_best: Option<Individual>,
+    /// Weights of the best individuals and final means of the finished runs
+    finished_candidates: Vec<Vec<f32>>,
+}
+
+impl CmaEs {
+    /// Creates an optimizer starting from a random mean, one weight set per phase of `gate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `board_features` - Features to evaluate (defines weight set length)
+    /// * `gate` - Gating rule selecting the weight set by stack height
+    /// * `params` - Optimizer parameters
+    /// * `rng` - Random number generator
+    #[must_use]
+    pub fn new<R>(
+        board_features: Vec<BoxedBoardFeature>,
+        gate: HeightGate,
+        params: CmaEsParams,
+        rng: &mut R,
+    ) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        let state = CmaEsState::new(
+            random_mean(&board_features, &gate, rng),
+            f64::from(params.initial_sigma),
+            params.population_size,
+        );
+        Self {
+            board_features,
+            gate,
+            params,
+            state,
+            restarts: 0,
+            best: None,
+            run_best: None,
+            finished_candidates: vec![],
+        }
+    }
+
+    /// Returns the features the weights apply to.
+    #[must_use]
+    pub fn board_features(&self) -> &[BoxedBoardFeature] {
+        &self.board_features
+    }
+
+    /// Returns the gating rule selecting weight sets.
+    #[must_use]
+    pub fn gate(&self) -> &HeightGate {
+        &self.gate
+    }
+
+    /// Returns the current step size.
+    #[must_use]
+    pub fn sigma(&self) -> f64 {
+        self.state.sigma
+    }
+
+    /// Returns the population size of the current run.
+    #[must_use]
+    pub fn population_size(&self) -> usize {
+        self.state.lambda
+    }
+
+    /// Returns the number of restarts so far.
+    #[must_use]
+    pub fn restarts(&self) -> usize {
+        self.restarts
+    }
+
+    /// Returns the best individual evaluated so far, over all runs.
+    ///
+    /// Its fitness is the highest of many noisy evaluations; use
+    /// [`final_candidates()`](Self::final_candidates) to choose the final individual.
+    #[must_use]
+    pub fn best(&self) -> Option<&Individual> {
+        self.best.as_ref()
+    }
+
+    /// Returns the candidates for the final individual as an unevaluated population: the
+    /// best individual and the mean of every run, including the current one.
+    #[must_use]
+    pub fn final_candidates(&self) -> Population {
+        let weights = self
+            .finished_candidates
+            .iter()
+            .cloned()
+            .chain(self.run_best.as_ref().map(|ind| ind.weights().to_vec()))
+            .chain([self.mean_weights()]);
+        Population::from_weights(self.board_features.clone(), self.gate.clone(), weights)
+    }
+
+    /// Returns the mean of the search distribution, clamped like the samples.
+    fn mean_weights(&self) -> Vec<f32> {
+        #[expect(clippy::cast_possible_truncation)]
+        self.state.mean.iter().map(|m| m.max(0.0) as f32).collect()
+    }
+
+    /// Samples the candidates of the next generation.
+    ///
+    /// The returned population is not evaluated yet.
+    #[must_use]
+    pub fn ask<R>(&self, rng: &mut R) -> Population
+    where
+        R: Rng + ?Sized,
+    {
+        #[expect(clippy::cast_possible_truncation)]
+        let weights = self
+            .state
+            .sample(rng)
+            .into_iter()
+            .map(|x| x.into_iter().map(|v| v.max(0.0) as f32).collect());
+        Population::from_weights(self.board_features.clone(), self.gate.clone(), weights)
+    }
+
+    /// Updates the search distribution from an evaluated population returned by
+    /// [`ask()`](Self::ask).
+    ///
+    /// Restarts with a doubled population size when a stopping criterion is met.
+    /// Returns `true` if the optimizer restarted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the population is not sorted by fitness descending.
+    pub fn tell<R>(&mut self, population: &Population, rng: &mut R) -> bool
+    where
+        R: Rng + ?Sized,
+    {
+        let individuals = population.individuals();
+        assert!(individuals.is_sorted_by(|a, b| a.fitness() >= b.fitness()));
+        if let Some(first) = individuals.first() {
+            for best in [&mut self.best, &mut self.run_best] {
+                if best.as_ref().is_none_or(|b| first.fitness() > b.fitness()) {
+                    *best = Some(first.clone());
+                }
+            }
+        }
+
+        let samples = individuals
+            .iter()
+            .map(|ind| ind.weights().iter().copied().map(f64::from).collect())
+            .collect::<Vec<Vec<f64>>>();
+        self.state.update(&samples, individuals[0].fitness());
+        #[expect(clippy::cast_precision_loss)]
+        let scale = self.state.mean.iter().sum::<f64>() / self.gate.num_phases() as f64;
+        if scale > 0.0 {
+            self.state.rescale(scale.recip());
+        }
+
+        if !self.state.should_restart(&self.params) {
+            return false;
+        }
+        self.restarts += 1;
+        let mean = self.mean_weights();
+        self.finished_candidates.extend(
+            self.run_best
+                .take()
+                .map(|ind| ind.weights().to_vec())
+                .into_iter()
+                .chain([mean]),
+        );
+        self.state = CmaEsState::new(
+            random_mean(&self.board_features, &self.gate, rng),
+            f64::from(self.params.initial_sigma),
+            self.state.lambda * 2,
+        );
+        true
+    }
+}
+
+fn random_mean<R>(board_features: &[BoxedBoardFeature], gate: &HeightGate, rng: &mut R) -> Vec<f64>
+where
+    R: Rng + ?Sized,
+{
+    let mut mean = crate::weights::random(rng, 1.0, board_features.len() * gate.num_phases());
+    genetic::normalize_phases(&mut mean, board_features.len());
+    mean.into_iter().map(f64::from).collect()
+}
+
+/// Search distribution and strategy parameters of one CMA-ES run.
+#[derive(Debug, Clone)]
+struct CmaEsState {
+    dim: usize,
+    lambda: usize,
+    /// Recombination weights of the best `μ` samples
+    recombination_weights: Vec<f64>,
+    mu_eff: f64,
+    c_sigma: f64,
+    d_sigma: f64,
+    c_c: f64,
+    c_1: f64,
+    c_mu: f64,
+    /// Expected norm of an `N(0, I)` sample
+    chi_n: f64,
+    mean: Vec<f64>,
+    sigma: f64,
+    /// Covariance matrix (row-major)
+    cov: Vec<f64>,
+    /// Eigenvectors of `cov` (column `j` is the `j`-th eigenvector)
+    basis: Vec<f64>,
+    /// Square roots of the eigenvalues of `cov`
+    scales: Vec<f64>,
+    p_sigma: Vec<f64>,
+    p_c: Vec<f64>,
+    generation: usize,
+    best_fitness: f32,
+    stagnation: usize,
+}
+
+impl CmaEsState {
+    #[expect(clippy::cast_precision_loss)]
+    fn new(mean: Vec<f64>, sigma: f64, lambda: usize) -> Self {
+        let dim = mean.len();
+        let n = dim as f64;
+        let mu = lambda / 2;
+        let mut recombination_weights = (0..mu)
+            .map(|i| ((mu as f64) + 0.5).ln() - ((i + 1) as f64).ln())
+            .collect::<Vec<_>>();
+        let sum = recombination_weights.iter().sum::<f64>();
+        for w in &mut recombination_weights {
+            *w /= sum;
+        }
+        let mu_eff = 1.0 / recombination_weights.iter().map(|w| w * w).sum::<f64>();
+
+        let c_sigma = (mu_eff + 2.0) / (n + mu_eff + 5.0);
+        let d_sigma = 1.0 + 2.0 * (((mu_eff - 1.0) / (n + 1.0)).sqrt() - 1.0).max(0.0) + c_sigma;
+        let c_c = (4.0 + mu_eff / n) / (n + 4.0 + 2.0 * mu_eff / n);
+        let c_1 = 2.0 / ((n + 1.3).powi(2) + mu_eff);
+        let c_mu =
+            (1.0 - c_1).min(2.0 * (mu_eff - 2.0 + 1.0 / mu_eff) / ((n + 2.0).powi(2) + mu_eff));
+        let chi_n = n.sqrt() * (1.0 - 1.0 / (4.0 * n) + 1.0 / (21.0 * n * n));
+
+        let identity = identity(dim);
+        Self {
+            dim,
+            lambda,
+            recombination_weights,
+            mu_eff,
+            c_sigma,
+            d_sigma,
+            c_c,
+            c_1,
+            c_mu,
+            chi_n,
+            mean,
+            sigma,
+            cov: identity.clone(),
+            basis: identity,
+            scales: vec![1.0; dim],
+            p_sigma: vec![0.0; dim],
+            p_c: vec![0.0; dim],
+            generation: 0,
+            best_fitness: f32::MIN,
+            stagnation: 0,
+        }
+    }
+
+    /// Draws `λ` samples `m + σ·B·D·z` with `z ~ N(0, I)`.
+    fn sample<R>(&self, rng: &mut R) -> Vec<Vec<f64>>
+    where
+        R: Rng + ?Sized,
+    {
+        (0..self.lambda)
+            .map(|_| {
+                let scaled = self
+                    .scales
+                    .iter()
+                    .map(|d| {
+                        let z: f64 = StandardNormal.sample(rng);
+                        d * z
+                    })
+                    .collect::<Vec<f64>>();
+                let y = self.mul_basis(&scaled);
+                iter::zip(&self.mean, y)
+                    .map(|(m, y)| m + self.sigma * y)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Updates the distribution from samples sorted by fitness descending.
+    fn update(&mut self, samples: &[Vec<f64>], best_fitness: f32) {
+        let old_mean = self.mean.clone();
+        let steps = samples
+            .iter()
+            .take(self.recombination_weights.len())
+            .map(|x| {
+                iter::zip(x, &old_mean)
+                    .map(|(x, m)| (x - m) / self.sigma)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut y_w = vec![0.0; self.dim];
+        for (w, y) in iter::zip(&self.recombination_weights, &steps) {
+            for (acc, y) in iter::zip(&mut y_w, y) {
+                *acc += w * y;
+            }
+        }
+        for (m, y) in iter::zip(&mut self.mean, &y_w) {
+            *m += self.sigma * y;
+        }
+
+        // Cumulative step-size adaptation path, using C^(-1/2)·y_w = B·D^(-1)·Bᵀ·y_w
+        let whitened = iter::zip(self.mul_basis_transposed(&y_w), &self.scales)
+            .map(|(v, d)| v / d)
+            .collect::<Vec<_>>();
+        let c_inv_sqrt_y_w = self.mul_basis(&whitened);
+        let sigma_factor = (self.c_sigma * (2.0 - self.c_sigma) * self.mu_eff).sqrt();
+        for (p, v) in iter::zip(&mut self.p_sigma, &c_inv_sqrt_y_w) {
+            *p = (1.0 - self.c_sigma) * *p + sigma_factor * v;
+        }
+        self.generation += 1;
+        let p_sigma_norm = norm(&self.p_sigma);
+        #[expect(clippy::cast_precision_loss)]
+        let (n, generation) = (self.dim as f64, self.generation as f64);
+        let h_sigma =
+            p_sigma_norm / (1.0 - (1.0 - self.c_sigma).powf(2.0 * generation)).sqrt() / self.chi_n
+                < 1.4 + 2.0 / (n + 1.0);
+
+        let c_factor = (self.c_c * (2.0 - self.c_c) * self.mu_eff).sqrt();
+        for (p, y) in iter::zip(&mut self.p_c, &y_w) {
+            *p = (1.0 - self.c_c) * *p + if h_sigma { c_factor * y } else { 0.0 };
+        }
+
+        // Rank-one and rank-μ covariance updates
+        let delta_h = if h_sigma {
+            0.0
+        } else {
+            self.c_c * (2.0 - self.c_c)
+        };
+        let decay = 1.0 - self.c_1 - self.c_mu + self.c_1 * delta_h;
+        for i in 0..self.dim {
+            for j in 0..self.dim {
+                let rank_mu = iter::zip(&self.recombination_weights, &steps)
+                    .map(|(w, y)| w * y[i] * y[j])
+                    .sum::<f64>();
+                let c = &mut self.cov[i * self.dim + j];
+                *c = decay * *c + self.c_1 * self.p_c[i] * self.p_c[j] + self.c_mu * rank_mu;
+            }
+        }
+
+        self.sigma *= ((self.c_sigma / self.d_sigma) * (p_sigma_norm / self.chi_n - 1.0)).exp();
+
+        let (eigenvalues, basis) = symmetric_eigen(&self.cov, self.dim);
+        self.scales = eigenvalues
+            .into_iter()
+            .map(|e| e.max(f64::MIN_POSITIVE).sqrt())
+            .collect();
+        self.basis = basis;
+
+        if best_fitness > self.best_fitness {
+            self.best_fitness = best_fitness;
+            self.stagnation = 0;
+        } else {
+            self.stagnation += 1;
+        }
+    }
+
+    /// Scales the mean and step size together, which leaves the distribution of the
+    /// normalized samples unchanged.
+    fn rescale(&mut self, factor: f64) {
+        for m in &mut self.mean {
+            *m *= factor;
+        }
+        self.sigma *= factor;
+    }
+
+    fn should_restart(&self, params: &CmaEsParams) -> bool {
+        let max_scale = self.scales.iter().copied().fold(0.0, f64::max);
+        let min_scale = self.scales.iter().copied().fold(f64::INFINITY, f64::min);
+        let stagnation_generations = params
+            .stagnation_generations
+            .unwrap_or_else(|| 10 + (30 * self.dim).div_ceil(self.lambda));
+        !self.sigma.is_finite()
+            || self.sigma * max_scale < f64::from(params.tol_x * params.initial_sigma)
+            || (max_scale / min_scale).powi(2) > f64::from(params.max_condition)
+            || self.stagnation >= stagnation_generations
+    }
+
+    /// Returns `B·v`.
+    fn mul_basis(&self, v: &[f64]) -> Vec<f64> {
+        self.basis
+            .chunks_exact(self.dim)
+            .map(|row| iter::zip(row, v).map(|(b, v)| b * v).sum())
+            .collect()
+    }
+
+    /// Returns `Bᵀ·v`.
+    fn mul_basis_transposed(&self, v: &[f64]) -> Vec<f64> {
+        let mut out = vec![0.0; self.dim];
+        for (row, v) in iter::zip(self.basis.chunks_exact(self.dim), v) {
+            for (out, b) in iter::zip(&mut out, row) {
+                *out += b * v;
+            }
+        }
+        out
+    }
+}
+
+fn identity(dim: usize) -> Vec<f64> {
+    let mut matrix = vec![0.0; dim * dim];
+    for i in 0..dim {
+        matrix[i * dim + i] = 1.0;
+    }
+    matrix
+}
+
+fn norm(v: &[f64]) -> f64 {
+    v.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+/// Eigendecomposition of a symmetric row-major matrix with the cyclic Jacobi method.
+///
+/// Returns the eigenvalues and the eigenvectors as columns of a row-major matrix.
+#[expect(clippy::many_single_char_names, clippy::similar_names)]
+fn symmetric_eigen(matrix: &[f64], dim: usize) -> (Vec<f64>, Vec<f64>) {
+    const MAX_SWEEPS: usize = 100;
+
+    let mut a = matrix.to_vec();
+    let mut v = identity(dim);
+    let total = a.iter().map(|x| x * x).sum::<f64>();
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal = (0..dim)
+            .flat_map(|p| ((p + 1)..dim).map(move |q| (p, q)))
+            .map(|(p, q)| a[p * dim + q].powi(2))
+            .sum::<f64>();
+        if off_diagonal <= f64::EPSILON * f64::EPSILON * total {
+            break;
+        }
+        for p in 0..dim {
+            for q in (p + 1)..dim {
+                let a_pq = a[p * dim + q];
+                if a_pq == 0.0 {
+                    continue;
+                }
+                let theta = (a[q * dim + q] - a[p * dim + p]) / (2.0 * a_pq);
+                let t = theta.signum() / (theta.abs() + theta.hypot(1.0));
+                let c = 1.0 / t.hypot(1.0);
+                let s = t * c;
+                // A ← Jᵀ·A·J, V ← V·J
+                for k in 0..dim {
+                    let (a_kp, a_kq) = (a[k * dim + p], a[k * dim + q]);
+                    a[k * dim + p] = c * a_kp - s * a_kq;
+                    a[k * dim + q] = s * a_kp + c * a_kq;
+                }
+                for k in 0..dim {
+                    let (a_pk, a_qk) = (a[p * dim + k], a[q * dim + k]);
+                    a[p * dim + k] = c * a_pk - s * a_qk;
+                    a[q * dim + k] = s * a_pk + c * a_qk;
+                }
+                for k in 0..dim {
+                    let (v_kp, v_kq) = (v[k * dim + p], v[k * dim + q]);
+                    v[k * dim + p] = c * v_kp - s * v_kq;
+                    v[k * dim + q] = s * v_kp + c * v_kq;
+                }
+            }
+        }
+    }
+    let eigenvalues = (0..dim).map(|i| a[i * dim + i]).collect();
+    (eigenvalues, v)
+}
+
+#[cfg(test)]
+mod tests {
+    use oxidris_evaluator::board_feature::{
+        FeatureSignal,
+        source::NumHoles,
+        transform::{RawTransform, RawTransformParam},
+    };
+    use rand::{SeedableRng as _, rngs::StdRng};
+
+    use super::*;
+
+    #[test]
+    fn test_symmetric_eigen() {
+        let matrix = [4.0, 1.0, 2.0, 1.0, 3.0, 0.5, 2.0, 0.5, 5.0];
+        let (eigenvalues, vectors) = symmetric_eigen(&matrix, 3);
+        for (j, lambda) in eigenvalues.iter().enumerate() {
+            let column = (0..3).map(|i| vectors[i * 3 + j]).collect::<Vec<_>>();
+            assert!((norm(&column) - 1.0).abs() < 1e-9);
+            for i in 0..3 {
+                let av = (0..3).map(|k| matrix[i * 3 + k] * column[k]).sum::<f64>();
+                assert!((av - lambda * column[i]).abs() < 1e-9);
+            }
+        }
+        assert!((eigenvalues.iter().sum::<f64>() - 12.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_converges_on_correlated_quadratic() {
+        // Maximize -(x - target)ᵀ·H·(x - target) with strongly correlated axes
+        let target = [0.3, -0.2, 0.5, 0.1];
+        let fitness = |x: &[f64]| {
+            let d = iter::zip(x, target).map(|(x, t)| x - t).collect::<Vec<_>>();
+            let s = d[0] + d[1];
+            let t = d[2] - d[3];
+            -(100.0 * s * s + (d[0] - d[1]).powi(2) + 50.0 * t * t + (d[2] + d[3]).powi(2))
+        };
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = CmaEsState::new(vec![0.0; 4], 0.5, 12);
+        for _ in 0..300 {
+            let mut samples = state.sample(&mut rng);
+            samples.sort_by(|a, b| fitness(b).total_cmp(&fitness(a)));
+            #[expect(clippy::cast_possible_truncation)]
+            let best = fitness(&samples[0]) as f32;
+            state.update(&samples, best);
+        }
+        for (m, t) in iter::zip(&state.mean, target) {
+            assert!((m - t).abs() < 1e-3, "{:?}", state.mean);
+        }
+    }
+
+    #[test]
+    fn test_restart_doubles_population_size() {
+        let params = CmaEsParams {
+            population_size: 8,
+            stagnation_generations: Some(0),
+            ..CmaEsParams::default()
+        };
+        let mut rng = StdRng::seed_from_u64(0);
+        let feature: BoxedBoardFeature = Box::new(RawTransform::new(
+            String::new(),
+            String::new(),
+            NumHoles,
+            RawTransformParam::new(FeatureSignal::Negative, 0.0, 4.0),
+        ));
+        let features = vec![feature.clone(), feature];
+        let mut optimizer = CmaEs::new(features, HeightGate::default(), params, &mut rng);
+        let population = optimizer.ask(&mut rng);
+        assert_eq!(population.individuals().len(), 8);
+        assert!(optimizer.tell(&population, &mut rng));
+        assert_eq!(optimizer.restarts(), 1);
+        assert_eq!(optimizer.population_size(), 16);
+        assert_eq!(optimizer.ask(&mut rng).individuals().len(), 16);
+        // Best and mean of the finished run, mean of the current run
+        assert_eq!(optimizer.final_candidates().individuals().len(), 3);
+    }
+}
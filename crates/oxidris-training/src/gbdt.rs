@@ -0,0 +1,282 @@
+//! Gradient-boosted regression tree training.
+//!
+//! This module fits a [`GradientBoostedTrees`] model to labeled feature vectors by
+//! minimizing squared error. Unlike the genetic algorithm, no game needs to be played:
+//! the model is fitted offline, typically to survival labels computed from recorded
+//! sessions (see `oxidris_analysis::survival_regression`).
+//!
+//! # Algorithm
+//!
+//! 1. Start from the mean label (`base_score`)
+//! 2. Fit a regression tree to the current residuals
+//! 3. Add the tree scaled by `learning_rate` and update the residuals
+//! 4. Repeat for `num_trees` trees
+//!
+//! Split search is histogram-based: each feature is bucketed into at most `num_bins` bins
+//! using quantiles of its values, so fitting stays linear in the number of samples.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use oxidris_training::gbdt::GbdtTrainer;
+//! # let inputs: Vec<Vec<f32>> = todo!(); // One feature vector per sample
+//! # let labels: Vec<f32> = todo!(); // One label per sample
+//!
+//! let trainer = GbdtTrainer::default();
+//! let model = trainer.fit(&inputs, &labels);
+//! let prediction = model.predict(&inputs[0]);
+//! ```
+
+use oxidris_evaluator::placement_evaluator::{GradientBoostedTrees, RegressionTree, TreeNode};
+
+/// Parameters for gradient-boosted tree training.
+#[derive(Debug, Clone, Copy)]
+pub struct GbdtTrainer {
+    /// Number of trees in the ensemble
+    pub num_trees: usize,
+    /// Maximum depth of each tree (a depth of 0 is a single leaf)
+    pub max_depth: usize,
+    /// Shrinkage applied to each tree's output
+    pub learning_rate: f32,
+    /// Minimum number of samples in each leaf
+    pub min_samples_leaf: usize,
+    /// Maximum number of histogram bins per feature (at most 256)
+    pub num_bins: usize,
+}
+
+impl Default for GbdtTrainer {
+    fn default() -> Self {
+        Self {
+            num_trees: 100,
+            max_depth: 4,
+            learning_rate: 0.1,
+            min_samples_leaf: 50,
+            num_bins: 64,
+        }
+    }
+}
+
+impl GbdtTrainer {
+    /// Fits a model predicting `labels` from `inputs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - Feature vectors (all of the same length)
+    /// * `labels` - Target value for each feature vector
+    ///
+    /// # Panics
+    ///
+    /// Panics if `inputs` is empty, if `inputs` and `labels` have different lengths,
+    /// or if `num_bins` is not in `2..=256`.
+    #[expect(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    #[must_use]
+    pub fn fit(&self, inputs: &[Vec<f32>], labels: &[f32]) -> GradientBoostedTrees {
+        assert!(!inputs.is_empty());
+        assert_eq!(inputs.len(), labels.len());
+        assert!((2..=256).contains(&self.num_bins));
+
+        let num_features = inputs[0].len();
+        let binned = (0..num_features)
+            .map(|feature| BinnedFeature::new(inputs, feature, self.num_bins))
+            .collect::<Vec<_>>();
+
+        let base_score =
+            (labels.iter().map(|&l| f64::from(l)).sum::<f64>() / labels.len() as f64) as f32;
+        let mut residuals = labels.iter().map(|l| l - base_score).collect::<Vec<_>>();
+
+        let mut trees = vec![];
+        for _ in 0..self.num_trees {
+            let mut nodes = vec![];
+            let mut indices = (0..inputs.len()).collect::<Vec<_>>();
+            self.build_node(&binned, &residuals, &mut indices, 0, &mut nodes);
+            let tree = RegressionTree::new(nodes).unwrap();
+            for (residual, input) in residuals.iter_mut().zip(inputs) {
+                *residual -= self.learning_rate * tree.predict(input);
+            }
+            trees.push(tree);
+        }
+
+        GradientBoostedTrees::new(base_score, self.learning_rate, trees)
+    }
+
+    /// Builds the subtree for the samples in `indices` and returns its root node index.
+    #[expect(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    fn build_node(
+        &self,
+        binned: &[BinnedFeature],
+        residuals: &[f32],
+        indices: &mut [usize],
+        depth: usize,
+        nodes: &mut Vec<TreeNode>,
+    ) -> usize {
+        let sum = indices
+            .iter()
+            .map(|&i| f64::from(residuals[i]))
+            .sum::<f64>();
+        let count = indices.len();
+        let node_index = nodes.len();
+        nodes.push(TreeNode::Leaf {
+            value: (sum / count as f64) as f32,
+        });
+
+        if depth >= self.max_depth || count < 2 * self.min_samples_leaf.max(1) {
+            return node_index;
+        }
+        let Some(split) = self.find_best_split(binned, residuals, indices, sum) else {
+            return node_index;
+        };
+
+        let feature = &binned[split.feature];
+        let (left, right) = partition(indices, |i| feature.bins[i] <= split.bin);
+        let left = self.build_node(binned, residuals, left, depth + 1, nodes);
+        let right = self.build_node(binned, residuals, right, depth + 1, nodes);
+        nodes[node_index] = TreeNode::Split {
+            feature: split.feature,
+            threshold: feature.thresholds[usize::from(split.bin)],
+            left,
+            right,
+        };
+        node_index
+    }
+
+    #[expect(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    fn find_best_split(
+        &self,
+        binned: &[BinnedFeature],
+        residuals: &[f32],
+        indices: &[usize],
+        sum: f64,
+    ) -> Option<Split> {
+        let count = indices.len();
+        let min_leaf = self.min_samples_leaf.max(1);
+        let parent_score = sum * sum / count as f64;
+
+        let mut best: Option<(f64, Split)> = None;
+        let mut hist_sum = vec![];
+        let mut hist_count = vec![];
+        for (feature_index, feature) in binned.iter().enumerate() {
+            let num_bins = feature.thresholds.len() + 1;
+            hist_sum.clear();
+            hist_sum.resize(num_bins, 0.0);
+            hist_count.clear();
+            hist_count.resize(num_bins, 0);
+            for &i in indices {
+                let bin = usize::from(feature.bins[i]);
+                hist_sum[bin] += f64::from(residuals[i]);
+                hist_count[bin] += 1;
+            }
+
+            let mut left_sum = 0.0;
+            let mut left_count = 0;
+            for bin in 0..num_bins - 1 {
+                left_sum += hist_sum[bin];
+                left_count += hist_count[bin];
+                let right_count = count - left_count;
+                if left_count < min_leaf || right_count < min_leaf {
+                    continue;
+                }
+                let right_sum = sum - left_sum;
+                let gain = left_sum * left_sum / left_count as f64
+                    + right_sum * right_sum / right_count as f64
+                    - parent_score;
+                if gain > 1e-9 && best.as_ref().is_none_or(|(best_gain, _)| gain > *best_gain) {
+                    let split = Split {
+                        feature: feature_index,
+                        bin: bin as u8,
+                    };
+                    best = Some((gain, split));
+                }
+            }
+        }
+        best.map(|(_, split)| split)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Split {
+    feature: usize,
+    /// Samples with bins `0..=bin` go to the left child
+    bin: u8,
+}
+
+/// A feature column bucketed into histogram bins.
+#[derive(Debug)]
+struct BinnedFeature {
+    /// Upper bound (inclusive) of each bin except the last one
+    thresholds: Vec<f32>,
+    /// Bin index of each sample
+    bins: Vec<u8>,
+}
+
+impl BinnedFeature {
+    #[expect(clippy::cast_possible_truncation)]
+    fn new(inputs: &[Vec<f32>], feature: usize, num_bins: usize) -> Self {
+        let mut values = inputs.iter().map(|x| x[feature]).collect::<Vec<_>>();
+        values.sort_by(f32::total_cmp);
+
+        let mut thresholds = (1..num_bins)
+            .map(|i| values[i * values.len() / num_bins])
+            .collect::<Vec<_>>();
+        // Use every distinct value when there are few of them
+        let mut distinct = values.clone();
+        distinct.dedup();
+        if distinct.len() <= num_bins {
+            thresholds = distinct;
+        }
+        thresholds.dedup();
+        // No split can put the maximum value on the left side
+        let max = values[values.len() - 1];
+        thresholds.retain(|&t| t < max);
+
+        let bins = inputs
+            .iter()
+            .map(|x| thresholds.partition_point(|&t| t < x[feature]) as u8)
+            .collect();
+        Self { thresholds, bins }
+    }
+}
+
+/// Reorders `indices` so that elements satisfying `pred` come first, and splits it there.
+fn partition<F>(indices: &mut [usize], mut pred: F) -> (&mut [usize], &mut [usize])
+where
+    F: FnMut(usize) -> bool,
+{
+    let mut mid = 0;
+    for i in 0..indices.len() {
+        if pred(indices[i]) {
+            indices.swap(mid, i);
+            mid += 1;
+        }
+    }
+    indices.split_at_mut(mid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[expect(clippy::cast_precision_loss)]
+    fn test_fit_step_function() {
+        // label depends on feature 0 only: 10.0 above 0.5, 0.0 otherwise
+        let inputs = (0..200)
+            .map(|i| vec![i as f32 / 200.0, (i % 7) as f32])
+            .collect::<Vec<_>>();
+        let labels = inputs
+            .iter()
+            .map(|x| if x[0] > 0.5 { 10.0 } else { 0.0 })
+            .collect::<Vec<_>>();
+
+        let trainer = GbdtTrainer {
+            num_trees: 20,
+            max_depth: 2,
+            learning_rate: 0.5,
+            min_samples_leaf: 5,
+            num_bins: 32,
+        };
+        let model = trainer.fit(&inputs, &labels);
+        assert_eq!(model.trees().len(), 20);
+        assert!((model.predict(&[0.1, 3.0]) - 0.0).abs() < 0.1);
+        assert!((model.predict(&[0.9, 3.0]) - 10.0).abs() < 0.1);
+    }
+}
@@ -15,6 +15,21 @@
 //! 5. **Mutation** - Apply random Gaussian noise to weights
 //! 6. **Normalization** - Normalize weights to sum to 1.0 (L1 normalization)
 //!
+//! # Phase-dependent Weights
+//!
+//! A population created with [`Population::random_phased()`] evolves one weight set per
+//! game phase of a [`HeightGate`] (e.g., low / middle / high stack). An individual's
+//! weights are the concatenation of its weight sets, so the genetic operators work
+//! unchanged; only L1 normalization is applied to each weight set separately.
+//!
+//! # Learnable Transforms
+//!
+//! Features with a trainable transform shape (see
+//! [`BoardFeature::shape_params()`](oxidris_evaluator::board_feature::BoardFeature::shape_params),
+//! e.g., piecewise-linear knot outputs) contribute shape parameters to each individual.
+//! They start from the features' fitted shape and evolve with the same crossover and
+//! mutation as the weights, L1-normalized per feature.
+//!
 //! # Key Components
 //!
 //! - [`Individual`] - A single candidate solution (set of feature weights + fitness score)
@@ -41,8 +56,9 @@
 //!
 //! # Parallelization
 //!
-//! Fitness evaluation is parallelized using threads - each individual evaluates its
-//! fitness independently across multiple game sessions.
+//! Fitness evaluation is parallelized on the rayon thread pool - each individual evaluates
+//! its fitness independently across multiple game sessions. Work stealing keeps all
+//! cores busy without spawning one OS thread per individual.
 //!
 //! # Example
 //!
@@ -65,7 +81,7 @@
 //! // Evolution loop
 //! for generation in 0..100 {
 //!     population.evaluate_fitness(&fields, &session_evaluator);
-//!     population = evolver.evolve(&population);
+//!     population = evolver.evolve(&population, &mut rng);
 //! }
 //! ```
 //!
@@ -101,23 +117,27 @@
 //!   diversity; no explicit diversity preservation mechanisms (e.g., fitness sharing,
 //!   crowding)
 //! - **No restart mechanism**: If the population converges to a local optimum, there's
-//!   no automatic restart or perturbation strategy
-//! - **Single-objective only**: Implementation assumes scalar fitness values; cannot
-//!   handle multi-objective optimization (e.g., Pareto fronts)
+//!   no automatic restart or perturbation strategy (see the [`cma_es`](crate::cma_es)
+//!   module for an optimizer with restarts)
+//! - **Single-objective only**: Implementation assumes scalar fitness values; see the
+//!   [`nsga2`](crate::nsga2) module for multi-objective optimization (Pareto fronts)
 //! - **No parameter guidance**: Choosing appropriate values for tournament size, mutation
 //!   rates, etc. requires manual experimentation
 //!
 //! See the crate-level documentation for broader training system limitations.
 
-use std::thread;
-
 use oxidris_engine::GameField;
 use oxidris_evaluator::{
-    board_feature::BoxedBoardFeature, placement_evaluator::FeatureBasedPlacementEvaluator,
-    session_evaluator::SessionEvaluator, turn_evaluator::TurnEvaluator,
+    board_feature::BoxedBoardFeature,
+    placement_evaluator::{
+        FeatureBasedPlacementEvaluator, HeightGate, PhasedPlacementEvaluator, PlacementEvaluator,
+    },
+    session_evaluator::SessionEvaluator,
+    turn_evaluator::TurnEvaluator,
 };
 use oxidris_stats::descriptive::DescriptiveStats;
 use rand::{Rng, seq::IndexedRandom};
+use rayon::prelude::*;
 
 use crate::weights;
 
@@ -129,6 +149,8 @@ use crate::weights;
 #[derive(Debug, Clone)]
 pub struct Individual {
     weights: Vec<f32>,
+    /// Transform shape parameters of all features, concatenated (empty if unused)
+    shape_params: Vec<f32>,
     fitness: f32,
 }
 
@@ -147,20 +169,80 @@ impl Individual {
     where
         R: Rng + ?Sized,
     {
-        let mut weights = weights::random(rng, max_weight, feature_count);
-        weights::normalize_l1(&mut weights);
+        Self::random_phased(rng, max_weight, feature_count, 1)
+    }
+
+    /// Creates a new individual with random weights for each of `num_phases` phases.
+    ///
+    /// Each phase's weight set is L1-normalized separately.
+    pub fn random_phased<R>(
+        rng: &mut R,
+        max_weight: f32,
+        feature_count: usize,
+        num_phases: usize,
+    ) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        let mut weights = weights::random(rng, max_weight, feature_count * num_phases);
+        normalize_phases(&mut weights, feature_count);
         Self {
             weights,
+            shape_params: vec![],
             fitness: f32::MIN,
         }
     }
 
+    /// Creates an individual with the given weights and fitness (e.g., restored from a
+    /// checkpoint).
+    #[must_use]
+    pub fn new(weights: Vec<f32>, fitness: f32) -> Self {
+        Self {
+            weights,
+            shape_params: vec![],
+            fitness,
+        }
+    }
+
+    /// Sets the transform shape parameters of the features.
+    ///
+    /// See [`shape_params()`](Self::shape_params) for the layout.
+    #[must_use]
+    pub fn with_shape_params(self, shape_params: Vec<f32>) -> Self {
+        Self {
+            shape_params,
+            ..self
+        }
+    }
+
     /// Returns the feature weights for this individual.
+    ///
+    /// For phased populations, the weight sets of all phases are concatenated
+    /// (see [`phase_weights()`](Self::phase_weights)).
     #[must_use]
     pub fn weights(&self) -> &[f32] {
         &self.weights
     }
 
+    /// Returns the weight set of each phase.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `feature_count` is zero.
+    pub fn phase_weights(&self, feature_count: usize) -> impl Iterator<Item = &[f32]> {
+        self.weights.chunks(feature_count)
+    }
+
+    /// Returns the transform shape parameters of all features, concatenated in feature
+    /// order.
+    ///
+    /// Empty if the individual uses the features' own shapes (e.g., individuals sampled by
+    /// CMA-ES).
+    #[must_use]
+    pub fn shape_params(&self) -> &[f32] {
+        &self.shape_params
+    }
+
     /// Returns the fitness score for this individual.
     ///
     /// Fitness is computed by playing game sessions and evaluating performance.
@@ -178,6 +260,7 @@ impl Individual {
 #[derive(Debug, Clone)]
 pub struct Population {
     board_features: Vec<BoxedBoardFeature>,
+    gate: HeightGate,
     individuals: Vec<Individual>,
 }
 
@@ -200,15 +283,110 @@ impl Population {
     where
         R: Rng + ?Sized,
     {
+        Self::random_phased(
+            board_features,
+            HeightGate::default(),
+            count,
+            rng,
+            max_weight,
+        )
+    }
+
+    /// Creates a new population with one weight set per phase of `gate`.
+    ///
+    /// Individuals are evaluated with [`PhasedPlacementEvaluator`] (or
+    /// [`FeatureBasedPlacementEvaluator`] if `gate` has a single phase). Their transform
+    /// shape parameters start from the shapes of `board_features`.
+    ///
+    /// # Arguments
+    ///
+    /// * `board_features` - Features to evaluate (defines weight set length)
+    /// * `gate` - Gating rule selecting the weight set by stack height
+    /// * `count` - Number of individuals in the population
+    /// * `rng` - Random number generator
+    /// * `max_weight` - Maximum weight value before normalization
+    #[must_use]
+    pub fn random_phased<R>(
+        board_features: Vec<BoxedBoardFeature>,
+        gate: HeightGate,
+        count: usize,
+        rng: &mut R,
+        max_weight: f32,
+    ) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        let shape_params = board_features
+            .iter()
+            .flat_map(|f| f.shape_params())
+            .collect::<Vec<_>>();
         let individuals = (0..count)
-            .map(|_| Individual::random(rng, max_weight, board_features.len()))
+            .map(|_| {
+                Individual::random_phased(rng, max_weight, board_features.len(), gate.num_phases())
+                    .with_shape_params(shape_params.clone())
+            })
             .collect();
         Population {
             board_features,
+            gate,
+            individuals,
+        }
+    }
+
+    /// Creates a population from existing individuals (e.g., restored from a checkpoint).
+    ///
+    /// Individuals must be sorted by fitness descending if the population is passed to
+    /// [`PopulationEvolver::evolve()`] without re-evaluation.
+    #[must_use]
+    pub fn new(
+        board_features: Vec<BoxedBoardFeature>,
+        gate: HeightGate,
+        individuals: Vec<Individual>,
+    ) -> Self {
+        Self {
+            board_features,
+            gate,
             individuals,
         }
     }
 
+    /// Creates an unevaluated population from the given weights (weight sets of all phases
+    /// concatenated).
+    pub(crate) fn from_weights<I>(
+        board_features: Vec<BoxedBoardFeature>,
+        gate: HeightGate,
+        weights: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = Vec<f32>>,
+    {
+        let individuals = weights
+            .into_iter()
+            .map(|weights| Individual {
+                weights,
+                shape_params: vec![],
+                fitness: f32::MIN,
+            })
+            .collect();
+        Self {
+            board_features,
+            gate,
+            individuals,
+        }
+    }
+
+    /// Returns the features the weights apply to.
+    #[must_use]
+    pub fn board_features(&self) -> &[BoxedBoardFeature] {
+        &self.board_features
+    }
+
+    /// Returns the gating rule selecting weight sets.
+    #[must_use]
+    pub fn gate(&self) -> &HeightGate {
+        &self.gate
+    }
+
     /// Returns all individuals in this population.
     #[must_use]
     pub fn individuals(&self) -> &[Individual] {
@@ -229,32 +407,65 @@ impl Population {
     where
         E: SessionEvaluator + ?Sized,
     {
-        thread::scope(|s| {
-            for ind in &mut self.individuals {
-                let placement_evaluator = FeatureBasedPlacementEvaluator::new(
-                    self.board_features.clone(),
-                    ind.weights.clone(),
-                );
-                let turn_evaluator = TurnEvaluator::new(Box::new(placement_evaluator));
-                s.spawn(move || {
-                    ind.fitness =
-                        session_evaluator.play_and_evaluate_sessions(fields, &turn_evaluator);
-                });
-            }
-        });
+        let placement_evaluators = self
+            .individuals
+            .iter()
+            .map(|ind| self.placement_evaluator(ind))
+            .collect::<Vec<_>>();
+        self.individuals
+            .par_iter_mut()
+            .zip(placement_evaluators)
+            .for_each(|(ind, placement_evaluator)| {
+                let turn_evaluator = TurnEvaluator::new(placement_evaluator);
+                ind.fitness = session_evaluator.play_and_evaluate_sessions(fields, &turn_evaluator);
+            });
 
         // sort by fitness descending
         self.individuals
             .sort_by(|a, b| b.fitness.partial_cmp(&a.fitness).unwrap());
     }
 
+    /// Builds the placement evaluator playing with the individual's weights.
+    #[must_use]
+    pub fn placement_evaluator(&self, individual: &Individual) -> Box<dyn PlacementEvaluator> {
+        placement_evaluator(
+            &self.individual_features(individual),
+            &self.gate,
+            &individual.weights,
+        )
+    }
+
+    /// Returns the features with the individual's transform shapes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the individual's shape parameters do not match the features.
+    #[must_use]
+    pub fn individual_features(&self, individual: &Individual) -> Vec<BoxedBoardFeature> {
+        if individual.shape_params.is_empty() {
+            return self.board_features.clone();
+        }
+        let mut rest = individual.shape_params.as_slice();
+        let features = self
+            .board_features
+            .iter()
+            .map(|f| {
+                let (params, tail) = rest.split_at(f.shape_params().len());
+                rest = tail;
+                f.with_shape_params(params)
+            })
+            .collect();
+        assert!(rest.is_empty());
+        features
+    }
+
     /// Computes descriptive statistics for each weight across all individuals.
     ///
-    /// Returns statistics (mean, std, min, max, etc.) for each feature weight,
-    /// useful for analyzing population diversity and convergence.
+    /// Returns statistics (mean, std, min, max, etc.) for each feature weight
+    /// (of each phase), useful for analyzing population diversity and convergence.
     #[must_use]
     pub fn compute_weight_stats(&self) -> Vec<DescriptiveStats> {
-        (0..self.board_features.len())
+        (0..self.board_features.len() * self.gate.num_phases())
             .map(|i| {
                 let weights = self.individuals().iter().map(|ind| ind.weights[i]);
                 DescriptiveStats::new(weights).unwrap()
@@ -297,18 +508,21 @@ impl PopulationEvolver {
     ///
     /// 1. Preserves top `elite_count` individuals unchanged
     /// 2. Creates remaining individuals through tournament selection, crossover, and mutation
-    /// 3. All new weights are L1-normalized
+    /// 3. All new weights are L1-normalized (per phase)
     ///
     /// # Arguments
     ///
     /// * `population` - Current population (must be sorted by fitness descending)
+    /// * `rng` - Random number generator
     ///
     /// # Returns
     ///
     /// New population with same size as input
     #[must_use]
-    pub fn evolve(&self, population: &Population) -> Population {
-        let mut rng = rand::rng();
+    pub fn evolve<R>(&self, population: &Population, rng: &mut R) -> Population
+    where
+        R: Rng + ?Sized,
+    {
         let mut next_individuals = vec![];
         assert!(
             population
@@ -321,38 +535,101 @@ impl PopulationEvolver {
 
         // generate the rest individuals
         while next_individuals.len() < population.individuals.len() {
-            let p1 = tournament_select(&population.individuals, self.tournament_size, &mut rng);
-            let p2 = tournament_select(&population.individuals, self.tournament_size, &mut rng);
+            let p1 = tournament_select(&population.individuals, self.tournament_size, rng);
+            let p2 = tournament_select(&population.individuals, self.tournament_size, rng);
 
             let mut child = weights::blx_alpha(
                 &p1.weights,
                 &p2.weights,
                 self.blx_alpha,
                 self.max_weight,
-                &mut rng,
+                rng,
             );
             weights::mutate(
                 &mut child,
                 self.mutation_sigma,
                 self.max_weight,
                 self.mutation_rate,
-                &mut rng,
+                rng,
             );
-            weights::normalize_l1(&mut child);
+            normalize_phases(&mut child, population.board_features.len());
+
+            let mut shape_params = weights::blx_alpha(
+                &p1.shape_params,
+                &p2.shape_params,
+                self.blx_alpha,
+                self.max_weight,
+                rng,
+            );
+            weights::mutate(
+                &mut shape_params,
+                self.mutation_sigma,
+                self.max_weight,
+                self.mutation_rate,
+                rng,
+            );
+            normalize_shape_params(&mut shape_params, &population.board_features);
 
             next_individuals.push(Individual {
                 weights: child,
+                shape_params,
                 fitness: 0.0,
             });
         }
 
         Population {
             board_features: population.board_features.clone(),
+            gate: population.gate.clone(),
             individuals: next_individuals,
         }
     }
 }
 
+/// Builds the placement evaluator for the concatenated weight sets of each phase.
+pub(crate) fn placement_evaluator(
+    board_features: &[BoxedBoardFeature],
+    gate: &HeightGate,
+    weights: &[f32],
+) -> Box<dyn PlacementEvaluator> {
+    if gate.num_phases() == 1 {
+        return Box::new(FeatureBasedPlacementEvaluator::new(
+            board_features.to_vec(),
+            weights.to_vec(),
+        ));
+    }
+    let weights = weights
+        .chunks(board_features.len())
+        .map(<[f32]>::to_vec)
+        .collect();
+    Box::new(PhasedPlacementEvaluator::new(board_features.to_vec(), gate.clone(), weights).unwrap())
+}
+
+/// L1-normalizes each phase's weight set of concatenated `weights`.
+pub(crate) fn normalize_phases(weights: &mut [f32], feature_count: usize) {
+    if feature_count == 0 {
+        return;
+    }
+    for phase_weights in weights.chunks_mut(feature_count) {
+        weights::normalize_l1(phase_weights);
+    }
+}
+
+/// L1-normalizes the shape parameters of each feature in concatenated `shape_params`.
+///
+/// Piecewise-linear curves are rescaled to \[0.0, 1.0\] when rebuilt, so this only removes
+/// redundant scales from the search space.
+fn normalize_shape_params(shape_params: &mut [f32], board_features: &[BoxedBoardFeature]) {
+    if shape_params.is_empty() {
+        return;
+    }
+    let mut rest = shape_params;
+    for feature in board_features {
+        let (params, tail) = rest.split_at_mut(feature.shape_params().len());
+        weights::normalize_l1(params);
+        rest = tail;
+    }
+}
+
 /// Selects an individual using tournament selection.
 ///
 /// Randomly selects `tournament_size` individuals and returns the one with
@@ -378,3 +655,76 @@ where
         .max_by(|a, b| a.fitness.partial_cmp(&b.fitness).unwrap())
         .unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+    use oxidris_evaluator::board_feature::{
+        FeatureSignal,
+        source::{MaxHeight, NumHoles},
+        transform::{FeatureProcessing, PiecewiseLinearParam, PiecewiseLinearTransform},
+    };
+    use rand::{SeedableRng as _, rngs::StdRng};
+
+    use super::*;
+
+    #[test]
+    fn test_random_phased_normalizes_each_phase() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let individual = Individual::random_phased(&mut rng, 1.0, 4, 3);
+        assert_eq!(individual.weights().len(), 12);
+        assert_eq!(individual.phase_weights(4).count(), 3);
+        for weights in individual.phase_weights(4) {
+            assert!((weights.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_evolve_shape_params() {
+        let param = PiecewiseLinearParam::new(
+            FeatureSignal::Negative,
+            vec![0.0, 2.0, 10.0],
+            vec![0.0, 0.8, 1.0],
+        )
+        .unwrap();
+        let features: Vec<BoxedBoardFeature> = vec![
+            Box::new(PiecewiseLinearTransform::new(
+                "holes".to_owned(),
+                "Holes".to_owned(),
+                NumHoles,
+                param,
+            )),
+            Box::new(PiecewiseLinearTransform::new(
+                "height".to_owned(),
+                "Height".to_owned(),
+                MaxHeight,
+                PiecewiseLinearParam::new(FeatureSignal::Negative, vec![0.0], vec![0.0]).unwrap(),
+            )),
+        ];
+        let mut rng = StdRng::seed_from_u64(0);
+        let population = Population::random(features, 10, &mut rng, 1.0);
+        for ind in population.individuals() {
+            let params = ind.shape_params();
+            assert!((params[0] - 0.8).abs() < 1e-6 && (params[1] - 0.2).abs() < 1e-6);
+        }
+
+        let evolver = PopulationEvolver {
+            elite_count: 0,
+            max_weight: 1.0,
+            tournament_size: 2,
+            mutation_sigma: 0.5,
+            blx_alpha: 0.5,
+            mutation_rate: 1.0,
+        };
+        let population = evolver.evolve(&population, &mut rng);
+        for ind in population.individuals() {
+            assert_eq!(ind.shape_params().len(), 2);
+            assert!((ind.shape_params().iter().sum::<f32>() - 1.0).abs() < 1e-5);
+            let features = population.individual_features(ind);
+            let FeatureProcessing::PiecewiseLinear(param) = features[0].feature_processing() else {
+                panic!("unexpected processing");
+            };
+            assert_eq!(param.knots(), &[0.0, 2.0, 10.0]);
+            assert!((param.outputs()[1] - ind.shape_params()[0]).abs() < 1e-5);
+        }
+    }
+}
@@ -41,12 +41,32 @@
 //!
 //! See the [`genetic`] module for implementation details.
 //!
+//! # CMA-ES
+//!
+//! The [`cma_es`] module is an alternative optimizer for the same fitness functions. It adapts
+//! a full covariance matrix over the weights, which suits correlated weights better than the
+//! GA's per-weight operators, and restarts with growing populations (IPOP) when it converges.
+//!
+//! # Multi-Objective Training
+//!
+//! Instead of blending survival and score into one hand-designed fitness, the [`nsga2`] module
+//! optimizes them as separate objectives with NSGA-II. The result is a Pareto front of weight
+//! sets covering the whole survival/score trade-off, each exported as its own model.
+//!
+//! # Tree Models
+//!
+//! As an alternative to evolving linear weights, the [`gbdt`] module fits gradient-boosted
+//! regression trees to labeled boards offline (e.g., survival labels from recorded sessions).
+//!
 //! # Fitness Functions
 //!
 //! Different fitness functions produce different play styles:
 //!
 //! - **Aggro** (`oxidris-evaluator::AggroSessionEvaluator`) - Balances survival with line clearing
 //! - **Defensive** (`oxidris-evaluator::DefensiveSessionEvaluator`) - Prioritizes survival time
+//! - **Score**, **Sprint**, **Tetris rate**, **Back-to-back** (`ScoreSessionEvaluator`,
+//!   `SprintSessionEvaluator`, ...) - Optimize points per piece, pieces per 40 lines, tetris
+//!   rate, or back-to-back chains
 //!
 //! # Training Process
 //!
@@ -105,17 +125,15 @@
 //!
 //! # Current Limitations
 //!
-//! - **No multi-objective optimization**: Each model optimizes a single fitness function that
-//!   manually combines multiple objectives (survival, score) into one scalar value. This prevents
-//!   systematic exploration of trade-offs—we can't answer "what's the best survival/score balance?"
-//!   or generate models with intermediate play styles without hand-designing new fitness functions.
-//!   Multi-objective optimization (e.g., Pareto fronts) would reveal the full range of optimal
-//!   trade-offs.
 //! - **Simple GA**: Uses basic genetic algorithm without advanced techniques (e.g., adaptive
-//!   mutation rates, island models, or hybrid algorithms)
+//!   mutation rates, island models, or hybrid algorithms); CMA-ES is available as an
+//!   alternative optimizer
 //! - **No transfer learning**: Each model trained from scratch, not reusing knowledge from
 //!   previously trained models
 //! - **Expensive**: Requires many game simulations (parallelized but still slow)
 
+pub mod cma_es;
+pub mod gbdt;
 pub mod genetic;
+pub mod nsga2;
 pub mod weights;
@@ -0,0 +1,510 @@
+//! NSGA-II multi-objective evolution of feature weights.
+//!
+//! This module evolves feature weights for several objectives at once (e.g., survival and
+//! score) instead of a single hand-blended fitness. The result is a Pareto front: a set of
+//! individuals where none is better than another in every objective, covering the
+//! trade-off between the objectives.
+//!
+//! # Algorithm Overview
+//!
+//! NSGA-II (Deb et al., 2002) keeps a population of `N` individuals and repeats:
+//!
+//! 1. **Reproduce** - Create `N` offspring with crowded tournament selection, BLX-α crossover,
+//!    and Gaussian mutation (the same operators as the [`genetic`](crate::genetic) module)
+//! 2. **Evaluate** - Evaluate the objectives of parents and offspring together
+//! 3. **Non-dominated Sort** - Split the `2N` individuals into fronts: front 0 is not
+//!    dominated by any individual, front 1 only by individuals in front 0, and so on
+//! 4. **Environmental Selection** - Keep whole fronts while they fit into `N`, and fill the
+//!    rest from the next front by descending crowding distance
+//!
+//! Individual `a` dominates `b` if `a` is at least as good in every objective and better in
+//! at least one (all objectives are maximized).
+//!
+//! ## Crowding Distance
+//!
+//! Within a front, the crowding distance of an individual is the sum over objectives of
+//! the normalized distance between its two neighbors. Boundary individuals get an infinite
+//! distance. Preferring larger distances keeps the front spread out along the trade-off.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use oxidris_training::nsga2::{Nsga2Evolver, ParetoPopulation};
+//!
+//! let mut population = ParetoPopulation::random_phased(features, gate, 30, &mut rng, 0.5);
+//! population.evaluate_objectives(&fields, &session_evaluator);
+//! for generation in 1..100 {
+//!     let mut combined = evolver.evolve(&population, &mut rng);
+//!     combined.evaluate_objectives(&fields, &session_evaluator);
+//!     population = combined.select(30);
+//! }
+//! let front = population.pareto_front();
+//! ```
+
+use std::{cmp::Ordering, iter};
+
+use oxidris_engine::GameField;
+use oxidris_evaluator::{
+    board_feature::BoxedBoardFeature,
+    placement_evaluator::{HeightGate, PlacementEvaluator},
+    session_evaluator::MultiObjectiveSessionEvaluator,
+    turn_evaluator::TurnEvaluator,
+};
+use oxidris_stats::descriptive::DescriptiveStats;
+use rand::{Rng, seq::IndexedRandom};
+use rayon::prelude::*;
+
+use crate::{
+    genetic::{self, Individual},
+    weights,
+};
+
+/// A candidate solution evaluated by several objectives.
+#[derive(Debug, Clone)]
+pub struct ParetoIndividual {
+    weights: Vec<f32>,
+    objectives: Vec<f32>,
+    rank: usize,
+    crowding_distance: f32,
+}
+
+impl ParetoIndividual {
+    fn new(weights: Vec<f32>) -> Self {
+        Self {
+            weights,
+            objectives: vec![],
+            rank: usize::MAX,
+            crowding_distance: 0.0,
+        }
+    }
+
+    /// Returns the feature weights (weight sets of all phases concatenated).
+    #[must_use]
+    pub fn weights(&self) -> &[f32] {
+        &self.weights
+    }
+
+    /// Returns the weight set of each phase.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `feature_count` is zero.
+    pub fn phase_weights(&self, feature_count: usize) -> impl Iterator<Item = &[f32]> {
+        self.weights.chunks(feature_count)
+    }
+
+    /// Returns the value of each objective (higher is better).
+    #[must_use]
+    pub fn objectives(&self) -> &[f32] {
+        &self.objectives
+    }
+
+    /// Returns the index of the non-dominated front (0 for the Pareto front).
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Returns the crowding distance within the front.
+    #[must_use]
+    pub fn crowding_distance(&self) -> f32 {
+        self.crowding_distance
+    }
+
+    /// Returns whether `self` dominates `other`.
+    #[must_use]
+    pub fn dominates(&self, other: &Self) -> bool {
+        let mut better = false;
+        for (a, b) in iter::zip(&self.objectives, &other.objectives) {
+            if a < b {
+                return false;
+            }
+            better |= a > b;
+        }
+        better
+    }
+
+    /// Crowded-comparison order: lower rank first, then larger crowding distance.
+    fn crowded_cmp(&self, other: &Self) -> Ordering {
+        self.rank
+            .cmp(&other.rank)
+            .then_with(|| other.crowding_distance.total_cmp(&self.crowding_distance))
+    }
+}
+
+/// A population evolved by NSGA-II.
+#[derive(Debug, Clone)]
+pub struct ParetoPopulation {
+    board_features: Vec<BoxedBoardFeature>,
+    gate: HeightGate,
+    individuals: Vec<ParetoIndividual>,
+}
+
+impl ParetoPopulation {
+    /// Creates a new population with random individuals, one weight set per phase of
+    /// `gate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `board_features` - Features to evaluate (defines weight set length)
+    /// * `gate` - Gating rule selecting the weight set by stack height
+    /// * `count` - Number of individuals in the population
+    /// * `rng` - Random number generator
+    /// * `max_weight` - Maximum weight value before normalization
+    #[must_use]
+    pub fn random_phased<R>(
+        board_features: Vec<BoxedBoardFeature>,
+        gate: HeightGate,
+        count: usize,
+        rng: &mut R,
+        max_weight: f32,
+    ) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        let individuals = (0..count)
+            .map(|_| {
+                let ind = Individual::random_phased(
+                    rng,
+                    max_weight,
+                    board_features.len(),
+                    gate.num_phases(),
+                );
+                ParetoIndividual::new(ind.weights().to_vec())
+            })
+            .collect();
+        Self {
+            board_features,
+            gate,
+            individuals,
+        }
+    }
+
+    /// Returns the features the weights apply to.
+    #[must_use]
+    pub fn board_features(&self) -> &[BoxedBoardFeature] {
+        &self.board_features
+    }
+
+    /// Returns the gating rule selecting weight sets.
+    #[must_use]
+    pub fn gate(&self) -> &HeightGate {
+        &self.gate
+    }
+
+    /// Returns all individuals, sorted by rank and descending crowding distance once
+    /// evaluated.
+    #[must_use]
+    pub fn individuals(&self) -> &[ParetoIndividual] {
+        &self.individuals
+    }
+
+    /// Returns the individuals of the Pareto front (rank 0).
+    #[must_use]
+    pub fn pareto_front(&self) -> Vec<&ParetoIndividual> {
+        self.individuals
+            .iter()
+            .filter(|ind| ind.rank == 0)
+            .collect()
+    }
+
+    /// Computes descriptive statistics for each weight across all individuals.
+    ///
+    /// See [`Population::compute_weight_stats`](crate::genetic::Population::compute_weight_stats).
+    #[must_use]
+    pub fn compute_weight_stats(&self) -> Vec<DescriptiveStats> {
+        (0..self.board_features.len() * self.gate.num_phases())
+            .map(|i| {
+                let weights = self.individuals.iter().map(|ind| ind.weights[i]);
+                DescriptiveStats::new(weights).unwrap()
+            })
+            .collect()
+    }
+
+    /// Builds the placement evaluator playing with the individual's weights.
+    #[must_use]
+    pub fn placement_evaluator(
+        &self,
+        individual: &ParetoIndividual,
+    ) -> Box<dyn PlacementEvaluator> {
+        genetic::placement_evaluator(&self.board_features, &self.gate, &individual.weights)
+    }
+
+    /// Evaluates the objectives of all individuals in parallel, then assigns ranks and
+    /// crowding distances.
+    ///
+    /// After evaluation, individuals are sorted by rank and descending crowding distance.
+    pub fn evaluate_objectives<E>(&mut self, fields: &[GameField], session_evaluator: &E)
+    where
+        E: MultiObjectiveSessionEvaluator + ?Sized,
+    {
+        let placement_evaluators = self
+            .individuals
+            .iter()
+            .map(|ind| self.placement_evaluator(ind))
+            .collect::<Vec<_>>();
+        self.individuals
+            .par_iter_mut()
+            .zip(placement_evaluators)
+            .for_each(|(ind, placement_evaluator)| {
+                let turn_evaluator = TurnEvaluator::new(placement_evaluator);
+                ind.objectives =
+                    session_evaluator.play_and_evaluate_sessions(fields, &turn_evaluator);
+            });
+        assign_rank_and_crowding(&mut self.individuals);
+        self.individuals.sort_by(ParetoIndividual::crowded_cmp);
+    }
+
+    /// Environmental selection: keeps the `count` best individuals by crowded comparison.
+    ///
+    /// Ranks and crowding distances are recomputed among the survivors.
+    #[must_use]
+    pub fn select(mut self, count: usize) -> Self {
+        assert!(
+            self.individuals
+                .is_sorted_by(|a, b| a.crowded_cmp(b).is_le())
+        );
+        self.individuals.truncate(count);
+        assign_rank_and_crowding(&mut self.individuals);
+        self.individuals.sort_by(ParetoIndividual::crowded_cmp);
+        self
+    }
+}
+
+/// Controls NSGA-II reproduction parameters.
+///
+/// The variation operators are the same as in
+/// [`PopulationEvolver`](crate::genetic::PopulationEvolver); selection uses the
+/// crowded-comparison operator instead of scalar fitness.
+#[derive(Debug)]
+pub struct Nsga2Evolver {
+    /// Maximum allowed weight value (weights are clipped to [0, `max_weight`])
+    pub max_weight: f32,
+    /// Tournament size for crowded tournament selection
+    pub tournament_size: usize,
+    /// Standard deviation for Gaussian mutation noise
+    pub mutation_sigma: f32,
+    /// BLX-α crossover parameter (controls exploration beyond parent range)
+    pub blx_alpha: f32,
+    /// Probability of mutating each weight (per-weight mutation rate)
+    pub mutation_rate: f32,
+}
+
+impl Nsga2Evolver {
+    /// Creates offspring and returns them together with the parents.
+    ///
+    /// The returned population has twice the size of `population`. Its objectives must be
+    /// evaluated with [`ParetoPopulation::evaluate_objectives`] before
+    /// [`ParetoPopulation::select`] reduces it to the next generation.
+    ///
+    /// # Arguments
+    ///
+    /// * `population` - Current population (evaluated, so ranks and crowding distances are set)
+    /// * `rng` - Random number generator
+    #[must_use]
+    pub fn evolve<R>(&self, population: &ParetoPopulation, rng: &mut R) -> ParetoPopulation
+    where
+        R: Rng + ?Sized,
+    {
+        let parents = &population.individuals;
+        let mut individuals = parents.clone();
+        for _ in 0..parents.len() {
+            let p1 = crowded_tournament_select(parents, self.tournament_size, rng);
+            let p2 = crowded_tournament_select(parents, self.tournament_size, rng);
+            let mut child = weights::blx_alpha(
+                &p1.weights,
+                &p2.weights,
+                self.blx_alpha,
+                self.max_weight,
+                rng,
+            );
+            weights::mutate(
+                &mut child,
+                self.mutation_sigma,
+                self.max_weight,
+                self.mutation_rate,
+                rng,
+            );
+            genetic::normalize_phases(&mut child, population.board_features.len());
+            individuals.push(ParetoIndividual::new(child));
+        }
+        ParetoPopulation {
+            board_features: population.board_features.clone(),
+            gate: population.gate.clone(),
+            individuals,
+        }
+    }
+}
+
+fn crowded_tournament_select<'a, R>(
+    population: &'a [ParetoIndividual],
+    tournament_size: usize,
+    rng: &mut R,
+) -> &'a ParetoIndividual
+where
+    R: Rng + ?Sized,
+{
+    assert!(tournament_size > 0);
+    population
+        .choose_multiple(rng, tournament_size)
+        .min_by(|a, b| a.crowded_cmp(b))
+        .unwrap()
+}
+
+/// Assigns the non-dominated front index and crowding distance of each individual.
+fn assign_rank_and_crowding(individuals: &mut [ParetoIndividual]) {
+    for (rank, front) in non_dominated_fronts(individuals).into_iter().enumerate() {
+        for &i in &front {
+            individuals[i].rank = rank;
+        }
+        assign_crowding_distance(individuals, &front);
+    }
+}
+
+/// Splits individuals into non-dominated fronts (fast non-dominated sort).
+///
+/// Returns the indices of the individuals in each front, best front first.
+fn non_dominated_fronts(individuals: &[ParetoIndividual]) -> Vec<Vec<usize>> {
+    let n = individuals.len();
+    let mut dominated_by = vec![vec![]; n];
+    let mut domination_count = vec![0_usize; n];
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if individuals[i].dominates(&individuals[j]) {
+                dominated_by[i].push(j);
+                domination_count[j] += 1;
+            } else if individuals[j].dominates(&individuals[i]) {
+                dominated_by[j].push(i);
+                domination_count[i] += 1;
+            }
+        }
+    }
+
+    let mut fronts = vec![];
+    let mut front = (0..n)
+        .filter(|i| domination_count[*i] == 0)
+        .collect::<Vec<_>>();
+    while !front.is_empty() {
+        let mut next_front = vec![];
+        for &i in &front {
+            for &j in &dominated_by[i] {
+                domination_count[j] -= 1;
+                if domination_count[j] == 0 {
+                    next_front.push(j);
+                }
+            }
+        }
+        fronts.push(front);
+        front = next_front;
+    }
+    fronts
+}
+
+fn assign_crowding_distance(individuals: &mut [ParetoIndividual], front: &[usize]) {
+    for &i in front {
+        individuals[i].crowding_distance = 0.0;
+    }
+    let num_objectives = front
+        .first()
+        .map_or(0, |i| individuals[*i].objectives.len());
+    let mut sorted = front.to_vec();
+    for m in 0..num_objectives {
+        sorted.sort_by(|a, b| {
+            individuals[*a].objectives[m].total_cmp(&individuals[*b].objectives[m])
+        });
+        let (Some(&first), Some(&last)) = (sorted.first(), sorted.last()) else {
+            continue;
+        };
+        let min = individuals[first].objectives[m];
+        let max = individuals[last].objectives[m];
+        individuals[first].crowding_distance = f32::INFINITY;
+        individuals[last].crowding_distance = f32::INFINITY;
+        let range = max - min;
+        if range <= 0.0 {
+            continue;
+        }
+        for w in sorted.windows(3) {
+            let gap = individuals[w[2]].objectives[m] - individuals[w[0]].objectives[m];
+            individuals[w[1]].crowding_distance += gap / range;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn individual(objectives: &[f32]) -> ParetoIndividual {
+        ParetoIndividual {
+            objectives: objectives.to_vec(),
+            ..ParetoIndividual::new(vec![])
+        }
+    }
+
+    #[test]
+    fn test_dominates() {
+        let a = individual(&[1.0, 2.0]);
+        let b = individual(&[1.0, 1.0]);
+        let c = individual(&[2.0, 0.0]);
+        assert!(a.dominates(&b));
+        assert!(!b.dominates(&a));
+        assert!(!a.dominates(&c));
+        assert!(!c.dominates(&a));
+        assert!(!a.dominates(&a));
+    }
+
+    #[test]
+    fn test_fronts_and_crowding() {
+        let mut individuals = vec![
+            individual(&[0.0, 4.0]),
+            individual(&[1.0, 3.0]),
+            individual(&[3.0, 2.9]),
+            individual(&[4.0, 0.0]),
+            individual(&[1.0, 1.0]),
+            individual(&[0.5, 0.5]),
+        ];
+        assert_eq!(
+            non_dominated_fronts(&individuals),
+            [vec![0, 1, 2, 3], vec![4], vec![5]]
+        );
+
+        assign_rank_and_crowding(&mut individuals);
+        let ranks = individuals.iter().map(|ind| ind.rank).collect::<Vec<_>>();
+        assert_eq!(ranks, [0, 0, 0, 0, 1, 2]);
+        assert!(individuals[0].crowding_distance.is_infinite());
+        assert!(individuals[3].crowding_distance.is_infinite());
+        // (3 - 0) / 4 + (4 - 2.9) / 4 and (4 - 1) / 4 + (3 - 0) / 4
+        assert!((individuals[1].crowding_distance - 1.025).abs() < 1e-6);
+        assert!((individuals[2].crowding_distance - 1.5).abs() < 1e-6);
+        // Single-member fronts are boundaries
+        assert!(individuals[4].crowding_distance.is_infinite());
+    }
+
+    #[test]
+    fn test_select_keeps_spread_of_last_front() {
+        let mut individuals = vec![
+            individual(&[0.0, 4.0]),
+            individual(&[1.0, 3.0]),
+            individual(&[3.0, 2.9]),
+            individual(&[4.0, 0.0]),
+            individual(&[1.0, 1.0]),
+        ];
+        assign_rank_and_crowding(&mut individuals);
+        individuals.sort_by(ParetoIndividual::crowded_cmp);
+        let population = ParetoPopulation {
+            board_features: vec![],
+            gate: HeightGate::default(),
+            individuals,
+        };
+        let selected = population.select(3);
+        let mut objectives = selected
+            .individuals()
+            .iter()
+            .map(|ind| ind.objectives().to_vec())
+            .collect::<Vec<_>>();
+        objectives.sort_by(|a, b| a[0].total_cmp(&b[0]));
+        // Both boundaries, then the less crowded of the inner individuals
+        assert_eq!(objectives, [vec![0.0, 4.0], vec![3.0, 2.9], vec![4.0, 0.0]]);
+        assert_eq!(selected.pareto_front().len(), 3);
+    }
+}